@@ -3,6 +3,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
+use chrono::format::TimestampParser;
 use chrono::prelude::*;
 use chrono::{DateTime, FixedOffset, Utc, __BenchYearFlags};
 
@@ -24,6 +25,15 @@ fn bench_datetime_parse_from_rfc3339(c: &mut Criterion) {
     });
 }
 
+fn bench_datetime_parse_from_rfc3339_fallback(c: &mut Criterion) {
+    c.bench_function("bench_datetime_parse_from_rfc3339_fallback", |b| {
+        b.iter(|| {
+            let str = black_box("2015-02-18T23:59:60.234567UTC");
+            DateTime::<FixedOffset>::parse_from_rfc3339(str).unwrap()
+        })
+    });
+}
+
 fn bench_datetime_from_str(c: &mut Criterion) {
     c.bench_function("bench_datetime_from_str", |b| {
         b.iter(|| {
@@ -34,6 +44,25 @@ fn bench_datetime_from_str(c: &mut Criterion) {
     });
 }
 
+fn bench_naive_datetime_parse_from_str(c: &mut Criterion) {
+    c.bench_function("bench_naive_datetime_parse_from_str", |b| {
+        b.iter(|| {
+            let str = black_box("2019-03-30 18:46:57.193");
+            NaiveDateTime::parse_from_str(str, "%Y-%m-%d %H:%M:%S%.f").unwrap()
+        })
+    });
+}
+
+fn bench_timestamp_parser(c: &mut Criterion) {
+    let parser = TimestampParser::new("%Y-%m-%d %H:%M:%S%.f");
+    c.bench_function("bench_timestamp_parser", |b| {
+        b.iter(|| {
+            let str = black_box("2019-03-30 18:46:57.193");
+            parser.parse(str).unwrap()
+        })
+    });
+}
+
 fn bench_datetime_to_rfc2822(c: &mut Criterion) {
     let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
     let dt = pst.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(10, 5, 13, 84_660_000).unwrap();
@@ -104,7 +133,10 @@ criterion_group!(
     benches,
     bench_datetime_parse_from_rfc2822,
     bench_datetime_parse_from_rfc3339,
+    bench_datetime_parse_from_rfc3339_fallback,
     bench_datetime_from_str,
+    bench_naive_datetime_parse_from_str,
+    bench_timestamp_parser,
     bench_datetime_to_rfc2822,
     bench_datetime_to_rfc3339,
     bench_year_flags_from_year,
@@ -0,0 +1,131 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A builder for updating several fields of a `DateTime` at once.
+
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{LocalResult, TimeZone};
+use crate::{DateTime, Datelike, Timelike};
+
+/// A builder that updates any subset of the local fields of a [`DateTime`] at once.
+///
+/// Obtained via [`DateTime::builder`]. Unlike chaining the `with_*` methods, which validate
+/// every intermediate value, all changes are applied together and the resulting local datetime
+/// is only validated and resolved in the time zone once, in [`build`](#method.build).
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.ymd_opt(2022, 1, 31).unwrap().and_hms_opt(9, 30, 0).unwrap();
+/// // `dt.with_month(2)` would fail, as February 31 does not exist.
+/// let dt = dt.builder().month(2).day(15).hour(18).build().unwrap();
+/// assert_eq!(dt, Utc.ymd_opt(2022, 2, 15).unwrap().and_hms_opt(18, 30, 0).unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DateTimeBuilder<Tz: TimeZone> {
+    local: NaiveDateTime,
+    offset: Tz::Offset,
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+}
+
+impl<Tz: TimeZone> DateTimeBuilder<Tz> {
+    pub(super) fn new(dt: &DateTime<Tz>) -> DateTimeBuilder<Tz> {
+        DateTimeBuilder {
+            local: dt.naive_local(),
+            offset: dt.offset().clone(),
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+            minute: None,
+            second: None,
+            nanosecond: None,
+        }
+    }
+
+    /// Sets the year number.
+    #[inline]
+    pub fn year(mut self, year: i32) -> DateTimeBuilder<Tz> {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the month number (starting from 1).
+    #[inline]
+    pub fn month(mut self, month: u32) -> DateTimeBuilder<Tz> {
+        self.month = Some(month);
+        self
+    }
+
+    /// Sets the day of month (starting from 1).
+    #[inline]
+    pub fn day(mut self, day: u32) -> DateTimeBuilder<Tz> {
+        self.day = Some(day);
+        self
+    }
+
+    /// Sets the hour number (0 to 23).
+    #[inline]
+    pub fn hour(mut self, hour: u32) -> DateTimeBuilder<Tz> {
+        self.hour = Some(hour);
+        self
+    }
+
+    /// Sets the minute number (0 to 59).
+    #[inline]
+    pub fn minute(mut self, minute: u32) -> DateTimeBuilder<Tz> {
+        self.minute = Some(minute);
+        self
+    }
+
+    /// Sets the second number (0 to 59).
+    #[inline]
+    pub fn second(mut self, second: u32) -> DateTimeBuilder<Tz> {
+        self.second = Some(second);
+        self
+    }
+
+    /// Sets the number of nanoseconds since the whole non-leap second.
+    ///
+    /// As with [`NaiveTime::from_hms_nano_opt`], values of 1,000,000,000 or more can only be used
+    /// to represent a leap second at second 59.
+    #[inline]
+    pub fn nanosecond(mut self, nanosecond: u32) -> DateTimeBuilder<Tz> {
+        self.nanosecond = Some(nanosecond);
+        self
+    }
+
+    /// Applies all the changes and resolves the new local datetime in the original time zone.
+    ///
+    /// Fields that were not set keep their value from the original `DateTime`.
+    /// Returns `LocalResult::None` when the resulting date or time is invalid, or when the local
+    /// datetime does not exist in the time zone. Returns `LocalResult::Ambiguous` when the local
+    /// datetime maps to two instants.
+    pub fn build(&self) -> LocalResult<DateTime<Tz>> {
+        let date = NaiveDate::from_ymd_opt(
+            self.year.unwrap_or_else(|| self.local.year()),
+            self.month.unwrap_or_else(|| self.local.month()),
+            self.day.unwrap_or_else(|| self.local.day()),
+        );
+        let time = NaiveTime::from_hms_nano_opt(
+            self.hour.unwrap_or_else(|| self.local.hour()),
+            self.minute.unwrap_or_else(|| self.local.minute()),
+            self.second.unwrap_or_else(|| self.local.second()),
+            self.nanosecond.unwrap_or_else(|| self.local.nanosecond()),
+        );
+        match (date, time) {
+            (Some(date), Some(time)) => {
+                Tz::from_offset(&self.offset).from_local_datetime(&date.and_time(time))
+            }
+            _ => LocalResult::None,
+        }
+    }
+}
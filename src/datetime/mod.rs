@@ -11,6 +11,8 @@ use alloc::string::{String, ToString};
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::cmp::Ordering;
+#[cfg(any(feature = "std", test))]
+use core::convert::TryFrom;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::{fmt, hash, str};
 #[cfg(feature = "std")]
@@ -25,13 +27,19 @@ use rkyv::{Archive, Deserialize, Serialize};
 use crate::format::DelayedFormat;
 #[cfg(feature = "unstable-locales")]
 use crate::format::Locale;
-use crate::format::{parse, ParseError, ParseResult, Parsed, StrftimeItems};
+use crate::format::ParseErrorKind;
+use crate::format::INVALID;
+use crate::format::NOT_ENOUGH;
+use crate::format::{parse, parse_rfc3339_fast, ParseError, ParseResult, Parsed, StrftimeItems};
 use crate::format::{Fixed, Item};
+use crate::format::{Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
-use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
-use crate::{Date, Datelike, Months, TimeDelta, Timelike, Weekday};
+use crate::offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+#[cfg(any(feature = "std", test))]
+use crate::OutOfRange;
+use crate::{Date, Datelike, Months, RelativeDelta, TimeDelta, Timelike, Weekday};
 
 /// documented at re-export site
 #[cfg(feature = "serde")]
@@ -40,6 +48,12 @@ pub(super) mod serde;
 #[cfg(test)]
 mod tests;
 
+mod builder;
+pub use self::builder::DateTimeBuilder;
+
+// Julian Date of 1970-01-01T00:00:00Z
+const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5;
+
 /// Specific formatting options for seconds. This may be extended in the
 /// future, so exhaustive matching in external code is not recommended.
 ///
@@ -71,6 +85,58 @@ pub enum SecondsFormat {
     __NonExhaustive,
 }
 
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl SecondsFormat {
+    /// Returns the formatting item for the subseconds, or `None` if no subseconds are displayed.
+    pub(crate) fn subsec_item(self) -> Option<Item<'static>> {
+        use crate::SecondsFormat::*;
+
+        debug_assert!(self != __NonExhaustive, "Do not use __NonExhaustive!");
+
+        match self {
+            Secs => None,
+            Millis => Some(Item::Fixed(Fixed::Nanosecond3)),
+            Micros => Some(Item::Fixed(Fixed::Nanosecond6)),
+            Nanos => Some(Item::Fixed(Fixed::Nanosecond9)),
+            AutoSi => Some(Item::Fixed(Fixed::Nanosecond)),
+            __NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+/// The date and time items of an RFC 3339 string, up to the whole seconds.
+#[cfg(any(feature = "alloc", feature = "std", test))]
+pub(crate) const RFC3339_PREFIX: &[Item<'static>] = &[
+    Item::Numeric(Numeric::Year, Pad::Zero),
+    Item::Literal("-"),
+    Item::Numeric(Numeric::Month, Pad::Zero),
+    Item::Literal("-"),
+    Item::Numeric(Numeric::Day, Pad::Zero),
+    Item::Literal("T"),
+    Item::Numeric(Numeric::Hour, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Minute, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Second, Pad::Zero),
+];
+
+/// The items of a C `asctime` string such as `Wed Feb 18 23:16:09 2015`.
+const CTIME_ITEMS: &[Item<'static>] = &[
+    Item::Fixed(Fixed::ShortWeekdayName),
+    Item::Space(" "),
+    Item::Fixed(Fixed::ShortMonthName),
+    Item::Space(" "),
+    Item::Numeric(Numeric::Day, Pad::Space),
+    Item::Space(" "),
+    Item::Numeric(Numeric::Hour, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Minute, Pad::Zero),
+    Item::Literal(":"),
+    Item::Numeric(Numeric::Second, Pad::Zero),
+    Item::Space(" "),
+    Item::Numeric(Numeric::Year, Pad::Zero),
+];
+
 /// ISO 8601 combined date and time with time zone.
 ///
 /// There are some constructors implemented here (the `from_*` methods), but
@@ -90,6 +156,56 @@ pub const MIN_DATETIME: DateTime<Utc> = DateTime::<Utc>::MIN_UTC;
 #[deprecated(since = "0.4.20", note = "Use DateTime::MAX_UTC instead")]
 pub const MAX_DATETIME: DateTime<Utc> = DateTime::<Utc>::MAX_UTC;
 
+/// Returns the earliest instant in `iter`, or `None` if it is empty.
+///
+/// Values are compared by their UTC instant, regardless of their offsets.
+/// If several values denote the same earliest instant, the first of them is returned.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone, Utc};
+///
+/// let noon = Utc.ymd_opt(2023, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+/// let new_york = FixedOffset::west_opt(4 * 3600).unwrap();
+///
+/// // 20:00 in Tokyo is 11:00 UTC, and 09:00 in New York is 13:00 UTC
+/// let times = vec![
+///     tokyo.ymd_opt(2023, 6, 1).unwrap().and_hms_opt(20, 0, 0).unwrap(),
+///     new_york.ymd_opt(2023, 6, 1).unwrap().and_hms_opt(9, 0, 0).unwrap(),
+/// ];
+/// assert_eq!(chrono::earliest(times.clone()).unwrap(), noon - chrono::TimeDelta::hours(1));
+/// assert_eq!(chrono::latest(times).unwrap(), noon + chrono::TimeDelta::hours(1));
+/// ```
+pub fn earliest<Tz, I>(iter: I) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    I: IntoIterator<Item = DateTime<Tz>>,
+{
+    iter.into_iter().fold(None, |min, dt| match min {
+        Some(min) if min <= dt => Some(min),
+        _ => Some(dt),
+    })
+}
+
+/// Returns the latest instant in `iter`, or `None` if it is empty.
+///
+/// Values are compared by their UTC instant, regardless of their offsets.
+/// If several values denote the same latest instant, the first of them is returned.
+///
+/// See [`earliest`] for an example.
+pub fn latest<Tz, I>(iter: I) -> Option<DateTime<Tz>>
+where
+    Tz: TimeZone,
+    I: IntoIterator<Item = DateTime<Tz>>,
+{
+    iter.into_iter().fold(None, |max, dt| match max {
+        Some(max) if max >= dt => Some(max),
+        _ => Some(dt),
+    })
+}
+
 impl<Tz: TimeZone> DateTime<Tz> {
     /// Makes a new `DateTime` with given *UTC* datetime and offset.
     /// The local datetime should be constructed via the `TimeZone` trait.
@@ -295,6 +411,67 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.timestamp_subsec_nanos()
     }
 
+    /// Returns the number of non-leap seconds since January 1, 1970 0:00:00 UTC together with
+    /// the number of nanoseconds since the last second boundary.
+    ///
+    /// This is a lossless representation of the instant: during a leap second the nanosecond
+    /// part exceeds 999,999,999. See [`DateTime::from_timestamp_parts`] for the inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2001, 9, 9).unwrap().and_hms_nano_opt(1, 46, 40, 555).unwrap();
+    /// assert_eq!(dt.as_timestamp_parts(), (1_000_000_000, 555));
+    /// ```
+    #[inline]
+    pub fn as_timestamp_parts(&self) -> (i64, u32) {
+        (self.datetime.timestamp(), self.datetime.timestamp_subsec_nanos())
+    }
+
+    /// Returns the number of seconds since January 1, 1970 0:00:00 UTC as a floating point
+    /// number, including the fractional subsecond part.
+    ///
+    /// An `f64` only has 53 bits of precision, so the result is rounded: dates near the present
+    /// keep a sub-microsecond precision, which degrades for larger magnitudes.
+    /// Use [`as_timestamp_parts`](#method.as_timestamp_parts) for a lossless representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_milli_opt(0, 0, 1, 500).unwrap();
+    /// assert_eq!(dt.timestamp_f64(), 1.5);
+    /// ```
+    #[inline]
+    pub fn timestamp_f64(&self) -> f64 {
+        self.datetime.timestamp() as f64 + f64::from(self.datetime.timestamp_subsec_nanos()) / 1e9
+    }
+
+    /// Returns the Julian Date of the instant, the fractional number of days since noon UTC on
+    /// November 24, 4714 BCE in the proleptic Gregorian calendar.
+    ///
+    /// The epoch is fixed by JD 2440587.5 being 1970-01-01T00:00:00Z. UTC is used as the time
+    /// scale, without accounting for leap seconds or the difference to Terrestrial Time.
+    /// Like [`timestamp_f64`](#method.timestamp_f64) the result is rounded; near the present
+    /// it is precise to tens of microseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let epoch = Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(epoch.to_julian_date(), 2_440_587.5);
+    /// let j2000 = Utc.ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(j2000.to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(&self) -> f64 {
+        self.timestamp_f64() / 86_400.0 + UNIX_EPOCH_JULIAN_DATE
+    }
+
     /// Retrieves an associated offset from UTC.
     #[inline]
     pub fn offset(&self) -> &Tz::Offset {
@@ -314,6 +491,75 @@ impl<Tz: TimeZone> DateTime<Tz> {
         tz.from_utc_datetime(&self.datetime)
     }
 
+    /// Changes the associated time zone, consuming both `self` and `tz`.
+    ///
+    /// This is the same as [`with_timezone`](#method.with_timezone), but takes its arguments by
+    /// value, which reads better at the end of a chain of conversions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(dt.into_timezone(tokyo).to_string(), "2022-06-01 21:00:00 +09:00");
+    /// ```
+    #[inline]
+    pub fn into_timezone<Tz2: TimeZone>(self, tz: Tz2) -> DateTime<Tz2> {
+        tz.from_utc_datetime(&self.datetime)
+    }
+
+    /// Returns `true` if `self` and `other` represent the same instant, whatever their
+    /// time zones or offsets.
+    ///
+    /// This is what `==` does as well, but spelled out, which can make an assertion comparing
+    /// values in different time zones easier to read. It is the same as comparing the results
+    /// of [`naive_utc`](#method.naive_utc).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let utc = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(3, 0, 0).unwrap();
+    /// let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    /// assert!(utc.eq_instant(&kst.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap()));
+    /// assert!(!utc.eq_instant(&kst.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(3, 0, 0).unwrap()));
+    /// ```
+    #[inline]
+    pub fn eq_instant<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> bool {
+        self.datetime == other.datetime
+    }
+
+    /// Returns `true` if `self` and `other` fall on the same calendar day when both are viewed
+    /// in the time zone `view`.
+    ///
+    /// Comparing the results of [`date_naive`](DateTime::date_naive) directly would use the
+    /// native offset of each value, which gives the wrong answer when they differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    /// let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(20, 0, 0).unwrap();
+    /// let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    ///
+    /// assert!(a.is_same_day_as(&b, Utc));
+    /// assert!(!a.is_same_day_as(&b, kst));
+    /// ```
+    pub fn is_same_day_as<Tz2: TimeZone>(
+        &self,
+        other: &DateTime<Tz2>,
+        view: impl TimeZone,
+    ) -> bool {
+        let lhs = view.from_utc_datetime(&self.datetime).date_naive();
+        let rhs = view.from_utc_datetime(&other.datetime).date_naive();
+        lhs == rhs
+    }
+
     /// Adds given `Duration` to the current date and time.
     ///
     /// Returns `None` when it will result in overflow.
@@ -324,6 +570,37 @@ impl<Tz: TimeZone> DateTime<Tz> {
         Some(tz.from_utc_datetime(&datetime))
     }
 
+    /// Adds given `Duration` to the current date and time, saturating at the bounds of the
+    /// representable range.
+    ///
+    /// Returns the resulting value together with a flag indicating whether the addition
+    /// overflowed. On overflow the value is clamped to [`NaiveDateTime::MIN`] or
+    /// [`NaiveDateTime::MAX`] on the UTC timeline, depending on the sign of `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    /// assert_eq!(
+    ///     dt.overflowing_add_signed(TimeDelta::hours(2)),
+    ///     (Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap(), false)
+    /// );
+    /// assert_eq!(dt.overflowing_add_signed(TimeDelta::max_value()), (DateTime::<Utc>::MAX_UTC, true));
+    /// ```
+    pub fn overflowing_add_signed(self, rhs: TimeDelta) -> (DateTime<Tz>, bool) {
+        let tz = self.timezone();
+        match self.datetime.checked_add_signed(rhs) {
+            Some(datetime) => (tz.from_utc_datetime(&datetime), false),
+            None => {
+                let bound =
+                    if rhs < TimeDelta::zero() { NaiveDateTime::MIN } else { NaiveDateTime::MAX };
+                (tz.from_utc_datetime(&bound), true)
+            }
+        }
+    }
+
     /// Adds given `Months` to the current date and time.
     ///
     /// Returns `None` when it will result in overflow, or if the
@@ -380,6 +657,32 @@ impl<Tz: TimeZone> DateTime<Tz> {
             .single()
     }
 
+    /// Adds a [`RelativeDelta`] to the local date and time.
+    ///
+    /// The years and months are applied first (clamping to the end of the month), then the days,
+    /// then the time delta, all on the local datetime. The result is resolved in the time zone only
+    /// once at the end, so it can be `LocalResult::None` or `LocalResult::Ambiguous` around a
+    /// DST transition. Returns `LocalResult::None` when any step goes out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{RelativeDelta, TimeDelta, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 1, 31).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let rd = RelativeDelta::new(0, 1, 10, TimeDelta::hours(2));
+    /// assert_eq!(
+    ///     dt.checked_add_relative(rd).unwrap(),
+    ///     Utc.ymd_opt(2024, 3, 10).unwrap().and_hms_opt(11, 0, 0).unwrap()
+    /// );
+    /// ```
+    pub fn checked_add_relative(self, rd: RelativeDelta) -> LocalResult<DateTime<Tz>> {
+        match rd.add_to(self.naive_local()) {
+            Some(datetime) => self.timezone().from_local_datetime(&datetime),
+            None => LocalResult::None,
+        }
+    }
+
     /// Subtracts another `DateTime` from the current date and time.
     /// This does not overflow or underflow at all.
     #[inline]
@@ -387,6 +690,187 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime.signed_duration_since(rhs.datetime)
     }
 
+    /// Returns the number of seconds from `other` to `self` as a floating point number,
+    /// including the fractional part.
+    ///
+    /// The result is negative if `self` is before `other`. Like
+    /// [`timestamp_f64`](#method.timestamp_f64), the result is rounded to the nearest `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let end = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_milli_opt(12, 0, 1, 500).unwrap();
+    /// assert_eq!(end.seconds_since_f64(&start), 1.5);
+    /// assert_eq!(start.seconds_since_f64(&end), -1.5);
+    /// ```
+    pub fn seconds_since_f64<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> f64 {
+        let delta = self.datetime.signed_duration_since(other.datetime);
+        let secs = delta.num_seconds();
+        // the remainder is less than a second, so it always fits
+        let nanos = (delta - TimeDelta::seconds(secs)).num_nanoseconds().unwrap_or(0);
+        secs as f64 + nanos as f64 / 1e9
+    }
+
+    /// Returns the instant exactly halfway between `self` and `other`.
+    ///
+    /// The midpoint is computed on the UTC timeline, so a change in offset between the two values
+    /// (e.g. a DST transition) does not skew the result. If the two instants are an odd number of
+    /// nanoseconds apart, the result is rounded towards the earlier one.
+    ///
+    /// The returned value is in the time zone of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    /// let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(12, 30, 0).unwrap();
+    /// assert_eq!(a.midpoint(&b), Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(11, 15, 0).unwrap());
+    /// assert_eq!(b.midpoint(&a), a.midpoint(&b));
+    /// ```
+    pub fn midpoint(&self, other: &DateTime<Tz>) -> DateTime<Tz> {
+        let (earlier, later) = if self.datetime <= other.datetime {
+            (self.datetime, other.datetime)
+        } else {
+            (other.datetime, self.datetime)
+        };
+        let half = later.signed_duration_since(earlier) / 2;
+        let datetime =
+            earlier.checked_add_signed(half).expect("midpoint lies between two valid datetimes");
+        self.timezone().from_utc_datetime(&datetime)
+    }
+
+    /// Compares the instants of `self` and `other`, which may be in different time zone types.
+    ///
+    /// This defines a total order on the UTC timeline. A leap second, represented with a
+    /// nanosecond of 1,000,000,000 or more (shown as second 60), is ordered after every instant
+    /// of the preceding second and before the following second; e.g. `23:59:59.999`,
+    /// `23:59:60.500` and `00:00:00` of the next day compare in that order.
+    ///
+    /// For values of the same time zone type this agrees with the `Ord` implementation.
+    /// It differs from comparing with `PartialOrd` across time zone types only in returning an
+    /// `Ordering` directly instead of an `Option<Ordering>`, which is always `Some` anyway.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use std::cmp::Ordering;
+    ///
+    /// let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    /// let next = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(leap.cmp_total(&next), Ordering::Less);
+    /// assert_eq!(next.with_timezone(&kst).cmp_total(&leap), Ordering::Greater);
+    /// ```
+    pub fn cmp_total<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+
+    /// Returns `true` if `self` and `other` are at most `tolerance` apart on the UTC timeline.
+    ///
+    /// The offsets of the two values are irrelevant; only the instants are compared.
+    /// A negative `tolerance` never matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, TimeZone, Utc};
+    ///
+    /// let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 0).unwrap();
+    /// let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 1).unwrap();
+    /// assert!(a.approx_eq(&b, TimeDelta::milliseconds(2)));
+    /// assert!(!a.approx_eq(&b, TimeDelta::microseconds(500)));
+    /// ```
+    pub fn approx_eq<Tz2: TimeZone>(&self, other: &DateTime<Tz2>, tolerance: TimeDelta) -> bool {
+        self.datetime.signed_duration_since(other.datetime).abs() <= tolerance
+    }
+
+    /// Returns `true` if `self` and `other` fall within the same UTC second, ignoring their
+    /// fractional seconds.
+    ///
+    /// This is for comparing against a source that only stores whole seconds. Unlike
+    /// [`approx_eq`](#method.approx_eq) with a tolerance of one second, both values are truncated
+    /// first, so `10:00:00.9` equals `10:00:00.0` but not `10:00:01.0`.
+    /// A [leap second](./naive/struct.NaiveTime.html#leap-second-handling) is a second of its own.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 900).unwrap();
+    /// let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 0).unwrap();
+    /// let c = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 1, 0).unwrap();
+    /// assert!(a.eq_to_second(&b));
+    /// assert!(!a.eq_to_second(&c));
+    /// ```
+    pub fn eq_to_second<Tz2: TimeZone>(&self, other: &DateTime<Tz2>) -> bool {
+        let whole_second = |dt: &NaiveDateTime| {
+            (dt.date(), dt.num_seconds_from_midnight(), dt.nanosecond() / 1_000_000_000)
+        };
+        whole_second(&self.datetime) == whole_second(&other.datetime)
+    }
+
+    /// Folds a [leap second](./naive/struct.NaiveTime.html#leap-second-handling)
+    /// into the following second, keeping the fractional part.
+    ///
+    /// This is for systems that cannot represent leap seconds:
+    /// `23:59:60.5` UTC becomes `00:00:00.5` UTC of the next day.
+    /// Returns `self` unchanged if it does not represent a leap second.
+    ///
+    /// Panics if the resulting value would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    /// let next = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500).unwrap();
+    /// assert_eq!(leap.normalize_leap_second(), next);
+    /// assert_eq!(next.normalize_leap_second(), next);
+    /// ```
+    pub fn normalize_leap_second(&self) -> DateTime<Tz> {
+        let nanos = self.datetime.nanosecond();
+        if nanos < 1_000_000_000 {
+            return self.clone();
+        }
+        let datetime = self
+            .datetime
+            .with_nanosecond(nanos - 1_000_000_000)
+            .and_then(|dt| dt.checked_add_signed(TimeDelta::seconds(1)))
+            .expect("`DateTime::normalize_leap_second` overflowed");
+        DateTime { datetime, offset: self.offset.clone() }
+    }
+
+    /// Truncates the fractional second to whole microseconds.
+    ///
+    /// The result round-trips through stores with microsecond precision, such as the timestamp
+    /// columns of PostgreSQL and MySQL. This is the same as
+    /// [`trunc_subsecs(6)`](./trait.SubsecRound.html#tymethod.trunc_subsecs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Timelike, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(10, 5, 13, 84_660_999).unwrap();
+    /// assert_eq!(dt.to_micros_precision().nanosecond(), 84_660_000);
+    /// ```
+    pub fn to_micros_precision(&self) -> DateTime<Tz> {
+        let nanos = self.datetime.nanosecond();
+        let datetime = self
+            .datetime
+            .with_nanosecond(nanos - nanos % 1_000)
+            .expect("truncating the nanoseconds keeps them in range");
+        DateTime { datetime, offset: self.offset.clone() }
+    }
+
     /// Returns a view to the naive UTC datetime.
     #[inline]
     pub fn naive_utc(&self) -> NaiveDateTime {
@@ -399,6 +883,28 @@ impl<Tz: TimeZone> DateTime<Tz> {
         self.datetime + self.offset.fix()
     }
 
+    /// Makes a new `DateTime` with the year number changed, clamping a leap day
+    /// (February 29) to February 28 when the target year is not a leap year.
+    ///
+    /// See [`NaiveDate::with_year_clamped`] for more details on behavior.
+    ///
+    /// Returns `None` when the year is out of range, or if the local time is not valid on the
+    /// newly calculated date.
+    pub fn with_year_clamped(&self, year: i32) -> Option<DateTime<Tz>> {
+        map_local(self, |datetime| {
+            datetime.with_year(year).or_else(|| datetime.with_day(28)?.with_year(year))
+        })
+    }
+
+    /// Returns a [`DateTimeBuilder`] to change several local fields of this `DateTime` at once.
+    ///
+    /// All changes are applied together and the time zone is resolved only once, so there are no
+    /// invalid intermediate states such as February 31 while changing both the month and the day.
+    #[inline]
+    pub fn builder(&self) -> DateTimeBuilder<Tz> {
+        DateTimeBuilder::new(self)
+    }
+
     /// Retrieve the elapsed years from now to the given [`DateTime`].
     pub fn years_since(&self, base: Self) -> Option<u32> {
         let mut years = self.year() - base.year();
@@ -416,44 +922,535 @@ impl<Tz: TimeZone> DateTime<Tz> {
         }
     }
 
-    /// The minimum possible `DateTime<Utc>`.
-    pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
-    /// The maximum possible `DateTime<Utc>`.
-    pub const MAX_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MAX, offset: Utc };
-}
-
-impl Default for DateTime<Utc> {
-    fn default() -> Self {
-        Utc.from_utc_datetime(&NaiveDateTime::default())
-    }
-}
-
-#[cfg(feature = "clock")]
-#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
-impl Default for DateTime<Local> {
-    fn default() -> Self {
-        Local.from_utc_datetime(&NaiveDateTime::default())
+    /// Returns the number of full years from `base` to `self`, comparing the local dates and
+    /// times.
+    ///
+    /// - `Some(0)` means that `base` is before (or equal to) `self`, but less than a year ago.
+    /// - `None` means that `base` is *after* `self`; it never stands for zero years.
+    ///
+    /// A year is complete when the same month, day and time of day is reached again,
+    /// so from February 29 the year completes on March 1 in a common year.
+    /// This is the same as [`years_since`](#method.years_since), but takes `base` by reference.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let now = Utc.ymd_opt(2023, 6, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let base = Utc.ymd_opt(2021, 6, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(now.full_years_since(&base), Some(2));
+    /// assert_eq!(base.full_years_since(&now), None);
+    /// ```
+    pub fn full_years_since(&self, base: &DateTime<Tz>) -> Option<u32> {
+        self.years_since(base.clone())
     }
-}
 
-impl Default for DateTime<FixedOffset> {
-    fn default() -> Self {
-        FixedOffset::west_opt(0).unwrap().from_utc_datetime(&NaiveDateTime::default())
+    /// Returns `true` if at least `years` full years have passed from `base` to `self`,
+    /// as counted by [`full_years_since`](#method.full_years_since).
+    ///
+    /// This is always `false` if `base` is after `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let birth = Utc.ymd_opt(2005, 6, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let day_before = Utc.ymd_opt(2023, 6, 14).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let birthday = Utc.ymd_opt(2023, 6, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert!(!day_before.is_at_least_years_old(&birth, 18));
+    /// assert!(birthday.is_at_least_years_old(&birth, 18));
+    /// ```
+    pub fn is_at_least_years_old(&self, base: &DateTime<Tz>, years: u32) -> bool {
+        self.full_years_since(base).map_or(false, |full| full >= years)
     }
-}
 
-/// Convert a `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
-impl From<DateTime<Utc>> for DateTime<FixedOffset> {
-    /// Convert this `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
+    /// Returns the fiscal quarter (1 to 4) of the local date, for a fiscal year starting on the
+    /// first day of `fiscal_start_month` (1 to 12).
     ///
-    /// Conversion is done via [`DateTime::with_timezone`]. Note that the converted value returned by
-    /// this will be created with a fixed timezone offset of 0.
-    fn from(src: DateTime<Utc>) -> Self {
-        src.with_timezone(&FixedOffset::east_opt(0).unwrap())
+    /// With `fiscal_start_month` of 1 this is the calendar quarter.
+    ///
+    /// Panics if `fiscal_start_month` is not between 1 and 12.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2023, 7, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(dt.fiscal_quarter(1), 3);
+    /// assert_eq!(dt.fiscal_quarter(7), 1);
+    /// assert_eq!(dt.fiscal_quarter(10), 4);
+    /// ```
+    pub fn fiscal_quarter(&self, fiscal_start_month: u32) -> u32 {
+        assert!((1..=12).contains(&fiscal_start_month), "invalid fiscal start month");
+        (self.month() + 12 - fiscal_start_month) % 12 / 3 + 1
     }
-}
 
-/// Convert a `DateTime<Utc>` instance into a `DateTime<Local>` instance.
+    /// Returns the fiscal year of the local date, for a fiscal year starting on the first day of
+    /// `fiscal_start_month` (1 to 12).
+    ///
+    /// A fiscal year is labelled by the calendar year in which it *ends*: with a fiscal year
+    /// starting in July, July 2023 to June 2024 is the fiscal year 2024.
+    /// With `fiscal_start_month` of 1 this is the calendar year.
+    ///
+    /// Panics if `fiscal_start_month` is not between 1 and 12.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let june = Utc.ymd_opt(2023, 6, 30).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let july = Utc.ymd_opt(2023, 7, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(june.fiscal_year(7), 2023);
+    /// assert_eq!(july.fiscal_year(7), 2024);
+    /// assert_eq!(july.fiscal_year(1), 2023);
+    /// ```
+    pub fn fiscal_year(&self, fiscal_start_month: u32) -> i32 {
+        assert!((1..=12).contains(&fiscal_start_month), "invalid fiscal start month");
+        if fiscal_start_month > 1 && self.month() >= fiscal_start_month {
+            self.year() + 1
+        } else {
+            self.year()
+        }
+    }
+
+    /// Returns the local midnight at the start of the first day of the local month.
+    ///
+    /// The midnight is resolved in the time zone, which can give `LocalResult::None` or
+    /// `LocalResult::Ambiguous` if a DST transition happens at that time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 3, 15).unwrap().and_hms_opt(16, 20, 0).unwrap();
+    /// assert_eq!(dt.floor_to_month(), Utc.at(2024, 3, 1, 0, 0, 0));
+    /// ```
+    pub fn floor_to_month(&self) -> LocalResult<DateTime<Tz>> {
+        self.start_of_month(self.year(), self.month())
+    }
+
+    /// Returns the local midnight at the start of the first day of the local calendar quarter,
+    /// which begins in January, April, July or October.
+    ///
+    /// The midnight is resolved as in [`floor_to_month`](#method.floor_to_month).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 3, 15).unwrap().and_hms_opt(16, 20, 0).unwrap();
+    /// assert_eq!(dt.floor_to_quarter(), Utc.at(2024, 1, 1, 0, 0, 0));
+    /// ```
+    pub fn floor_to_quarter(&self) -> LocalResult<DateTime<Tz>> {
+        self.start_of_month(self.year(), (self.month() - 1) / 3 * 3 + 1)
+    }
+
+    /// Returns the local midnight at the start of January 1 of the local year.
+    ///
+    /// The midnight is resolved as in [`floor_to_month`](#method.floor_to_month).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 3, 15).unwrap().and_hms_opt(16, 20, 0).unwrap();
+    /// assert_eq!(dt.floor_to_year(), Utc.at(2024, 1, 1, 0, 0, 0));
+    /// ```
+    pub fn floor_to_year(&self) -> LocalResult<DateTime<Tz>> {
+        self.start_of_month(self.year(), 1)
+    }
+
+    /// Returns the local midnight at the start of the first day of the next local month, or
+    /// `self` if it already is at the start of a month.
+    ///
+    /// The midnight is resolved as in [`floor_to_month`](#method.floor_to_month).
+    /// `LocalResult::None` is also returned when the next month is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{LocalResult, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 3, 15).unwrap().and_hms_opt(16, 20, 0).unwrap();
+    /// assert_eq!(dt.ceil_to_month(), Utc.at(2024, 4, 1, 0, 0, 0));
+    ///
+    /// let start = Utc.ymd_opt(2024, 3, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(start.ceil_to_month(), LocalResult::Single(start));
+    /// ```
+    pub fn ceil_to_month(&self) -> LocalResult<DateTime<Tz>> {
+        if self.is_start_of_month(self.month()) {
+            return LocalResult::Single(self.clone());
+        }
+        match self.month() {
+            12 => self.start_of_month(self.year() + 1, 1),
+            month => self.start_of_month(self.year(), month + 1),
+        }
+    }
+
+    /// Returns the local midnight at the start of January 1 of the next local year, or `self` if
+    /// it already is at the start of a year.
+    ///
+    /// The midnight is resolved as in [`floor_to_month`](#method.floor_to_month).
+    /// `LocalResult::None` is also returned when the next year is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2024, 12, 15).unwrap().and_hms_opt(16, 20, 0).unwrap();
+    /// assert_eq!(dt.ceil_to_year(), Utc.at(2025, 1, 1, 0, 0, 0));
+    /// ```
+    pub fn ceil_to_year(&self) -> LocalResult<DateTime<Tz>> {
+        if self.is_start_of_month(1) {
+            return LocalResult::Single(self.clone());
+        }
+        self.start_of_month(self.year() + 1, 1)
+    }
+
+    /// Returns the local midnight at the start of the given month.
+    fn start_of_month(&self, year: i32, month: u32) -> LocalResult<DateTime<Tz>> {
+        match NaiveDate::from_ymd_opt(year, month, 1).and_then(|d| d.and_hms_opt(0, 0, 0)) {
+            Some(midnight) => Tz::from_offset(&self.offset).from_local_datetime(&midnight),
+            None => LocalResult::None,
+        }
+    }
+
+    /// Returns true if the local time is midnight at the start of `month`.
+    fn is_start_of_month(&self, month: u32) -> bool {
+        let local = self.naive_local();
+        local.month() == month
+            && local.day() == 1
+            && local.num_seconds_from_midnight() == 0
+            && local.nanosecond() == 0
+    }
+
+    /// Returns the next datetime after `self` at the local wall-clock `time`.
+    ///
+    /// This is today if `time` is still ahead of the local time of `self`, and tomorrow otherwise.
+    /// The comparison is made on the local wall-clock times, so a `time` equal to the current
+    /// local time gives tomorrow.
+    ///
+    /// The target date and time are resolved in the time zone, which can give
+    /// `LocalResult::None` or `LocalResult::Ambiguous` around a DST transition on that day.
+    /// `LocalResult::None` is also returned when tomorrow is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{LocalResult, NaiveTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(9, 30, 0).unwrap();
+    /// let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    /// let eight = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    /// assert_eq!(dt.next_occurrence_of(noon), Utc.at(2023, 5, 1, 12, 0, 0));
+    /// assert_eq!(dt.next_occurrence_of(eight), Utc.at(2023, 5, 2, 8, 0, 0));
+    /// ```
+    pub fn next_occurrence_of(&self, time: NaiveTime) -> LocalResult<DateTime<Tz>> {
+        let local = self.naive_local();
+        let date = if time > local.time() {
+            local.date()
+        } else {
+            match local.date().succ_opt() {
+                Some(date) => date,
+                None => return LocalResult::None,
+            }
+        };
+        Tz::from_offset(&self.offset).from_local_datetime(&date.and_time(time))
+    }
+
+    /// Rounds to the nearest local midnight.
+    ///
+    /// Returns the start of the local day if the local time is before noon, and the start of the
+    /// next local day otherwise; noon itself rounds up. Only the wall clock is considered, so days
+    /// made longer or shorter by a DST transition still round at 12:00.
+    ///
+    /// The start of a day is local midnight, or the earlier instant if midnight is ambiguous.
+    /// If midnight falls in a gap, the day starts when the gap ends.
+    ///
+    /// Returns `None` if the result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let morning = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(11, 59, 59).unwrap();
+    /// let noon = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(morning.round_to_nearest_day(), Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(0, 0, 0));
+    /// assert_eq!(noon.round_to_nearest_day(), Utc.ymd_opt(2023, 5, 2).unwrap().and_hms_opt(0, 0, 0));
+    /// ```
+    pub fn round_to_nearest_day(&self) -> Option<DateTime<Tz>> {
+        let local = self.naive_local();
+        let date = if local.hour() < 12 { local.date() } else { local.date().succ_opt()? };
+        start_of_local_day(&Tz::from_offset(&self.offset), date)
+    }
+
+    /// Rounds down to a multiple of `step` counted from the start of the local day.
+    ///
+    /// Unlike [`DurationRound::duration_trunc`](./trait.DurationRound.html), which works on a grid
+    /// anchored at the UNIX epoch, the grid restarts at every local day. With a 15-minute step
+    /// the result is always on a local quarter hour, even for offsets that are not whole hours.
+    /// On a day with a DST transition, the elapsed time since the start of the day is used, so
+    /// the grid follows the wall clock again once the transition has passed.
+    ///
+    /// The start of a day is determined as in [`round_to_nearest_day`](#method.round_to_nearest_day).
+    ///
+    /// Returns `None` if `step` is not positive or does not evenly divide 24 hours, or if the
+    /// result would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeDelta, TimeZone};
+    ///
+    /// let tz = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+    /// let dt = tz.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(10, 52, 30).unwrap();
+    /// assert_eq!(
+    ///     dt.quantize_local(TimeDelta::minutes(15)),
+    ///     tz.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(10, 45, 0)
+    /// );
+    /// assert_eq!(dt.quantize_local(TimeDelta::minutes(7)), None);
+    /// ```
+    pub fn quantize_local(&self, step: TimeDelta) -> Option<DateTime<Tz>> {
+        const NANOS_PER_DAY: i64 = 86_400_000_000_000;
+        let step = step.num_nanoseconds()?;
+        if step <= 0 || NANOS_PER_DAY % step != 0 {
+            return None;
+        }
+        let start = start_of_local_day(&Tz::from_offset(&self.offset), self.naive_local().date())?;
+        let elapsed = self.datetime.signed_duration_since(start.datetime).num_nanoseconds()?;
+        start.checked_add_signed(TimeDelta::nanoseconds(elapsed - elapsed.rem_euclid(step)))
+    }
+
+    /// Combines the local date of `self` with the local time of day of `other`, in the time zone
+    /// of `self`.
+    ///
+    /// The time is taken as shown on the wall clock of `other`, whatever its time zone. The result
+    /// is resolved in the time zone of `self` again, so it can be `None` or `Ambiguous` around a
+    /// DST transition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
+    /// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let reference = tz.ymd_opt(2020, 1, 1).unwrap().and_hms_opt(14, 30, 0).unwrap();
+    /// assert_eq!(dt.with_time_from(&reference), Utc.at(2023, 5, 1, 14, 30, 0));
+    /// ```
+    pub fn with_time_from<Tz2: TimeZone>(
+        &self,
+        other: &DateTime<Tz2>,
+    ) -> LocalResult<DateTime<Tz>> {
+        let datetime = self.naive_local().date().and_time(other.naive_local().time());
+        Tz::from_offset(&self.offset).from_local_datetime(&datetime)
+    }
+
+    /// Adds `n` business days (Monday to Friday) to the local date, keeping the local time.
+    ///
+    /// Saturdays and Sundays are skipped, so adding one business day to a Friday gives the next
+    /// Monday. A negative `n` goes backward. When starting on a weekend, the first business day
+    /// in the direction of travel counts as the first step.
+    ///
+    /// The result is resolved in the time zone again, and `None` is returned if that fails
+    /// or if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let fri = Utc.ymd_opt(2023, 5, 5).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let mon = Utc.ymd_opt(2023, 5, 8).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// assert_eq!(fri.add_business_days(1), Some(mon));
+    /// assert_eq!(mon.add_business_days(-1), Some(fri));
+    /// ```
+    pub fn add_business_days(&self, n: i64) -> Option<DateTime<Tz>> {
+        let local = self.naive_local();
+        let mut date = local.date();
+
+        // on a weekend, start from the adjacent business day behind the direction of travel
+        let weekday = date.weekday().num_days_from_monday();
+        if weekday >= 5 {
+            date = match n.cmp(&0) {
+                Ordering::Greater => date.checked_sub_days(Days::new(u64::from(weekday - 4)))?,
+                Ordering::Less => date.checked_add_days(Days::new(u64::from(7 - weekday)))?,
+                Ordering::Equal => date,
+            };
+        }
+
+        // every five business days are a whole week
+        let weeks = Days::new((n / 5).abs() as u64 * 7);
+        date = if n >= 0 { date.checked_add_days(weeks)? } else { date.checked_sub_days(weeks)? };
+        for _ in 0..(n % 5).abs() {
+            loop {
+                date = if n > 0 { date.succ_opt()? } else { date.pred_opt()? };
+                if date.weekday().num_days_from_monday() < 5 {
+                    break;
+                }
+            }
+        }
+
+        date.and_time(local.time()).and_local_timezone(Tz::from_offset(&self.offset)).single()
+    }
+
+    /// Returns the number of business days (Monday to Friday) from `self` to `other`.
+    ///
+    /// The days counted are the local dates from the date of `self` up to, but not including,
+    /// the date of `other`; the times of day are ignored.
+    /// The result is negative if `other` is before `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// // Monday to the next Monday
+    /// let mon = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let next_mon = Utc.ymd_opt(2023, 5, 8).unwrap().and_hms_opt(17, 0, 0).unwrap();
+    /// assert_eq!(mon.business_days_between(&next_mon), 5);
+    /// assert_eq!(next_mon.business_days_between(&mon), -5);
+    /// ```
+    pub fn business_days_between(&self, other: &DateTime<Tz>) -> i64 {
+        self.business_days_between_excluding(other, &[])
+    }
+
+    /// Returns the number of business days (Monday to Friday) from `self` to `other`,
+    /// not counting the given `holidays`.
+    ///
+    /// This is the same as [`business_days_between`](#method.business_days_between), except that
+    /// holidays within the span are skipped as well. Holidays falling on a weekend are ignored,
+    /// and a holiday listed more than once is only skipped once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, TimeZone, Utc};
+    ///
+    /// let mon = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    /// let next_mon = Utc.ymd_opt(2023, 5, 8).unwrap().and_hms_opt(17, 0, 0).unwrap();
+    /// let holidays = [NaiveDate::from_ymd_opt(2023, 5, 1).unwrap()];
+    /// assert_eq!(mon.business_days_between_excluding(&next_mon, &holidays), 4);
+    /// ```
+    pub fn business_days_between_excluding(
+        &self,
+        other: &DateTime<Tz>,
+        holidays: &[NaiveDate],
+    ) -> i64 {
+        let from = self.naive_local().date();
+        let to = other.naive_local().date();
+        if from <= to {
+            business_days(from, to, holidays)
+        } else {
+            -business_days(to, from, holidays)
+        }
+    }
+
+    /// The minimum possible `DateTime<Utc>` (January 1, 262145 BCE, 00:00:00 UTC).
+    pub const MIN_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MIN, offset: Utc };
+    /// The maximum possible `DateTime<Utc>` (December 31, 262143 CE, 23:59:59.999999999 UTC).
+    pub const MAX_UTC: DateTime<Utc> = DateTime { datetime: NaiveDateTime::MAX, offset: Utc };
+}
+
+/// Returns the first instant of the local `date` in `tz`.
+///
+/// If midnight falls in a gap, this is the instant the gap ends, wherever the gap started.
+/// Returns `None` if the whole day is skipped.
+pub(crate) fn start_of_local_day<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> Option<DateTime<Tz>> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    match tz.from_local_datetime(&midnight) {
+        LocalResult::Single(dt) => return Some(dt),
+        LocalResult::Ambiguous(earliest, _) => return Some(earliest),
+        LocalResult::None => {}
+    }
+
+    // Midnight is skipped. Search forward by the hour for a valid local time on the same day...
+    let is_valid =
+        |local: &NaiveDateTime| tz.offset_from_local_datetime(local).earliest().is_some();
+    let mut skipped = midnight;
+    let mut valid = None;
+    for hours in 1..24 {
+        let local = midnight + TimeDelta::hours(hours);
+        if is_valid(&local) {
+            valid = Some(local);
+            break;
+        }
+        skipped = local;
+    }
+    let mut valid = valid?;
+    // ...and narrow it down to the first valid second, which is where the gap ends.
+    while valid - skipped > TimeDelta::seconds(1) {
+        let mid = skipped + (valid - skipped) / 2;
+        if is_valid(&mid) {
+            valid = mid;
+        } else {
+            skipped = mid;
+        }
+    }
+    tz.from_local_datetime(&valid).earliest()
+}
+
+/// Counts the business days in `[from, to)`, skipping `holidays`. Requires `from <= to`.
+fn business_days(from: NaiveDate, to: NaiveDate, holidays: &[NaiveDate]) -> i64 {
+    let is_business_day = |date: &NaiveDate| date.weekday().num_days_from_monday() < 5;
+
+    let days = to.signed_duration_since(from).num_days();
+    let mut count = days / 7 * 5;
+    let first = i64::from(from.weekday().num_days_from_monday());
+    count += (first..first + days % 7).filter(|day| day % 7 < 5).count() as i64;
+
+    for (i, holiday) in holidays.iter().enumerate() {
+        if (from..to).contains(holiday)
+            && is_business_day(holiday)
+            && !holidays[..i].contains(holiday)
+        {
+            count -= 1;
+        }
+    }
+    count
+}
+
+impl Default for DateTime<Utc> {
+    fn default() -> Self {
+        Utc.from_utc_datetime(&NaiveDateTime::default())
+    }
+}
+
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+impl Default for DateTime<Local> {
+    fn default() -> Self {
+        Local.from_utc_datetime(&NaiveDateTime::default())
+    }
+}
+
+impl Default for DateTime<FixedOffset> {
+    fn default() -> Self {
+        FixedOffset::west_opt(0).unwrap().from_utc_datetime(&NaiveDateTime::default())
+    }
+}
+
+/// Convert a `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
+impl From<DateTime<Utc>> for DateTime<FixedOffset> {
+    /// Convert this `DateTime<Utc>` instance into a `DateTime<FixedOffset>` instance.
+    ///
+    /// Conversion is done via [`DateTime::with_timezone`]. Note that the converted value returned by
+    /// this will be created with a fixed timezone offset of 0.
+    fn from(src: DateTime<Utc>) -> Self {
+        src.with_timezone(&FixedOffset::east_opt(0).unwrap())
+    }
+}
+
+/// Convert a `DateTime<Utc>` instance into a `DateTime<Local>` instance.
 #[cfg(feature = "clock")]
 #[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
 impl From<DateTime<Utc>> for DateTime<Local> {
@@ -489,110 +1486,433 @@ impl From<DateTime<FixedOffset>> for DateTime<Local> {
     }
 }
 
-/// Convert a `DateTime<Local>` instance into a `DateTime<Utc>` instance.
-#[cfg(feature = "clock")]
-#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
-impl From<DateTime<Local>> for DateTime<Utc> {
-    /// Convert this `DateTime<Local>` instance into a `DateTime<Utc>` instance.
+/// Convert a `DateTime<Local>` instance into a `DateTime<Utc>` instance.
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+impl From<DateTime<Local>> for DateTime<Utc> {
+    /// Convert this `DateTime<Local>` instance into a `DateTime<Utc>` instance.
+    ///
+    /// Conversion is performed via [`DateTime::with_timezone`], accounting for the difference in
+    /// timezones.
+    fn from(src: DateTime<Local>) -> Self {
+        src.with_timezone(&Utc)
+    }
+}
+
+/// Convert a `DateTime<Local>` instance into a `DateTime<FixedOffset>` instance.
+#[cfg(feature = "clock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
+impl From<DateTime<Local>> for DateTime<FixedOffset> {
+    /// Convert this `DateTime<Local>` instance into a `DateTime<FixedOffset>` instance.
+    ///
+    /// Conversion is performed via [`DateTime::with_timezone`]. Note that the converted value returned
+    /// by this will be created with a fixed timezone offset of 0.
+    fn from(src: DateTime<Local>) -> Self {
+        src.with_timezone(&FixedOffset::east_opt(0).unwrap())
+    }
+}
+
+/// Maps the local datetime to other datetime with given conversion function.
+fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> Option<DateTime<Tz>>
+where
+    F: FnMut(NaiveDateTime) -> Option<NaiveDateTime>,
+{
+    f(dt.naive_local()).and_then(|datetime| dt.timezone().from_local_datetime(&datetime).single())
+}
+
+impl DateTime<FixedOffset> {
+    /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value.
+    ///
+    /// This parses valid RFC 2822 datetime strings (such as `Tue, 1 Jul 2003 10:52:37 +0200`)
+    /// and returns a new [`DateTime`] instance with the parsed timezone as the [`FixedOffset`].
+    ///
+    /// RFC 2822 is the internet message standard that specifies the representation of times in HTTP
+    /// and email headers.
+    ///
+    /// ```
+    /// # use chrono::{DateTime, FixedOffset, TimeZone};
+    /// assert_eq!(
+    ///     DateTime::<FixedOffset>::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 GMT").unwrap(),
+    ///     FixedOffset::east_opt(0).unwrap().ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9).unwrap()
+    /// );
+    /// ```
+    pub fn parse_from_rfc2822(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC2822)];
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.to_datetime()
+    }
+
+    /// Parses an RFC 3339 date-and-time string into a `DateTime<FixedOffset>` value.
+    ///
+    /// Parses all valid RFC 3339 values (as well as the subset of valid ISO 8601 values that are
+    /// also valid RFC 3339 date-and-time values) and returns a new [`DateTime`] with a
+    /// [`FixedOffset`] corresponding to the parsed timezone. While RFC 3339 values come in a wide
+    /// variety of shapes and sizes, `1996-12-19T16:39:57-08:00` is an example of the most commonly
+    /// encountered variety of RFC 3339 formats.
+    ///
+    /// Why isn't this named `parse_from_iso8601`? That's because ISO 8601 allows representing
+    /// values in a wide range of formats, only some of which represent actual date-and-time
+    /// instances (rather than periods, ranges, dates, or times). Some valid ISO 8601 values are
+    /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
+    /// values (or the other way around).
+    pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        if let Some(dt) = parse_rfc3339_fast(s) {
+            return Ok(dt);
+        }
+
+        const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC3339)];
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.to_datetime()
+    }
+
+    /// Parses an RFC 3339 date-and-time from a byte slice, such as a network buffer,
+    /// into a `DateTime<FixedOffset>` value.
+    ///
+    /// This behaves exactly like [`parse_from_rfc3339`](#method.parse_from_rfc3339) on the
+    /// same input as a string, and returns an error if the bytes are not valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    ///
+    /// let dt = DateTime::<FixedOffset>::parse_from_rfc3339_bytes(b"1996-12-19T16:39:57-08:00");
+    /// assert_eq!(dt, DateTime::<FixedOffset>::parse_from_rfc3339("1996-12-19T16:39:57-08:00"));
+    /// assert!(DateTime::<FixedOffset>::parse_from_rfc3339_bytes(b"1996-12-19T16:39:57\xff").is_err());
+    /// ```
+    pub fn parse_from_rfc3339_bytes(b: &[u8]) -> ParseResult<DateTime<FixedOffset>> {
+        let s = str::from_utf8(b).map_err(|_| INVALID)?;
+        DateTime::<FixedOffset>::parse_from_rfc3339(s)
+    }
+
+    /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value.
+    ///
+    /// Note that this method *requires a timezone* in the input string. See
+    /// [`NaiveDateTime::parse_from_str`](./naive/struct.NaiveDateTime.html#method.parse_from_str)
+    /// for a version that does not require a timezone in the to-be-parsed str. The returned
+    /// [`DateTime`] value will have a [`FixedOffset`] reflecting the parsed timezone.
+    ///
+    /// See the [`format::strftime` module](./format/strftime/index.html) for supported format
+    /// sequences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let dt = DateTime::<FixedOffset>::parse_from_str(
+    ///     "1983 Apr 13 12:09:14.274 +0000", "%Y %b %d %H:%M:%S%.3f %z");
+    /// assert_eq!(dt, Ok(FixedOffset::east_opt(0).unwrap().ymd_opt(1983, 4, 13).unwrap().and_hms_milli_opt(12, 9, 14, 274).unwrap()));
+    /// ```
+    pub fn parse_from_str(s: &str, fmt: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.to_datetime()
+    }
+
+    /// Parses a string with the specified format string, using `default` as the offset
+    /// when the string does not contain one.
+    ///
+    /// Unlike [`parse_from_str`](#method.parse_from_str) this accepts formats without an offset,
+    /// which is handy for logs known to be written in a fixed time zone.
+    /// An offset present in the string is still honored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    ///
+    /// let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let dt = DateTime::parse_from_str_with_default_offset(
+    ///     "2014-05-07 12:00:00", "%Y-%m-%d %H:%M:%S", kst);
+    /// assert_eq!(dt, Ok(kst.ymd_opt(2014, 5, 7).unwrap().and_hms_opt(12, 0, 0).unwrap()));
+    ///
+    /// let dt = DateTime::parse_from_str_with_default_offset(
+    ///     "2014-05-07 12:00:00 +0000", "%Y-%m-%d %H:%M:%S %z", kst).unwrap();
+    /// assert_eq!(dt.offset().local_minus_utc(), 0);
+    /// ```
+    pub fn parse_from_str_with_default_offset(
+        s: &str,
+        fmt: &str,
+        default: FixedOffset,
+    ) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        if parsed.offset.is_none() {
+            parsed.set_offset(i64::from(default.local_minus_utc()))?;
+        }
+        parsed.to_datetime()
+    }
+
+    /// Parses a string with the specified format string, resolving a time zone name
+    /// matched by `%Z` to an offset with the given `resolver`.
+    ///
+    /// Chrono itself cannot map names like `America/New_York` or `EST` to an offset,
+    /// so the caller supplies the mapping, e.g. a lookup table or a time zone database.
+    /// If the input also contains a numeric offset, it must agree with the resolved one.
+    ///
+    /// Returns an error of kind [`ParseErrorKind::Invalid`](./format/enum.ParseErrorKind.html#variant.Invalid)
+    /// if the resolver returns `None` for the parsed name, and one of kind
+    /// [`ParseErrorKind::Impossible`](./format/enum.ParseErrorKind.html#variant.Impossible)
+    /// if a numeric offset in the input disagrees with the resolved one.
+    /// Input without a time zone name is handled like [`parse_from_str`](#method.parse_from_str).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::format::ParseErrorKind;
+    /// use chrono::{DateTime, FixedOffset};
+    ///
+    /// let resolver = |name: &str| match name {
+    ///     "EST" => FixedOffset::west_opt(5 * 3600),
+    ///     "CET" => FixedOffset::east_opt(3600),
+    ///     _ => None,
+    /// };
+    /// let dt = DateTime::parse_from_str_with_tz_resolver(
+    ///     "2014-11-28 12:00:09 EST", "%Y-%m-%d %H:%M:%S %Z", resolver).unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2014-11-28T12:00:09-05:00");
+    ///
+    /// let err = DateTime::parse_from_str_with_tz_resolver(
+    ///     "2014-11-28 12:00:09 XYZ", "%Y-%m-%d %H:%M:%S %Z", resolver).unwrap_err();
+    /// assert_eq!(err.kind(), ParseErrorKind::Invalid);
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn parse_from_str_with_tz_resolver<F>(
+        s: &str,
+        fmt: &str,
+        resolver: F,
+    ) -> ParseResult<DateTime<FixedOffset>>
+    where
+        F: Fn(&str) -> Option<FixedOffset>,
+    {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        let resolved = match parsed.timezone_name() {
+            // the name is present but unknown to the resolver
+            Some(name) => Some(resolver(name).ok_or(INVALID)?),
+            None => None,
+        };
+        if let Some(offset) = resolved {
+            parsed.set_offset(i64::from(offset.local_minus_utc()))?;
+        }
+        parsed.to_datetime()
+    }
+
+    /// Converts the instant of `self` into the offset of `other`.
+    ///
+    /// This is the same as `self.with_timezone(other.offset())`, and is handy when normalizing
+    /// values to the offset of a reference datetime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    ///
+    /// let reference = DateTime::<FixedOffset>::parse_from_rfc3339("2023-01-01T00:00:00+05:00").unwrap();
+    /// let dt = DateTime::<FixedOffset>::parse_from_rfc3339("2023-06-15T12:00:00+00:00").unwrap();
+    /// assert_eq!(dt.with_offset_from(&reference).to_rfc3339(), "2023-06-15T17:00:00+05:00");
+    /// ```
+    pub fn with_offset_from(&self, other: &DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        self.with_timezone(other.offset())
+    }
+
+    /// Adds `delta` to the offset of `self` while keeping the local date and time unchanged.
+    ///
+    /// Unlike [`with_timezone`](#method.with_timezone), this **changes the represented instant**:
+    /// the same wall-clock time is reinterpreted at the new offset, so shifting by `+01:00` moves
+    /// the instant one hour earlier.
+    ///
+    /// Returns `None` if the resulting offset is out of bounds (a day or more), or if the
+    /// resulting instant is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, FixedOffset};
+    ///
+    /// let dt = DateTime::<FixedOffset>::parse_from_rfc3339("2023-06-15T12:00:00+00:00").unwrap();
+    /// let shifted = dt.shift_offset(FixedOffset::east_opt(3600).unwrap()).unwrap();
+    /// assert_eq!(shifted.to_rfc3339(), "2023-06-15T12:00:00+01:00");
+    /// assert_eq!(shifted.signed_duration_since(dt).num_hours(), -1);
+    /// ```
+    pub fn shift_offset(&self, delta: FixedOffset) -> Option<DateTime<FixedOffset>> {
+        let offset =
+            FixedOffset::east_opt(self.offset.local_minus_utc() + delta.local_minus_utc())?;
+        let utc = self
+            .naive_local()
+            .checked_sub_signed(TimeDelta::seconds(i64::from(offset.local_minus_utc())))?;
+        Some(DateTime::from_utc(utc, offset))
+    }
+}
+
+impl DateTime<Utc> {
+    /// Makes a new `DateTime<Utc>` from the number of non-leap seconds since January 1, 1970
+    /// 0:00:00 UTC and the number of nanoseconds since the last second boundary, as returned by
+    /// [`DateTime::as_timestamp_parts`].
     ///
-    /// Conversion is performed via [`DateTime::with_timezone`], accounting for the difference in
-    /// timezones.
-    fn from(src: DateTime<Local>) -> Self {
-        src.with_timezone(&Utc)
+    /// The nanosecond part can exceed 1,000,000,000 in order to represent a leap second.
+    ///
+    /// Returns `None` on the out-of-range number of seconds and/or invalid nanosecond.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2015, 6, 30).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    /// let (secs, nanos) = dt.as_timestamp_parts();
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_parts(secs, nanos), Some(dt));
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_parts(0, 2_000_000_000), None);
+    /// ```
+    #[inline]
+    pub fn from_timestamp_parts(secs: i64, nanos: u32) -> Option<DateTime<Utc>> {
+        NaiveDateTime::from_timestamp_opt(secs, nanos)
+            .map(|datetime| DateTime::from_utc(datetime, Utc))
     }
-}
 
-/// Convert a `DateTime<Local>` instance into a `DateTime<FixedOffset>` instance.
-#[cfg(feature = "clock")]
-#[cfg_attr(docsrs, doc(cfg(feature = "clock")))]
-impl From<DateTime<Local>> for DateTime<FixedOffset> {
-    /// Convert this `DateTime<Local>` instance into a `DateTime<FixedOffset>` instance.
+    /// Makes a new `DateTime<Utc>` from the number of non-leap microseconds since
+    /// January 1, 1970 0:00:00 UTC, as returned by [`DateTime::timestamp_micros`].
     ///
-    /// Conversion is performed via [`DateTime::with_timezone`]. Note that the converted value returned
-    /// by this will be created with a fixed timezone offset of 0.
-    fn from(src: DateTime<Local>) -> Self {
-        src.with_timezone(&FixedOffset::east_opt(0).unwrap())
+    /// Returns `None` on an out-of-range number of microseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2023, 5, 17).unwrap().and_hms_micro_opt(8, 30, 15, 123_456).unwrap();
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_micros(dt.timestamp_micros()), Some(dt));
+    /// assert_eq!(
+    ///     DateTime::<Utc>::from_timestamp_micros(-1),
+    ///     Some(Utc.ymd_opt(1969, 12, 31).unwrap().and_hms_micro_opt(23, 59, 59, 999_999).unwrap())
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_timestamp_micros(micros: i64) -> Option<DateTime<Utc>> {
+        let secs = micros.div_euclid(1_000_000);
+        let nanos = micros.rem_euclid(1_000_000) as u32 * 1_000;
+        DateTime::<Utc>::from_timestamp_parts(secs, nanos)
     }
-}
 
-/// Maps the local datetime to other datetime with given conversion function.
-fn map_local<Tz: TimeZone, F>(dt: &DateTime<Tz>, mut f: F) -> Option<DateTime<Tz>>
-where
-    F: FnMut(NaiveDateTime) -> Option<NaiveDateTime>,
-{
-    f(dt.naive_local()).and_then(|datetime| dt.timezone().from_local_datetime(&datetime).single())
-}
+    /// Makes a new `DateTime<Utc>` from the number of non-leap milliseconds since
+    /// January 1, 1970 0:00:00 UTC, as returned by [`DateTime::timestamp_millis`].
+    ///
+    /// An out-of-range number of milliseconds is clamped to [`DateTime::MIN_UTC`] or
+    /// [`DateTime::MAX_UTC`]. Use [`TimeZone::timestamp_millis_opt`] to detect it instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2023, 5, 17).unwrap().and_hms_milli_opt(8, 30, 15, 123).unwrap();
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_millis_saturating(dt.timestamp_millis()), dt);
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_millis_saturating(i64::max_value()), DateTime::<Utc>::MAX_UTC);
+    /// ```
+    pub fn from_timestamp_millis_saturating(millis: i64) -> DateTime<Utc> {
+        let secs = millis.div_euclid(1_000);
+        let nanos = millis.rem_euclid(1_000) as u32 * 1_000_000;
+        match DateTime::<Utc>::from_timestamp_parts(secs, nanos) {
+            Some(dt) => dt,
+            None if millis < 0 => DateTime::<Utc>::MIN_UTC,
+            None => DateTime::<Utc>::MAX_UTC,
+        }
+    }
 
-impl DateTime<FixedOffset> {
-    /// Parses an RFC 2822 date-and-time string into a `DateTime<FixedOffset>` value.
+    /// Makes a new `DateTime<Utc>` from the number of seconds since January 1, 1970 0:00:00 UTC
+    /// given as a floating point number, as returned by [`DateTime::timestamp_f64`].
     ///
-    /// This parses valid RFC 2822 datetime strings (such as `Tue, 1 Jul 2003 10:52:37 +0200`)
-    /// and returns a new [`DateTime`] instance with the parsed timezone as the [`FixedOffset`].
+    /// The fractional part is rounded to the nearest nanosecond. Note that an `f64` cannot
+    /// represent every nanosecond: dates near the present are only precise to a fraction of
+    /// a microsecond, and the precision degrades for larger magnitudes.
     ///
-    /// RFC 2822 is the internet message standard that specifies the representation of times in HTTP
-    /// and email headers.
+    /// Returns `None` on a non-finite or out-of-range number of seconds.
+    ///
+    /// # Example
     ///
     /// ```
-    /// # use chrono::{DateTime, FixedOffset, TimeZone};
-    /// assert_eq!(
-    ///     DateTime::<FixedOffset>::parse_from_rfc2822("Wed, 18 Feb 2015 23:16:09 GMT").unwrap(),
-    ///     FixedOffset::east_opt(0).unwrap().ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9).unwrap()
-    /// );
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_milli_opt(0, 0, 1, 500).unwrap();
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_f64(1.5), Some(dt));
+    /// assert_eq!(DateTime::<Utc>::from_timestamp_f64(std::f64::NAN), None);
     /// ```
-    pub fn parse_from_rfc2822(s: &str) -> ParseResult<DateTime<FixedOffset>> {
-        const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC2822)];
-        let mut parsed = Parsed::new();
-        parse(&mut parsed, s, ITEMS.iter())?;
-        parsed.to_datetime()
+    pub fn from_timestamp_f64(secs: f64) -> Option<DateTime<Utc>> {
+        // this also rules out NaN; casting out-of-range floats is undefined on our MSRV
+        if !(secs > core::i64::MIN as f64 && secs < core::i64::MAX as f64) {
+            return None;
+        }
+        let mut whole = secs as i64;
+        let mut frac = secs - whole as f64;
+        if frac < 0.0 {
+            whole -= 1;
+            frac += 1.0;
+        }
+        let mut nanos = (frac * 1e9 + 0.5) as u32;
+        if nanos >= 1_000_000_000 {
+            whole += 1;
+            nanos -= 1_000_000_000;
+        }
+        DateTime::<Utc>::from_timestamp_parts(whole, nanos)
     }
 
-    /// Parses an RFC 3339 date-and-time string into a `DateTime<FixedOffset>` value.
+    /// Converts a `SystemTime`, returning an error if it lies outside the range of `DateTime`.
     ///
-    /// Parses all valid RFC 3339 values (as well as the subset of valid ISO 8601 values that are
-    /// also valid RFC 3339 date-and-time values) and returns a new [`DateTime`] with a
-    /// [`FixedOffset`] corresponding to the parsed timezone. While RFC 3339 values come in a wide
-    /// variety of shapes and sizes, `1996-12-19T16:39:57-08:00` is an example of the most commonly
-    /// encountered variety of RFC 3339 formats.
+    /// The `From<SystemTime>` implementation panics in that case instead.
     ///
-    /// Why isn't this named `parse_from_iso8601`? That's because ISO 8601 allows representing
-    /// values in a wide range of formats, only some of which represent actual date-and-time
-    /// instances (rather than periods, ranges, dates, or times). Some valid ISO 8601 values are
-    /// also simultaneously valid RFC 3339 values, but not all RFC 3339 values are valid ISO 8601
-    /// values (or the other way around).
-    pub fn parse_from_rfc3339(s: &str) -> ParseResult<DateTime<FixedOffset>> {
-        const ITEMS: &[Item<'static>] = &[Item::Fixed(Fixed::RFC3339)];
-        let mut parsed = Parsed::new();
-        parse(&mut parsed, s, ITEMS.iter())?;
-        parsed.to_datetime()
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let t = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+    /// let dt = Utc.ymd_opt(2001, 9, 9).unwrap().and_hms_opt(1, 46, 40).unwrap();
+    /// assert_eq!(DateTime::<Utc>::try_from_system_time(t), Ok(dt));
+    /// ```
+    #[cfg(any(feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn try_from_system_time(t: SystemTime) -> Result<DateTime<Utc>, OutOfRange> {
+        let (sec, nsec) = match t.duration_since(UNIX_EPOCH) {
+            Ok(dur) => {
+                (i64::try_from(dur.as_secs()).map_err(|_| OutOfRange::new())?, dur.subsec_nanos())
+            }
+            Err(e) => {
+                // unlikely but should be handled
+                let dur = e.duration();
+                let sec = i64::try_from(dur.as_secs()).map_err(|_| OutOfRange::new())?;
+                match dur.subsec_nanos() {
+                    0 => (-sec, 0),
+                    nsec => (-sec - 1, 1_000_000_000 - nsec),
+                }
+            }
+        };
+        DateTime::<Utc>::from_timestamp_parts(sec, nsec).ok_or_else(OutOfRange::new)
     }
 
-    /// Parses a string from a user-specified format into a `DateTime<FixedOffset>` value.
+    /// Makes a new `DateTime<Utc>` from a Julian Date,
+    /// the inverse of [`to_julian_date`](#method.to_julian_date).
     ///
-    /// Note that this method *requires a timezone* in the input string. See
-    /// [`NaiveDateTime::parse_from_str`](./naive/struct.NaiveDateTime.html#method.parse_from_str)
-    /// for a version that does not require a timezone in the to-be-parsed str. The returned
-    /// [`DateTime`] value will have a [`FixedOffset`] reflecting the parsed timezone.
+    /// The result is rounded to the nearest nanosecond, but is only as precise as the `f64`
+    /// input: near the present that is tens of microseconds.
     ///
-    /// See the [`format::strftime` module](./format/strftime/index.html) for supported format
-    /// sequences.
+    /// Returns `None` on a non-finite or out-of-range Julian Date.
     ///
     /// # Example
     ///
-    /// ```rust
-    /// use chrono::{DateTime, FixedOffset, TimeZone};
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
     ///
-    /// let dt = DateTime::<FixedOffset>::parse_from_str(
-    ///     "1983 Apr 13 12:09:14.274 +0000", "%Y %b %d %H:%M:%S%.3f %z");
-    /// assert_eq!(dt, Ok(FixedOffset::east_opt(0).unwrap().ymd_opt(1983, 4, 13).unwrap().and_hms_milli_opt(12, 9, 14, 274).unwrap()));
+    /// let j2000 = Utc.ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// assert_eq!(DateTime::<Utc>::from_julian_date(2_451_545.0), Some(j2000));
     /// ```
-    pub fn parse_from_str(s: &str, fmt: &str) -> ParseResult<DateTime<FixedOffset>> {
-        let mut parsed = Parsed::new();
-        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
-        parsed.to_datetime()
+    pub fn from_julian_date(jd: f64) -> Option<DateTime<Utc>> {
+        DateTime::<Utc>::from_timestamp_f64((jd - UNIX_EPOCH_JULIAN_DATE) * 86_400.0)
     }
-}
 
-impl DateTime<Utc> {
     /// Parses an RFC 2822 date-and-time string into a `DateTime<Utc>` value.
     ///
     /// This parses valid RFC 2822 datetime values (such as `Tue, 1 Jul 2003 10:52:37 +0200`)
@@ -605,6 +1925,27 @@ impl DateTime<Utc> {
         DateTime::<FixedOffset>::parse_from_rfc2822(s).map(|result| result.into())
     }
 
+    /// Parses a C `asctime` string such as `Wed Feb 18 23:16:09 2015` into a `DateTime<Utc>`.
+    ///
+    /// The string carries no offset, so it is interpreted as UTC.
+    /// The day of the month may be padded with a space or not padded at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// assert_eq!(
+    ///     DateTime::<Utc>::parse_from_ctime("Sun Mar  1 09:05:00 2015"),
+    ///     Ok(Utc.ymd_opt(2015, 3, 1).unwrap().and_hms_opt(9, 5, 0).unwrap())
+    /// );
+    /// ```
+    pub fn parse_from_ctime(s: &str) -> ParseResult<DateTime<Utc>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, CTIME_ITEMS.iter())?;
+        parsed.to_naive_datetime_with_offset(0).map(|dt| DateTime::from_utc(dt, Utc))
+    }
+
     /// Parses an RFC 3339 date-and-time string into a `DateTime<Utc>` value.
     ///
     /// Parses all valid RFC 3339 values (as well as the subset of valid ISO 8601 values that are
@@ -646,6 +1987,86 @@ impl DateTime<Utc> {
     pub fn parse_from_str(s: &str, fmt: &str) -> ParseResult<DateTime<Utc>> {
         DateTime::<FixedOffset>::parse_from_str(s, fmt).map(|result| result.into())
     }
+
+    /// Parses a string with each of the format strings in turn, like
+    /// [`parse_from_str`](#method.parse_from_str), and returns the first success.
+    ///
+    /// If no format matches, the most specific error is returned: an error for input that
+    /// matched a format but gave an invalid or incomplete value (`OutOfRange`, `Impossible`,
+    /// `NotEnough`) beats one for input that ran out or had trailing characters (`TooShort`,
+    /// `TooLong`), which beats any other mismatch. Between errors of the same rank the one for
+    /// the earliest format wins. If `fmts` is empty a `NotEnough` error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let fmts = ["%Y-%m-%dT%H:%M:%S%z", "%d/%m/%Y %H:%M %z"];
+    /// let dt = DateTime::<Utc>::parse_from_any("13/04/1983 12:09 +0100", &fmts);
+    /// assert_eq!(dt, Ok(Utc.ymd_opt(1983, 4, 13).unwrap().and_hms_opt(11, 9, 0).unwrap()));
+    /// assert!(DateTime::<Utc>::parse_from_any("1983-04-13", &fmts).is_err());
+    /// ```
+    pub fn parse_from_any(s: &str, fmts: &[&str]) -> ParseResult<DateTime<Utc>> {
+        fn rank(err: ParseError) -> u8 {
+            match err.kind() {
+                ParseErrorKind::OutOfRange
+                | ParseErrorKind::Impossible
+                | ParseErrorKind::NotEnough => 2,
+                ParseErrorKind::TooShort | ParseErrorKind::TooLong => 1,
+                _ => 0,
+            }
+        }
+
+        let mut best_err: Option<ParseError> = None;
+        for fmt in fmts {
+            match DateTime::<Utc>::parse_from_str(s, fmt) {
+                Ok(datetime) => return Ok(datetime),
+                Err(e) => {
+                    if best_err.map_or(true, |best| rank(e) > rank(best)) {
+                        best_err = Some(e);
+                    }
+                }
+            }
+        }
+        Err(best_err.unwrap_or(NOT_ENOUGH))
+    }
+
+    /// Parses an HTTP date (RFC 7231 section 7.1.1.1) into a `DateTime<Utc>` value.
+    ///
+    /// This accepts the preferred IMF-fixdate format (such as `Sun, 06 Nov 1994 08:49:37 GMT`),
+    /// and the two obsolete formats recipients are required to accept: the RFC 850 format
+    /// (such as `Sunday, 06-Nov-94 08:49:37 GMT`) and the ANSI C `asctime()` format
+    /// (such as `Sun Nov  6 08:49:37 1994`).
+    ///
+    /// Two-digit years of the RFC 850 format are read as 1970 to 2069, as with `%y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(1994, 11, 6).unwrap().and_hms_opt(8, 49, 37).unwrap();
+    /// assert_eq!(DateTime::<Utc>::parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(dt));
+    /// assert_eq!(DateTime::<Utc>::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(dt));
+    /// assert_eq!(DateTime::<Utc>::parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(dt));
+    /// ```
+    pub fn parse_http_date(s: &str) -> ParseResult<DateTime<Utc>> {
+        const FORMATS: [&str; 3] =
+            ["%a, %d %b %Y %H:%M:%S GMT", "%A, %d-%b-%y %H:%M:%S GMT", "%a %b %e %H:%M:%S %Y"];
+
+        let mut first_err = None;
+        for fmt in FORMATS.iter() {
+            let mut parsed = Parsed::new();
+            let result = parse(&mut parsed, s, StrftimeItems::new(fmt))
+                .and_then(|_| parsed.to_naive_datetime_with_offset(0));
+            match result {
+                Ok(datetime) => return Ok(DateTime::from_utc(datetime, Utc)),
+                Err(e) => first_err = first_err.or(Some(e)),
+            }
+        }
+        Err(first_err.unwrap())
+    }
 }
 
 impl<Tz: TimeZone> DateTime<Tz>
@@ -668,6 +2089,25 @@ where
         self.format_with_items(ITEMS.iter()).to_string()
     }
 
+    /// Returns a C `asctime` string such as `Wed Feb 18 23:16:09 2015`.
+    ///
+    /// The local date and time are written with fixed-width fields and no offset;
+    /// the day of the month is padded with a space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let dt = Utc.ymd_opt(2015, 3, 1).unwrap().and_hms_opt(9, 5, 0).unwrap();
+    /// assert_eq!(dt.to_ctime_string(), "Sun Mar  1 09:05:00 2015");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_ctime_string(&self) -> String {
+        self.format_with_items(CTIME_ITEMS.iter()).to_string()
+    }
+
     /// Return an RFC 3339 and ISO 8601 date and time string with subseconds
     /// formatted as per `SecondsFormat`.
     ///
@@ -695,47 +2135,116 @@ where
     #[cfg(any(feature = "alloc", feature = "std", test))]
     #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
     pub fn to_rfc3339_opts(&self, secform: SecondsFormat, use_z: bool) -> String {
-        use crate::format::Numeric::*;
-        use crate::format::Pad::Zero;
-        use crate::SecondsFormat::*;
-
-        debug_assert!(secform != __NonExhaustive, "Do not use __NonExhaustive!");
-
-        const PREFIX: &[Item<'static>] = &[
-            Item::Numeric(Year, Zero),
-            Item::Literal("-"),
-            Item::Numeric(Month, Zero),
-            Item::Literal("-"),
-            Item::Numeric(Day, Zero),
-            Item::Literal("T"),
-            Item::Numeric(Hour, Zero),
-            Item::Literal(":"),
-            Item::Numeric(Minute, Zero),
-            Item::Literal(":"),
-            Item::Numeric(Second, Zero),
-        ];
-
-        let ssitem = match secform {
-            Secs => None,
-            Millis => Some(Item::Fixed(Fixed::Nanosecond3)),
-            Micros => Some(Item::Fixed(Fixed::Nanosecond6)),
-            Nanos => Some(Item::Fixed(Fixed::Nanosecond9)),
-            AutoSi => Some(Item::Fixed(Fixed::Nanosecond)),
-            __NonExhaustive => unreachable!(),
-        };
-
         let tzitem = Item::Fixed(if use_z {
             Fixed::TimezoneOffsetColonZ
         } else {
             Fixed::TimezoneOffsetColon
         });
+        self.format_rfc3339_with_offset_item(secform, tzitem)
+    }
+
+    /// Same as [`DateTime::to_rfc3339_opts`], but writes the offset without a colon,
+    /// such as `+0800`.
+    ///
+    /// If `use_z` is true and the timezone is UTC (offset 0), uses `Z` as
+    /// per [`Fixed::TimezoneOffsetZ`]. If `use_z` is false, uses [`Fixed::TimezoneOffset`].
+    ///
+    /// Note that RFC 3339 itself requires the colon; the result is still valid ISO 8601.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, Utc};
+    /// let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    /// let dt = pst.ymd_opt(2018, 1, 26).unwrap().and_hms_micro_opt(10, 30, 9, 453_829).unwrap();
+    /// assert_eq!(dt.to_rfc3339_opts_no_colon(SecondsFormat::Millis, true),
+    ///            "2018-01-26T10:30:09.453+0800");
+    ///
+    /// let dt = dt.with_timezone(&Utc);
+    /// assert_eq!(dt.to_rfc3339_opts_no_colon(SecondsFormat::Secs, true), "2018-01-26T02:30:09Z");
+    /// assert_eq!(dt.to_rfc3339_opts_no_colon(SecondsFormat::Secs, false),
+    ///            "2018-01-26T02:30:09+0000");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_rfc3339_opts_no_colon(&self, secform: SecondsFormat, use_z: bool) -> String {
+        let tzitem =
+            Item::Fixed(if use_z { Fixed::TimezoneOffsetZ } else { Fixed::TimezoneOffset });
+        self.format_rfc3339_with_offset_item(secform, tzitem)
+    }
 
-        match ssitem {
-            None => self.format_with_items(PREFIX.iter().chain([tzitem].iter())).to_string(),
-            Some(s) => self.format_with_items(PREFIX.iter().chain([s, tzitem].iter())).to_string(),
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn format_rfc3339_with_offset_item(&self, secform: SecondsFormat, tzitem: Item) -> String {
+        match secform.subsec_item() {
+            None => {
+                self.format_with_items(RFC3339_PREFIX.iter().chain([tzitem].iter())).to_string()
+            }
+            Some(s) => {
+                self.format_with_items(RFC3339_PREFIX.iter().chain([s, tzitem].iter())).to_string()
+            }
         }
     }
 
+    /// Return an RFC 3339 and ISO 8601 date and time string for the same instant as seen from
+    /// the offset `off`, with subseconds formatted as per `SecondsFormat`.
+    ///
+    /// This is equivalent to `self.with_timezone(&off).to_rfc3339_opts(secform, use_z)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use chrono::{FixedOffset, SecondsFormat, TimeZone, Utc};
+    /// let dt = Utc.ymd_opt(2018, 1, 26).unwrap().and_hms_micro_opt(18, 30, 9, 453_829).unwrap();
+    /// let off = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    /// assert_eq!(dt.to_rfc3339_in_offset(off, SecondsFormat::Millis, true),
+    ///            "2018-01-27T02:30:09.453+08:00");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_rfc3339_in_offset(
+        &self,
+        off: FixedOffset,
+        secform: SecondsFormat,
+        use_z: bool,
+    ) -> String {
+        self.with_timezone(&off).to_rfc3339_opts(secform, use_z)
+    }
+
+    /// Returns an HTTP date string (RFC 7231 IMF-fixdate) such as `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// The date and time are always converted to UTC, which HTTP calls GMT.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone};
+    ///
+    /// let pst = FixedOffset::west_opt(8 * 60 * 60).unwrap();
+    /// let dt = pst.ymd_opt(1994, 11, 6).unwrap().and_hms_opt(0, 49, 37).unwrap();
+    /// assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_http_date(&self) -> String {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Fixed(Fixed::ShortWeekdayName),
+            Item::Literal(", "),
+            Item::Numeric(Numeric::Day, Pad::Zero),
+            Item::Literal(" "),
+            Item::Fixed(Fixed::ShortMonthName),
+            Item::Literal(" "),
+            Item::Numeric(Numeric::Year, Pad::Zero),
+            Item::Literal(" "),
+            Item::Numeric(Numeric::Hour, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Minute, Pad::Zero),
+            Item::Literal(":"),
+            Item::Numeric(Numeric::Second, Pad::Zero),
+            Item::Literal(" GMT"),
+        ];
+        self.naive_utc().format_with_items(ITEMS.iter()).to_string()
+    }
+
     /// Formats the combined date and time with the specified formatting items.
     #[cfg(any(feature = "alloc", feature = "std", test))]
     #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
@@ -746,7 +2255,14 @@ where
         B: Borrow<Item<'a>>,
     {
         let local = self.naive_local();
-        DelayedFormat::new_with_offset(Some(local.date()), Some(local.time()), &self.offset, items)
+        let name = self.timezone().offset_name(&self.datetime);
+        DelayedFormat::new_with_offset_name(
+            Some(local.date()),
+            Some(local.time()),
+            name,
+            self.offset.fix(),
+            items,
+        )
     }
 
     /// Formats the combined date and time per the specified format string.
@@ -782,13 +2298,15 @@ where
         B: Borrow<Item<'a>>,
     {
         let local = self.naive_local();
-        DelayedFormat::new_with_offset_and_locale(
+        let name = self.timezone().offset_name(&self.datetime);
+        DelayedFormat::new_with_offset_name(
             Some(local.date()),
             Some(local.time()),
-            &self.offset,
+            name,
+            self.offset.fix(),
             items,
-            locale,
         )
+        .with_locale(locale)
     }
 
     /// Formats the combined date and time per the specified format string and
@@ -1103,21 +2621,11 @@ impl str::FromStr for DateTime<Local> {
 #[cfg(any(feature = "std", test))]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl From<SystemTime> for DateTime<Utc> {
+    /// Converts a `SystemTime`, panicking if it is out of range.
+    ///
+    /// See [`DateTime::try_from_system_time`] for a non-panicking version.
     fn from(t: SystemTime) -> DateTime<Utc> {
-        let (sec, nsec) = match t.duration_since(UNIX_EPOCH) {
-            Ok(dur) => (dur.as_secs() as i64, dur.subsec_nanos()),
-            Err(e) => {
-                // unlikely but should be handled
-                let dur = e.duration();
-                let (sec, nsec) = (dur.as_secs() as i64, dur.subsec_nanos());
-                if nsec == 0 {
-                    (-sec, 0)
-                } else {
-                    (-sec - 1, 1_000_000_000 - nsec)
-                }
-            }
-        };
-        Utc.timestamp_opt(sec, nsec).unwrap()
+        DateTime::<Utc>::try_from_system_time(t).expect("`SystemTime` out of range for `DateTime`")
     }
 }
 
@@ -1,13 +1,20 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 
 use core::fmt;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::marker::PhantomData;
 use serde::{de, ser};
 
-use super::DateTime;
+use super::{DateTime, SecondsFormat};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::format::ParseResult;
 use crate::naive::datetime::serde::serde_from;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 #[cfg(feature = "clock")]
 use crate::offset::Local;
 use crate::offset::{FixedOffset, TimeZone, Utc};
+use crate::Timelike;
 
 /// Serialize into a rfc3339 time string
 ///
@@ -33,6 +40,36 @@ impl<Tz: TimeZone> ser::Serialize for DateTime<Tz> {
     }
 }
 
+/// Writes the datetime like its `Debug` output, but with the fractional digits chosen by the
+/// `SecondsFormat`. Unlike `to_rfc3339_opts` this needs no allocation.
+struct FormatRfc3339<'a, Tz: TimeZone>(&'a DateTime<Tz>, SecondsFormat);
+
+impl<'a, Tz: TimeZone> fmt::Display for FormatRfc3339<'a, Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let local = self.0.naive_local();
+        let (sec, nano) = match local.nanosecond() {
+            nano if nano >= 1_000_000_000 => (local.second() + 1, nano - 1_000_000_000),
+            nano => (local.second(), nano),
+        };
+        let (date, hour, min) = (local.date(), local.hour(), local.minute());
+        write!(f, "{:?}T{:02}:{:02}:{:02}", date, hour, min, sec)?;
+        match self.1 {
+            SecondsFormat::Secs => {}
+            SecondsFormat::Millis => write!(f, ".{:03}", nano / 1_000_000)?,
+            SecondsFormat::Micros => write!(f, ".{:06}", nano / 1_000)?,
+            SecondsFormat::Nanos => write!(f, ".{:09}", nano)?,
+            SecondsFormat::AutoSi if nano == 0 => {}
+            SecondsFormat::AutoSi if nano % 1_000_000 == 0 => {
+                write!(f, ".{:03}", nano / 1_000_000)?
+            }
+            SecondsFormat::AutoSi if nano % 1_000 == 0 => write!(f, ".{:06}", nano / 1_000)?,
+            SecondsFormat::AutoSi => write!(f, ".{:09}", nano)?,
+            SecondsFormat::__NonExhaustive => unreachable!(),
+        }
+        write!(f, "{:?}", self.0.offset())
+    }
+}
+
 struct DateTimeVisitor;
 
 impl<'de> de::Visitor<'de> for DateTimeVisitor {
@@ -99,6 +136,216 @@ impl<'de> de::Deserialize<'de> for DateTime<Local> {
     }
 }
 
+/// Ser/de to/from RFC 3339 strings with exactly nine fractional digits
+///
+/// The default serialization uses as few fractional digits as needed, so the length of the
+/// output varies. This always writes nanoseconds, giving fixed-width strings for years 0 to 9999.
+/// Deserialization accepts any valid RFC 3339 string.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{TimeZone, DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc3339_nanos;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc3339_nanos")]
+///     time: DateTime<Utc>
+/// }
+///
+/// let time = Utc.ymd_opt(2018, 5, 17).unwrap().and_hms_milli_opt(02, 04, 59, 918).unwrap();
+/// let my_s = S {
+///     time: time.clone(),
+/// };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"2018-05-17T02:04:59.918000000Z"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, time);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod rfc3339_nanos {
+    use core::fmt;
+    use serde::{de, ser};
+
+    use super::FormatRfc3339;
+    use crate::{DateTime, FixedOffset, SecondsFormat, Utc};
+
+    /// Serialize a UTC datetime into an RFC 3339 string with nine fractional digits
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc3339_nanos::serialize as to_rfc3339_nanos;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc3339_nanos")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s = S {
+    ///     time: Utc.ymd_opt(2018, 5, 17).unwrap().and_hms_opt(02, 04, 59).unwrap(),
+    /// };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2018-05-17T02:04:59.000000000Z"}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&FormatRfc3339(dt, SecondsFormat::Nanos))
+    }
+
+    /// Deserialize a [`DateTime`] from any RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc3339_nanos::deserialize as from_rfc3339;
+    /// #[derive(Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "2018-05-17T11:04:59.5+09:00" }"#)?;
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_str(Rfc3339Visitor)
+    }
+
+    struct Rfc3339Visitor;
+
+    impl<'de> de::Visitor<'de> for Rfc3339Visitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an RFC 3339 formatted date and time string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            DateTime::<FixedOffset>::parse_from_rfc3339(value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(E::custom)
+        }
+    }
+}
+
+/// Ser/de to/from RFC 3339 strings with exactly six fractional digits
+///
+/// Serialization **truncates** the datetime to whole microseconds, matching the precision of
+/// stores such as PostgreSQL `timestamp`. A value written this way and read back compares equal
+/// to what the store holds, rather than differing in the discarded nanoseconds.
+/// Deserialization accepts any valid RFC 3339 string and keeps all of its digits.
+///
+/// Intended for use with `serde`'s `with` attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// # use chrono::{TimeZone, DateTime, Utc};
+/// # use serde_derive::{Deserialize, Serialize};
+/// use chrono::serde::rfc3339_micros;
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "rfc3339_micros")]
+///     time: DateTime<Utc>
+/// }
+///
+/// let time = Utc.ymd_opt(2018, 5, 17).unwrap().and_hms_nano_opt(02, 04, 59, 918_355_733).unwrap();
+/// let my_s = S {
+///     time: time.clone(),
+/// };
+///
+/// let as_string = serde_json::to_string(&my_s)?;
+/// assert_eq!(as_string, r#"{"time":"2018-05-17T02:04:59.918355Z"}"#);
+/// let my_s: S = serde_json::from_str(&as_string)?;
+/// assert_eq!(my_s.time, Utc.ymd_opt(2018, 5, 17).unwrap().and_hms_micro_opt(02, 04, 59, 918_355).unwrap());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod rfc3339_micros {
+    use serde::{de, ser};
+
+    use super::FormatRfc3339;
+    use crate::{DateTime, SecondsFormat, Utc};
+
+    /// Serialize a UTC datetime into an RFC 3339 string with six fractional digits, truncating
+    /// any sub-microsecond part
+    ///
+    /// Intended for use with `serde`s `serialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{TimeZone, DateTime, Utc};
+    /// # use serde_derive::Serialize;
+    /// use chrono::serde::rfc3339_micros::serialize as to_rfc3339_micros;
+    /// #[derive(Serialize)]
+    /// struct S {
+    ///     #[serde(serialize_with = "to_rfc3339_micros")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s = S {
+    ///     time: Utc.ymd_opt(2018, 5, 17).unwrap().and_hms_nano_opt(02, 04, 59, 999_999_999).unwrap(),
+    /// };
+    /// let as_string = serde_json::to_string(&my_s)?;
+    /// assert_eq!(as_string, r#"{"time":"2018-05-17T02:04:59.999999Z"}"#);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn serialize<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_str(&FormatRfc3339(dt, SecondsFormat::Micros))
+    }
+
+    /// Deserialize a [`DateTime`] from any RFC 3339 string
+    ///
+    /// Intended for use with `serde`s `deserialize_with` attribute.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # use chrono::{DateTime, Utc};
+    /// # use serde_derive::Deserialize;
+    /// use chrono::serde::rfc3339_micros::deserialize as from_rfc3339;
+    /// #[derive(Deserialize)]
+    /// struct S {
+    ///     #[serde(deserialize_with = "from_rfc3339")]
+    ///     time: DateTime<Utc>
+    /// }
+    ///
+    /// let my_s: S = serde_json::from_str(r#"{ "time": "2018-05-17T11:04:59.5+09:00" }"#)?;
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        super::rfc3339_nanos::deserialize(d)
+    }
+}
+
 /// Ser/de to/from timestamps in nanoseconds
 ///
 /// Intended for use with `serde`'s `with` attribute.
@@ -1113,6 +1360,250 @@ pub mod ts_seconds_option {
     }
 }
 
+/// A value that `serde_strftime!` can (de)serialize with a format string.
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[doc(hidden)]
+pub trait __StrftimeValue: Sized {
+    fn __format<S: ser::Serializer>(&self, fmt: &str, serializer: S) -> Result<S::Ok, S::Error>;
+    fn __parse(s: &str, fmt: &str) -> ParseResult<Self>;
+}
+
+macro_rules! impl_strftime_value {
+    ($($ty:ty),*) => {$(
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        impl __StrftimeValue for $ty {
+            fn __format<S: ser::Serializer>(&self, fmt: &str, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&self.format(fmt))
+            }
+
+            fn __parse(s: &str, fmt: &str) -> ParseResult<Self> {
+                <$ty>::parse_from_str(s, fmt)
+            }
+        }
+    )*};
+}
+
+impl_strftime_value!(NaiveDate, NaiveTime, NaiveDateTime, DateTime<FixedOffset>);
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[doc(hidden)]
+pub fn __deserialize_strftime<'de, T, D>(fmt: &'static str, deserializer: D) -> Result<T, D::Error>
+where
+    T: __StrftimeValue,
+    D: de::Deserializer<'de>,
+{
+    struct StrftimeVisitor<T> {
+        fmt: &'static str,
+        value: PhantomData<T>,
+    }
+
+    impl<'de, T: __StrftimeValue> de::Visitor<'de> for StrftimeVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string in the format {:?}", self.fmt)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<T, E>
+        where
+            E: de::Error,
+        {
+            T::__parse(value, self.fmt).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(StrftimeVisitor { fmt, value: PhantomData })
+}
+
+// used by `serde_strftime!`, which expands in other crates
+#[doc(hidden)]
+pub use serde as __serde;
+
+/// Generates a module for serde's `with` attribute that (de)serializes values as strings in a
+/// custom [`strftime`](../format/strftime/index.html) format.
+///
+/// `serde_strftime!(pub mod name, "format")` defines a module `name` with `serialize` and
+/// `deserialize` functions, which work with `NaiveDate`, `NaiveTime`, `NaiveDateTime` and
+/// `DateTime<FixedOffset>` fields. The format string must be a `&'static str`.
+///
+/// The macro is also available as `chrono::serde::strftime!`.
+///
+/// *Available on crate features 'serde' and 'alloc' (or 'std') only.*
+///
+/// # Example
+///
+/// ```rust
+/// # use chrono::{NaiveDate, NaiveDateTime};
+/// # use serde_derive::{Deserialize, Serialize};
+/// chrono::serde_strftime!(mod day_month_year, "%d/%m/%Y");
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct S {
+///     #[serde(with = "day_month_year")]
+///     date: NaiveDate,
+/// }
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 5, 17).unwrap();
+/// let json = serde_json::to_string(&S { date })?;
+/// assert_eq!(json, r#"{"date":"17/05/2023"}"#);
+/// assert_eq!(serde_json::from_str::<S>(&json)?.date, date);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[macro_export]
+macro_rules! serde_strftime {
+    ($vis:vis mod $name:ident, $fmt:expr) => {
+        $vis mod $name {
+            use $crate::serde::{__StrftimeValue, __serde};
+
+            /// Serializes the value as a string in the format of this module.
+            #[allow(unreachable_pub)] // the module itself may be private
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: __StrftimeValue,
+                S: __serde::Serializer,
+            {
+                value.__format($fmt, serializer)
+            }
+
+            /// Deserializes the value from a string in the format of this module.
+            #[allow(unreachable_pub)]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: __StrftimeValue,
+                D: __serde::Deserializer<'de>,
+            {
+                $crate::serde::__deserialize_strftime($fmt, deserializer)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+crate::serde_strftime!(mod dmy, "%d/%m/%Y");
+#[cfg(test)]
+crate::serde::strftime!(pub(crate) mod compact, "%Y%m%d%H%M%S");
+
+#[test]
+fn test_serde_strftime() {
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct S {
+        #[serde(with = "dmy")]
+        date: NaiveDate,
+        #[serde(with = "compact")]
+        datetime: NaiveDateTime,
+    }
+
+    let date = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+    let value = S { date, datetime: date.and_hms_opt(9, 8, 7).unwrap() };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"date":"05/01/2023","datetime":"20230105090807"}"#);
+    assert_eq!(serde_json::from_str::<S>(&json).unwrap(), value);
+
+    let err = serde_json::from_str::<S>(r#"{"date":"2023-01-05","datetime":"20230105090807"}"#);
+    assert!(err.is_err());
+    let err = serde_json::from_str::<S>(r#"{"date":5,"datetime":"20230105090807"}"#).unwrap_err();
+    assert!(err.to_string().contains(r#"a string in the format "%d/%m/%Y""#));
+}
+
+#[test]
+fn test_serde_rfc3339_nanos() {
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct S {
+        #[serde(with = "rfc3339_nanos")]
+        time: DateTime<Utc>,
+    }
+
+    let check = |dt: DateTime<Utc>, expected: &str| {
+        let json = serde_json::to_string(&S { time: dt }).unwrap();
+        assert_eq!(json, format!(r#"{{"time":"{}"}}"#, expected));
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time: dt });
+    };
+    check(
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_opt(12, 34, 6).unwrap(),
+        "2014-07-24T12:34:06.000000000Z",
+    );
+    check(
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(12, 34, 6, 7).unwrap(),
+        "2014-07-24T12:34:06.000000007Z",
+    );
+    check(
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(12, 34, 6, 123_456_789).unwrap(),
+        "2014-07-24T12:34:06.123456789Z",
+    );
+    check(
+        Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_000_000_001).unwrap(),
+        "2016-12-31T23:59:60.000000001Z",
+    );
+
+    // any RFC 3339 string is accepted
+    let expected = Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_milli_opt(3, 34, 6, 500).unwrap();
+    for input in ["2014-07-24T12:34:06.5+09:00", "2014-07-24t03:34:06.500z"].iter() {
+        let json = format!(r#"{{"time":"{}"}}"#, input);
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time: expected });
+    }
+    assert!(serde_json::from_str::<S>(r#"{"time":"2014-07-24T12:34:06"}"#).is_err());
+}
+
+#[test]
+fn test_serde_rfc3339_micros() {
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct S {
+        #[serde(with = "rfc3339_micros")]
+        time: DateTime<Utc>,
+    }
+
+    let check = |dt: DateTime<Utc>, expected: &str, truncated: DateTime<Utc>| {
+        let json = serde_json::to_string(&S { time: dt }).unwrap();
+        assert_eq!(json, format!(r#"{{"time":"{}"}}"#, expected));
+        assert_eq!(serde_json::from_str::<S>(&json).unwrap(), S { time: truncated });
+    };
+    let dt = Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_opt(12, 34, 6).unwrap();
+    check(dt, "2014-07-24T12:34:06.000000Z", dt);
+    let dt = Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_micro_opt(12, 34, 6, 7).unwrap();
+    check(dt, "2014-07-24T12:34:06.000007Z", dt);
+    check(
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(12, 34, 6, 123_456_789).unwrap(),
+        "2014-07-24T12:34:06.123456Z",
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_micro_opt(12, 34, 6, 123_456).unwrap(),
+    );
+    check(
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(12, 34, 6, 999).unwrap(),
+        "2014-07-24T12:34:06.000000Z",
+        Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_opt(12, 34, 6).unwrap(),
+    );
+    let dt = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_micro_opt(23, 59, 59, 1_000_001).unwrap();
+    check(dt, "2016-12-31T23:59:60.000001Z", dt);
+
+    // the deserializer keeps every digit it is given
+    let expected = Utc.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(3, 34, 6, 5).unwrap();
+    let json = r#"{"time":"2014-07-24T03:34:06.000000005Z"}"#;
+    assert_eq!(serde_json::from_str::<S>(json).unwrap(), S { time: expected });
+}
+
+#[test]
+fn test_format_rfc3339() {
+    let offset = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    let dt = offset.ymd_opt(2014, 7, 24).unwrap().and_hms_nano_opt(12, 34, 6, 123_450_000).unwrap();
+    for &secform in [
+        SecondsFormat::Secs,
+        SecondsFormat::Millis,
+        SecondsFormat::Micros,
+        SecondsFormat::Nanos,
+        SecondsFormat::AutoSi,
+    ]
+    .iter()
+    {
+        let formatted = FormatRfc3339(&dt, secform).to_string();
+        assert_eq!(formatted, dt.to_rfc3339_opts(secform, true));
+    }
+    let utc = dt.with_timezone(&Utc);
+    assert_eq!(
+        FormatRfc3339(&utc, SecondsFormat::AutoSi).to_string(),
+        "2014-07-24T07:04:06.123450Z"
+    );
+}
+
 #[test]
 fn test_serde_serialize() {
     super::test_encodable_json(serde_json::to_string, serde_json::to_string);
@@ -1,13 +1,15 @@
 use std::time::{SystemTime, UNIX_EPOCH};
+use std::{f64, i32, i64};
 
-use super::DateTime;
-use crate::naive::{NaiveDate, NaiveTime};
+use super::{earliest, latest, DateTime};
+use crate::format::ParseErrorKind;
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::tests::Cet;
 #[cfg(feature = "clock")]
 use crate::offset::Local;
+use crate::offset::LocalResult;
 use crate::offset::{FixedOffset, TimeZone, Utc};
-#[cfg(feature = "clock")]
-use crate::Datelike;
-use crate::TimeDelta;
+use crate::{Datelike, RelativeDelta, SubsecRound, TimeDelta, Timelike};
 
 #[test]
 fn test_datetime_offset() {
@@ -214,6 +216,94 @@ fn test_rfc3339_opts() {
     assert_eq!(ut.to_rfc3339_opts(AutoSi, true), "2018-01-11T02:05:13.084660Z");
 }
 
+#[test]
+fn test_rfc3339_opts_no_colon() {
+    use crate::SecondsFormat::*;
+    let ut = Utc.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(20, 5, 13, 84_660_000).unwrap();
+    for &secs in &[8 * 3600, -5 * 3600, 5 * 3600 + 45 * 60, -(9 * 3600 + 30 * 60), 0] {
+        let dt = ut.with_timezone(&FixedOffset::east_opt(secs).unwrap());
+        for &secform in &[Secs, Millis, Micros, Nanos, AutoSi] {
+            for &use_z in &[false, true] {
+                let mut expected = dt.to_rfc3339_opts(secform, use_z);
+                if !expected.ends_with('Z') {
+                    expected.remove(expected.len() - 3);
+                }
+                assert_eq!(dt.to_rfc3339_opts_no_colon(secform, use_z), expected);
+            }
+        }
+    }
+
+    let dt = ut.with_timezone(&FixedOffset::west_opt(9 * 3600 + 30 * 60).unwrap());
+    assert_eq!(dt.to_rfc3339_opts_no_colon(Millis, true), "2018-01-11T10:35:13.084-0930");
+    assert_eq!(ut.to_rfc3339_opts_no_colon(Secs, false), "2018-01-11T20:05:13+0000");
+    assert_eq!(ut.to_rfc3339_opts_no_colon(Secs, true), "2018-01-11T20:05:13Z");
+}
+
+#[test]
+fn test_rfc3339_in_offset() {
+    use crate::SecondsFormat::*;
+    let ut = Utc.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(20, 5, 13, 84_660_000).unwrap();
+    let off = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    assert_eq!(ut.to_rfc3339_in_offset(off, Secs, false), "2018-01-12T04:05:13+08:00");
+    assert_eq!(ut.to_rfc3339_in_offset(off, Millis, true), "2018-01-12T04:05:13.084+08:00");
+    for &secform in &[Secs, Millis, Micros, Nanos, AutoSi] {
+        for &use_z in &[false, true] {
+            assert_eq!(
+                ut.to_rfc3339_in_offset(off, secform, use_z),
+                ut.with_timezone(&off).to_rfc3339_opts(secform, use_z)
+            );
+        }
+    }
+
+    // the instant is unchanged, whatever the original offset
+    let west = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+    assert_eq!(
+        ut.with_timezone(&west).to_rfc3339_in_offset(off, AutoSi, false),
+        "2018-01-12T04:05:13.084660+08:00"
+    );
+    let utc = FixedOffset::east_opt(0).unwrap();
+    assert_eq!(ut.to_rfc3339_in_offset(utc, Secs, true), "2018-01-11T20:05:13Z");
+}
+
+#[test]
+fn test_http_date() {
+    let dt = Utc.ymd_opt(1994, 11, 6).unwrap().and_hms_opt(8, 49, 37).unwrap();
+    assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    assert_eq!(DateTime::<Utc>::parse_http_date(&dt.to_http_date()), Ok(dt));
+
+    // always rendered in GMT, dropping subseconds
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.ymd_opt(2023, 1, 1).unwrap().and_hms_milli_opt(8, 5, 9, 999).unwrap();
+    assert_eq!(dt.to_http_date(), "Sat, 31 Dec 2022 23:05:09 GMT");
+
+    // round-trips
+    for &(y, m, d, h, mi, sec) in &[
+        (1970, 1, 1, 0, 0, 0),
+        (2000, 2, 29, 12, 30, 45),
+        (2038, 1, 19, 3, 14, 7),
+        (9999, 12, 31, 23, 59, 59),
+    ] {
+        let dt = Utc.ymd_opt(y, m, d).unwrap().and_hms_opt(h, mi, sec).unwrap();
+        assert_eq!(DateTime::<Utc>::parse_http_date(&dt.to_http_date()), Ok(dt));
+    }
+
+    // the obsolete formats
+    let expected = Ok(Utc.ymd_opt(1994, 11, 6).unwrap().and_hms_opt(8, 49, 37).unwrap());
+    assert_eq!(DateTime::<Utc>::parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), expected);
+    assert_eq!(DateTime::<Utc>::parse_http_date("Sun Nov  6 08:49:37 1994"), expected);
+    assert_eq!(
+        DateTime::<Utc>::parse_http_date("Wednesday, 12-Feb-25 10:00:00 GMT"),
+        Ok(Utc.ymd_opt(2025, 2, 12).unwrap().and_hms_opt(10, 0, 0).unwrap())
+    );
+
+    // invalid values
+    assert!(DateTime::<Utc>::parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT").is_err()); // wrong weekday
+    assert!(DateTime::<Utc>::parse_http_date("Sun, 06 Nov 1994 08:49:37 +0000").is_err());
+    assert!(DateTime::<Utc>::parse_http_date("Sun, 06 Nov 1994 08:49:37").is_err());
+    assert!(DateTime::<Utc>::parse_http_date("1994-11-06T08:49:37Z").is_err());
+    assert!(DateTime::<Utc>::parse_http_date("").is_err());
+}
+
 #[test]
 #[should_panic]
 fn test_rfc3339_opts_nonexhaustive() {
@@ -300,6 +390,117 @@ fn test_datetime_parse_from_str() {
     );
 }
 
+#[test]
+fn test_datetime_parse_from_rfc3339_bytes() {
+    let parse = DateTime::<FixedOffset>::parse_from_rfc3339_bytes;
+    let inputs = [
+        "2015-02-18T23:16:09Z",
+        "2015-02-18T23:16:09.153+05:30",
+        "2015-02-18t23:16:09.153z",
+        "2015-02-18T23:59:60.234567+00:00",
+        "2015-02-18 23:16:09Z",
+        "2015-02-18T23:16:09",
+        "2015-02-30T23:16:09Z",
+        "2015-02-18T23:16:09Zé",
+        "2015-02-18T23:16:09+01:00 ",
+        "",
+    ];
+    for input in inputs.iter() {
+        let expected = DateTime::<FixedOffset>::parse_from_rfc3339(input);
+        assert_eq!(parse(input.as_bytes()), expected, "{}", input);
+    }
+    assert!(parse(b"2015-02-18T23:16:09Z").is_ok());
+
+    // not UTF-8
+    assert!(parse(b"2015-02-18T23:16:09\xffZ").is_err());
+    assert!(parse(b"\xc3").is_err());
+    assert!(parse(&[0x80; 20]).is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_any() {
+    let parse = DateTime::<Utc>::parse_from_any;
+    let fmts = ["%Y-%m-%dT%H:%M:%S%z", "%d/%m/%Y %H:%M:%S %z", "%s %z"];
+    let dt = Utc.ymd_opt(2014, 5, 7).unwrap().and_hms_opt(12, 34, 56).unwrap();
+
+    assert_eq!(parse("2014-05-07T12:34:56+00:00", &fmts), Ok(dt));
+    assert_eq!(parse("07/05/2014 14:34:56 +0200", &fmts), Ok(dt));
+    assert_eq!(parse("1399466096 +0900", &fmts), Ok(dt));
+
+    // the most specific error is reported: only the second format gets to the end of the input
+    assert_eq!(parse("07/05/2014 14:34:56", &fmts).unwrap_err().kind(), ParseErrorKind::TooShort);
+    assert_eq!(
+        parse("07/05/2014 14:34:56 +0200 x", &fmts).unwrap_err().kind(),
+        ParseErrorKind::TooLong
+    );
+    assert_eq!(
+        parse("07/13/2014 14:34:56 +0200", &fmts).unwrap_err().kind(),
+        ParseErrorKind::OutOfRange
+    );
+    // with equally specific errors, the one for the first format is reported
+    let err = parse("x", &fmts).unwrap_err();
+    assert_eq!(err, DateTime::<Utc>::parse_from_str("x", fmts[0]).unwrap_err());
+    assert_eq!(
+        parse("2014-05-32T12:34:56+00:00", &fmts).unwrap_err().kind(),
+        ParseErrorKind::OutOfRange
+    );
+    assert_eq!(parse("2014-05-07T12:34:56Z", &[]).unwrap_err().kind(), ParseErrorKind::NotEnough);
+}
+
+#[test]
+fn test_datetime_parse_from_str_with_default_offset() {
+    let parse = DateTime::parse_from_str_with_default_offset;
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+
+    assert_eq!(
+        parse("20140507000000", "%Y%m%d%H%M%S", kst),
+        Ok(kst.ymd_opt(2014, 5, 7).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    );
+    let dt = parse("2014-5-7T12:34:56-05:00", "%Y-%m-%dT%H:%M:%S%z", kst).unwrap();
+    assert_eq!(dt, est.ymd_opt(2014, 5, 7).unwrap().and_hms_opt(12, 34, 56).unwrap());
+    assert_eq!(dt.offset(), &est);
+
+    // a timestamp is viewed in the default offset
+    let dt = parse("1399420800", "%s", kst).unwrap();
+    assert_eq!(dt, Utc.ymd_opt(2014, 5, 7).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(dt.offset(), &kst);
+
+    // other errors are still reported
+    assert!(parse("20140507", "%Y%m%d", kst).is_err());
+    assert!(parse("20140507000000", "%Y%m%d%H%M", kst).is_err());
+}
+
+#[test]
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn test_datetime_parse_from_str_with_tz_resolver() {
+    let resolver = |name: &str| match name {
+        "EST" => FixedOffset::west_opt(5 * 3600),
+        "UTC" => FixedOffset::east_opt(0),
+        _ => None,
+    };
+    let parse = |s| DateTime::parse_from_str_with_tz_resolver(s, "%Y-%m-%d %H:%M:%S %Z", resolver);
+
+    let dt = parse("2023-01-15 09:30:00 EST").unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::west_opt(5 * 3600).unwrap());
+    assert_eq!(dt, Utc.ymd_opt(2023, 1, 15).unwrap().and_hms_opt(14, 30, 0).unwrap());
+    assert_eq!(parse("2023-01-15 09:30:00 UTC").unwrap().offset().local_minus_utc(), 0);
+    assert_eq!(parse("2023-01-15 09:30:00 PST").unwrap_err().kind(), ParseErrorKind::Invalid);
+
+    // a numeric offset must agree with the resolved name
+    let both = |s| DateTime::parse_from_str_with_tz_resolver(s, "%H:%M %d/%m/%Y %z %Z", resolver);
+    assert!(both("09:30 15/01/2023 -0500 EST").is_ok());
+    assert_eq!(both("09:30 15/01/2023 +0100 EST").unwrap_err().kind(), ParseErrorKind::Impossible);
+
+    // without a name the resolver is not consulted
+    let dt = DateTime::parse_from_str_with_tz_resolver(
+        "2023-01-15 09:30:00 +0100",
+        "%Y-%m-%d %H:%M:%S %z",
+        |_: &str| -> Option<FixedOffset> { panic!("resolver called") },
+    );
+    assert_eq!(dt.unwrap().offset().local_minus_utc(), 3600);
+}
+
 #[test]
 fn test_to_string_round_trip() {
     let dt = Utc.ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
@@ -458,6 +659,38 @@ fn test_from_system_time() {
     );
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+fn test_try_from_system_time() {
+    use std::time::Duration;
+
+    let nanos = 999_999_999;
+    assert_eq!(DateTime::<Utc>::try_from_system_time(UNIX_EPOCH), Ok(Utc.timestamp_nanos(0)));
+    assert_eq!(
+        DateTime::<Utc>::try_from_system_time(UNIX_EPOCH - Duration::new(999_999_999, nanos)),
+        Ok(Utc.ymd_opt(1938, 4, 24).unwrap().and_hms_nano_opt(22, 13, 20, 1).unwrap())
+    );
+
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(DateTime::<Utc>::try_from_system_time(SystemTime::from(max)), Ok(max));
+    let min = DateTime::<Utc>::MIN_UTC;
+    assert_eq!(DateTime::<Utc>::try_from_system_time(SystemTime::from(min)), Ok(min));
+
+    // about 317,000 years away from the epoch in either direction
+    let far = Duration::from_secs(10_000_000_000_000);
+    assert!(DateTime::<Utc>::try_from_system_time(UNIX_EPOCH + far).is_err());
+    assert!(DateTime::<Utc>::try_from_system_time(UNIX_EPOCH - far).is_err());
+    let after_max = SystemTime::from(max) + Duration::from_secs(1);
+    assert!(DateTime::<Utc>::try_from_system_time(after_max).is_err());
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+#[should_panic]
+fn test_from_system_time_out_of_range() {
+    let _ = DateTime::<Utc>::from(UNIX_EPOCH + std::time::Duration::from_secs(10_000_000_000_000));
+}
+
 #[test]
 fn test_datetime_format_alignment() {
     let datetime = Utc.ymd_opt(2007, 1, 2).unwrap();
@@ -529,6 +762,33 @@ fn test_years_elapsed() {
     assert_eq!(Utc::today().years_since(future), None);
 }
 
+#[test]
+fn test_datetime_full_years_since() {
+    let now = Utc.ymd_opt(2023, 6, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let at = |y, m, d, h| Utc.ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+
+    assert_eq!(now.full_years_since(&now), Some(0));
+    assert_eq!(now.full_years_since(&at(2022, 12, 15, 12)), Some(0));
+    assert_eq!(now.full_years_since(&at(2022, 6, 15, 13)), Some(0));
+    assert_eq!(now.full_years_since(&at(2022, 6, 15, 12)), Some(1));
+    assert_eq!(now.full_years_since(&at(2021, 6, 15, 12)), Some(2));
+    assert_eq!(now.full_years_since(&at(2023, 6, 15, 13)), None);
+    assert_eq!(now.full_years_since(&at(2023, 12, 15, 12)), None);
+    assert_eq!(now.full_years_since(&at(2025, 6, 15, 12)), None);
+
+    // a leap day completes a year on March 1 in common years
+    let leap_day = at(2020, 2, 29, 0);
+    assert_eq!(at(2021, 2, 28, 23).full_years_since(&leap_day), Some(0));
+    assert_eq!(at(2021, 3, 1, 0).full_years_since(&leap_day), Some(1));
+    assert_eq!(at(2024, 2, 29, 0).full_years_since(&leap_day), Some(4));
+
+    let base = at(2021, 6, 15, 12);
+    assert!(now.is_at_least_years_old(&base, 0));
+    assert!(now.is_at_least_years_old(&base, 2));
+    assert!(!now.is_at_least_years_old(&base, 3));
+    assert!(!base.is_at_least_years_old(&now, 0));
+}
+
 #[test]
 fn test_datetime_add_assign() {
     let naivedatetime = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
@@ -602,3 +862,963 @@ fn test_datetime_sub_assign_local() {
         assert_eq!(datetime_sub, datetime - TimeDelta::days(i))
     }
 }
+
+#[test]
+fn test_datetime_eq_instant() {
+    let utc = Utc.at(2022, 3, 1, 3, 0, 0).unwrap();
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let pst = FixedOffset::west_opt(8 * 3600).unwrap();
+
+    assert!(utc.eq_instant(&utc));
+    assert!(utc.eq_instant(&kst.at(2022, 3, 1, 12, 0, 0).unwrap()));
+    assert!(utc.eq_instant(&pst.at(2022, 2, 28, 19, 0, 0).unwrap()));
+    assert!(kst.at(2022, 3, 1, 12, 0, 0).unwrap().eq_instant(&utc));
+
+    assert!(!utc.eq_instant(&kst.at(2022, 3, 1, 3, 0, 0).unwrap()));
+    assert!(!utc.eq_instant(&(utc + TimeDelta::nanoseconds(1))));
+    let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_000).unwrap();
+    assert!(!leap.eq_instant(&Utc.at(2016, 12, 31, 23, 59, 59).unwrap()));
+}
+
+#[test]
+fn test_datetime_is_same_day_as() {
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(20, 0, 0).unwrap();
+
+    // 2022-03-01 19:00 and 2022-03-02 05:00 in +09:00
+    assert!(a.is_same_day_as(&b, Utc));
+    assert!(!a.is_same_day_as(&b, kst));
+
+    // the native zone of either side does not matter
+    let b_kst = b.with_timezone(&kst);
+    assert!(a.is_same_day_as(&b_kst, Utc));
+    assert!(b_kst.is_same_day_as(&a, Utc));
+    assert!(!b_kst.is_same_day_as(&a, kst));
+    assert!(a.is_same_day_as(&a, kst));
+}
+
+#[test]
+fn test_datetime_midpoint() {
+    let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 1).unwrap();
+    let mid = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 500).unwrap();
+    assert_eq!(a.midpoint(&b), mid);
+    assert_eq!(b.midpoint(&a), mid);
+
+    // identical instants
+    assert_eq!(a.midpoint(&a), a);
+
+    // an odd number of nanoseconds rounds towards the earlier instant
+    let c = a + TimeDelta::nanoseconds(3);
+    assert_eq!(a.midpoint(&c), a + TimeDelta::nanoseconds(1));
+    assert_eq!(c.midpoint(&a), a + TimeDelta::nanoseconds(1));
+    let d = a - TimeDelta::nanoseconds(1);
+    assert_eq!(a.midpoint(&d), d);
+
+    // the result is in the zone of `self`
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let a_kst = a.with_timezone(&kst);
+    let b_kst = b.with_timezone(&kst);
+    assert_eq!(a_kst.midpoint(&b_kst), mid);
+    assert_eq!(*a_kst.midpoint(&b_kst).offset(), kst);
+
+    // the full range does not overflow
+    let mid = DateTime::<Utc>::MIN_UTC.midpoint(&DateTime::<Utc>::MAX_UTC);
+    assert!(DateTime::<Utc>::MIN_UTC < mid && mid < DateTime::<Utc>::MAX_UTC);
+}
+
+#[test]
+fn test_datetime_normalize_leap_second() {
+    let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_000).unwrap();
+    let next = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(leap.to_rfc3339(), "2016-12-31T23:59:60+00:00");
+    assert_eq!(leap.normalize_leap_second(), next);
+
+    // the fraction is kept
+    let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_999_999_999);
+    let next = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_nano_opt(0, 0, 0, 999_999_999);
+    assert_eq!(leap.unwrap().normalize_leap_second(), next.unwrap());
+
+    // non-leap instants are unchanged
+    let dt = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 999).unwrap();
+    assert_eq!(dt.normalize_leap_second(), dt);
+
+    // the offset is kept, and the leap second is folded on the UTC timeline
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let leap = kst.ymd_opt(2017, 1, 1).unwrap().and_hms_milli_opt(8, 59, 59, 1_000).unwrap();
+    let normalized = leap.normalize_leap_second();
+    assert_eq!(normalized.offset(), &kst);
+    assert_eq!(normalized.to_rfc3339(), "2017-01-01T09:00:00+09:00");
+}
+
+#[test]
+fn test_datetime_cmp_total() {
+    use core::cmp::Ordering;
+
+    let hmsm =
+        |h, m, s, ms| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(h, m, s, ms).unwrap();
+    let leap = hmsm(23, 59, 59, 1_500);
+    let before = [
+        hmsm(23, 59, 58, 999),
+        hmsm(23, 59, 59, 0),
+        hmsm(23, 59, 59, 999),
+        hmsm(23, 59, 59, 1_200),
+    ];
+    let after = [
+        hmsm(23, 59, 59, 1_999),
+        Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500).unwrap(),
+    ];
+    for dt in before.iter() {
+        assert_eq!(leap.cmp_total(dt), Ordering::Greater);
+        assert_eq!(dt.cmp_total(&leap), Ordering::Less);
+        assert_eq!(leap.cmp_total(dt), leap.cmp(dt));
+    }
+    for dt in after.iter() {
+        assert_eq!(leap.cmp_total(dt), Ordering::Less);
+        assert_eq!(dt.cmp_total(&leap), Ordering::Greater);
+        assert_eq!(leap.cmp_total(dt), leap.cmp(dt));
+    }
+    assert_eq!(leap.cmp_total(&leap), Ordering::Equal);
+
+    // the offsets are irrelevant
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let leap_kst = leap.with_timezone(&kst);
+    assert_eq!(leap_kst.to_string(), "2017-01-01 08:59:60.500 +09:00");
+    assert_eq!(leap_kst.cmp_total(&leap), Ordering::Equal);
+    assert_eq!(leap_kst.cmp_total(&after[1]), Ordering::Less);
+    assert_eq!(before[2].cmp_total(&leap_kst), Ordering::Less);
+}
+
+#[test]
+fn test_datetime_approx_eq() {
+    let a = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 0).unwrap();
+    let b = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_milli_opt(10, 0, 0, 1).unwrap();
+    assert!(a.approx_eq(&b, TimeDelta::milliseconds(2)));
+    assert!(b.approx_eq(&a, TimeDelta::milliseconds(2)));
+    assert!(a.approx_eq(&b, TimeDelta::milliseconds(1)));
+    assert!(!a.approx_eq(&b, TimeDelta::microseconds(500)));
+    assert!(!b.approx_eq(&a, TimeDelta::microseconds(500)));
+    assert!(a.approx_eq(&a, TimeDelta::zero()));
+    assert!(!a.approx_eq(&a, TimeDelta::nanoseconds(-1)));
+
+    // only the instants matter, not the offsets
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    assert!(a.with_timezone(&kst).approx_eq(&b, TimeDelta::milliseconds(2)));
+    assert!(!a.approx_eq(&b.with_timezone(&kst), TimeDelta::microseconds(500)));
+
+    // the extremes of the range
+    assert!(DateTime::<Utc>::MIN_UTC.approx_eq(&DateTime::<Utc>::MAX_UTC, TimeDelta::max_value()));
+    assert!(DateTime::<Utc>::MAX_UTC.approx_eq(&DateTime::<Utc>::MAX_UTC, TimeDelta::zero()));
+}
+
+#[test]
+fn test_datetime_eq_to_second() {
+    let hmsn = |h, m, s, n| Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_nano_opt(h, m, s, n).unwrap();
+
+    // differing only in the fractional second
+    assert!(hmsn(10, 0, 0, 0).eq_to_second(&hmsn(10, 0, 0, 999_999_999)));
+    assert!(hmsn(10, 0, 0, 123_456_789).eq_to_second(&hmsn(10, 0, 0, 1)));
+    assert!(hmsn(10, 0, 0, 0).eq_to_second(&hmsn(10, 0, 0, 0)));
+
+    // differing by a full second, or across a second boundary
+    assert!(!hmsn(10, 0, 0, 0).eq_to_second(&hmsn(10, 0, 1, 0)));
+    assert!(!hmsn(10, 0, 0, 999_999_999).eq_to_second(&hmsn(10, 0, 1, 0)));
+    assert!(!hmsn(10, 0, 1, 0).eq_to_second(&hmsn(10, 0, 0, 999_999_999)));
+
+    // only the instants matter, not the offsets
+    let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+    assert!(hmsn(10, 0, 0, 0).with_timezone(&ist).eq_to_second(&hmsn(10, 0, 0, 500_000_000)));
+
+    // a leap second is a second of its own
+    let leap = |n| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, n).unwrap();
+    assert!(leap(1_000_000_000).eq_to_second(&leap(1_999_999_999)));
+    assert!(!leap(1_000_000_000).eq_to_second(&leap(999_999_999)));
+    assert!(!leap(1_500_000_000).eq_to_second(&(leap(0) + TimeDelta::seconds(1))));
+}
+
+#[test]
+fn test_datetime_from_timestamp_millis_saturating() {
+    let from_millis = DateTime::<Utc>::from_timestamp_millis_saturating;
+    let dt = Utc.ymd_opt(2023, 5, 17).unwrap().and_hms_milli_opt(8, 30, 15, 123).unwrap();
+    assert_eq!(from_millis(dt.timestamp_millis()), dt);
+    assert_eq!(from_millis(0), Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(
+        from_millis(-1),
+        Utc.ymd_opt(1969, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 999).unwrap()
+    );
+
+    assert_eq!(from_millis(i64::MAX), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(from_millis(i64::MIN), DateTime::<Utc>::MIN_UTC);
+
+    // the limits themselves are not clamped
+    let max = DateTime::<Utc>::MAX_UTC.timestamp_millis();
+    let min = DateTime::<Utc>::MIN_UTC.timestamp_millis();
+    assert_eq!(from_millis(max), DateTime::<Utc>::MAX_UTC.trunc_subsecs(3));
+    assert_eq!(from_millis(max + 1), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(from_millis(min), DateTime::<Utc>::MIN_UTC);
+    assert_eq!(from_millis(min - 1), DateTime::<Utc>::MIN_UTC);
+    assert_eq!(Utc.timestamp_millis_opt(max + 1), LocalResult::None);
+}
+
+#[test]
+fn test_datetime_to_micros_precision() {
+    let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
+    let dt = pst.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(10, 5, 13, 84_660_999).unwrap();
+    let truncated = dt.to_micros_precision();
+    assert_eq!(
+        truncated,
+        pst.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(10, 5, 13, 84_660_000).unwrap()
+    );
+    assert_eq!(truncated.offset(), &pst);
+    assert_eq!(truncated, dt.trunc_subsecs(6));
+    assert_eq!(truncated.to_micros_precision(), truncated);
+    assert_eq!(
+        DateTime::<Utc>::from_timestamp_micros(truncated.timestamp_micros()),
+        Some(truncated.with_timezone(&Utc))
+    );
+
+    let dt = Utc.ymd_opt(2018, 1, 11).unwrap().and_hms_nano_opt(10, 5, 13, 999_999_999).unwrap();
+    assert_eq!(dt.to_micros_precision().nanosecond(), 999_999_000);
+    assert_eq!(dt.to_micros_precision().second(), 13);
+
+    // a leap second stays a leap second
+    let leap =
+        Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_234_567_891).unwrap();
+    assert_eq!(leap.to_micros_precision().nanosecond(), 1_234_567_000);
+}
+
+#[test]
+fn test_datetime_with_year_clamped() {
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.ymd_opt(2024, 2, 29).unwrap().and_hms_opt(23, 30, 0).unwrap();
+    assert_eq!(
+        dt.with_year_clamped(2023),
+        Some(kst.ymd_opt(2023, 2, 28).unwrap().and_hms_opt(23, 30, 0).unwrap())
+    );
+    assert_eq!(
+        dt.with_year_clamped(2028),
+        Some(kst.ymd_opt(2028, 2, 29).unwrap().and_hms_opt(23, 30, 0).unwrap())
+    );
+    assert_eq!(dt.with_year_clamped(i32::MAX), None);
+}
+
+#[test]
+fn test_datetime_builder() {
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = kst.ymd_opt(2022, 1, 31).unwrap().and_hms_nano_opt(9, 30, 15, 500).unwrap();
+
+    // changing the month first would give February 31
+    assert_eq!(dt.with_month(2), None);
+    assert_eq!(
+        dt.builder().month(2).day(15).build(),
+        LocalResult::Single(
+            kst.ymd_opt(2022, 2, 15).unwrap().and_hms_nano_opt(9, 30, 15, 500).unwrap()
+        )
+    );
+
+    // no changes
+    assert_eq!(dt.builder().build(), LocalResult::Single(dt));
+
+    // every field at once
+    assert_eq!(
+        dt.builder()
+            .year(2024)
+            .month(2)
+            .day(29)
+            .hour(23)
+            .minute(0)
+            .second(59)
+            .nanosecond(0)
+            .build(),
+        LocalResult::Single(kst.ymd_opt(2024, 2, 29).unwrap().and_hms_opt(23, 0, 59).unwrap())
+    );
+
+    // leap second
+    assert_eq!(
+        dt.builder().second(59).nanosecond(1_000_000_000).build().unwrap().to_rfc3339(),
+        "2022-01-31T09:30:60+09:00"
+    );
+
+    // invalid results
+    assert_eq!(dt.builder().month(2).build(), LocalResult::None);
+    assert_eq!(dt.builder().year(2023).month(2).day(29).build(), LocalResult::None);
+    assert_eq!(dt.builder().hour(24).build(), LocalResult::None);
+    assert_eq!(dt.builder().minute(60).build(), LocalResult::None);
+    assert_eq!(dt.builder().year(i32::MAX).build(), LocalResult::None);
+}
+
+#[test]
+fn test_datetime_checked_add_relative() {
+    let rd = |y, m, d, time| RelativeDelta::new(y, m, d, time);
+
+    // months are applied before days, clamping across a leap day
+    let dt = Utc.ymd_opt(2024, 1, 31).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    assert_eq!(
+        dt.checked_add_relative(rd(0, 1, 1, TimeDelta::zero())),
+        LocalResult::Single(Utc.ymd_opt(2024, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap())
+    );
+    assert_eq!(
+        dt.checked_add_relative(rd(0, 1, 10, TimeDelta::hours(15))),
+        LocalResult::Single(Utc.ymd_opt(2024, 3, 11).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    );
+    let leap = Utc.ymd_opt(2024, 2, 29).unwrap().and_hms_opt(9, 0, 0).unwrap();
+    assert_eq!(
+        leap.checked_add_relative(rd(1, 0, 1, TimeDelta::zero())),
+        LocalResult::Single(Utc.ymd_opt(2025, 3, 1).unwrap().and_hms_opt(9, 0, 0).unwrap())
+    );
+    assert_eq!(
+        leap.checked_add_relative(rd(-1, -2, -3, TimeDelta::minutes(-30))),
+        LocalResult::Single(Utc.ymd_opt(2022, 12, 26).unwrap().and_hms_opt(8, 30, 0).unwrap())
+    );
+
+    // days keep the wall-clock time across a DST boundary
+    let dt = Cet.ymd_opt(2022, 2, 26).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let later = dt.checked_add_relative(rd(0, 1, 1, TimeDelta::zero())).unwrap();
+    assert_eq!(later.naive_local().to_string(), "2022-03-27 12:00:00");
+    assert_eq!(later.offset().local_minus_utc(), 7200);
+    assert_eq!(later - dt, TimeDelta::days(29) - TimeDelta::hours(1));
+
+    // the zone is resolved once, at the end
+    assert_eq!(
+        dt.checked_add_relative(rd(0, 1, 1, TimeDelta::hours(-9) - TimeDelta::minutes(30))),
+        LocalResult::None
+    );
+    match dt.checked_add_relative(rd(0, 8, 4, TimeDelta::hours(-9) - TimeDelta::minutes(30))) {
+        LocalResult::Ambiguous(a, b) => {
+            assert_eq!(a.naive_local().to_string(), "2022-10-30 02:30:00");
+            assert_eq!(b.naive_local(), a.naive_local());
+        }
+        e => panic!("expected an ambiguous result, got {:?}", e),
+    }
+
+    // out of range
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.checked_add_relative(rd(0, 0, 1, TimeDelta::zero())), LocalResult::None);
+    assert_eq!(
+        max.checked_add_relative(rd(i32::MAX, i32::MAX, 0, TimeDelta::zero())),
+        LocalResult::None
+    );
+    assert_eq!(max.checked_add_relative(rd(0, 0, i64::MIN, TimeDelta::zero())), LocalResult::None);
+}
+
+#[test]
+fn test_datetime_fiscal_periods() {
+    let ymd = |y, m, d| Utc.ymd_opt(y, m, d).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+    // a fiscal year starting in July
+    assert_eq!(ymd(2023, 6, 30).fiscal_quarter(7), 4);
+    assert_eq!(ymd(2023, 6, 30).fiscal_year(7), 2023);
+    assert_eq!(ymd(2023, 7, 1).fiscal_quarter(7), 1);
+    assert_eq!(ymd(2023, 7, 1).fiscal_year(7), 2024);
+    assert_eq!(ymd(2023, 10, 1).fiscal_quarter(7), 2);
+    assert_eq!(ymd(2024, 1, 1).fiscal_quarter(7), 3);
+    assert_eq!(ymd(2024, 1, 1).fiscal_year(7), 2024);
+
+    // calendar quarters
+    for month in 1..=12 {
+        let dt = ymd(2023, month, 1);
+        assert_eq!(dt.fiscal_quarter(1), (month - 1) / 3 + 1);
+        assert_eq!(dt.fiscal_year(1), 2023);
+    }
+
+    // the local date is used
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let dt = Utc.ymd_opt(2023, 6, 30).unwrap().and_hms_opt(20, 0, 0).unwrap();
+    assert_eq!(dt.fiscal_year(7), 2023);
+    assert_eq!(dt.with_timezone(&kst).fiscal_year(7), 2024);
+}
+
+#[test]
+#[should_panic]
+fn test_datetime_fiscal_quarter_invalid_start() {
+    Utc.ymd_opt(2023, 6, 30).unwrap().and_hms_opt(0, 0, 0).unwrap().fiscal_quarter(13);
+}
+
+#[test]
+fn test_datetime_julian_date() {
+    let epoch = Utc.at(1970, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(epoch.to_julian_date(), 2_440_587.5);
+    assert_eq!(DateTime::<Utc>::from_julian_date(2_440_587.5), Some(epoch));
+
+    // J2000.0, taken in UTC
+    let j2000 = Utc.at(2000, 1, 1, 12, 0, 0).unwrap();
+    assert_eq!(j2000.to_julian_date(), 2_451_545.0);
+    assert_eq!(DateTime::<Utc>::from_julian_date(2_451_545.0), Some(j2000));
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    assert_eq!(j2000.with_timezone(&kst).to_julian_date(), 2_451_545.0);
+
+    assert_eq!(Utc.at(1858, 11, 17, 0, 0, 0).unwrap().to_julian_date(), 2_400_000.5);
+    assert_eq!(Utc.at(2000, 1, 1, 18, 0, 0).unwrap().to_julian_date(), 2_451_545.25);
+
+    // round trip, precise to tens of microseconds
+    let dt = Utc.at(2023, 5, 17, 9, 41, 27).unwrap() + TimeDelta::milliseconds(250);
+    let back = DateTime::<Utc>::from_julian_date(dt.to_julian_date()).unwrap();
+    assert!((back - dt).num_microseconds().unwrap().abs() < 50);
+
+    assert_eq!(DateTime::<Utc>::from_julian_date(f64::NAN), None);
+    assert_eq!(DateTime::<Utc>::from_julian_date(f64::INFINITY), None);
+    assert_eq!(DateTime::<Utc>::from_julian_date(1e300), None);
+}
+
+#[test]
+fn test_datetime_floor_to_calendar_period() {
+    let dt = Utc.ymd_opt(2024, 3, 15).unwrap().and_hms_nano_opt(16, 20, 30, 500).unwrap();
+    assert_eq!(dt.floor_to_month(), Utc.at(2024, 3, 1, 0, 0, 0));
+    assert_eq!(dt.floor_to_quarter(), Utc.at(2024, 1, 1, 0, 0, 0));
+    assert_eq!(dt.floor_to_year(), Utc.at(2024, 1, 1, 0, 0, 0));
+
+    let dt = Utc.at(2024, 12, 31, 23, 59, 59).unwrap();
+    assert_eq!(dt.floor_to_month(), Utc.at(2024, 12, 1, 0, 0, 0));
+    assert_eq!(dt.floor_to_quarter(), Utc.at(2024, 10, 1, 0, 0, 0));
+    let dt = Utc.at(2024, 7, 1, 0, 0, 0).unwrap();
+    assert_eq!(dt.floor_to_month(), LocalResult::Single(dt));
+    assert_eq!(dt.floor_to_quarter(), LocalResult::Single(dt));
+
+    // the periods are those of the local date
+    let tz = FixedOffset::east_opt(3600).unwrap();
+    let local = Utc.at(2024, 3, 31, 23, 30, 0).unwrap().with_timezone(&tz);
+    assert_eq!(local.floor_to_month(), tz.at(2024, 4, 1, 0, 0, 0));
+    assert_eq!(local.floor_to_quarter(), tz.at(2024, 4, 1, 0, 0, 0));
+
+    // the offset at the start of the period may differ from that of `self`
+    let summer = Cet.at(2022, 8, 20, 12, 0, 0).unwrap();
+    assert_eq!(summer.floor_to_month().unwrap().offset(), &FixedOffset::east_opt(7200).unwrap());
+    assert_eq!(summer.floor_to_quarter(), Cet.at(2022, 7, 1, 0, 0, 0));
+    assert_eq!(summer.floor_to_year().unwrap().offset(), &FixedOffset::east_opt(3600).unwrap());
+}
+
+#[test]
+fn test_datetime_ceil_to_calendar_period() {
+    let dt = Utc.at(2024, 3, 15, 16, 20, 30).unwrap();
+    assert_eq!(dt.ceil_to_month(), Utc.at(2024, 4, 1, 0, 0, 0));
+    assert_eq!(dt.ceil_to_year(), Utc.at(2025, 1, 1, 0, 0, 0));
+
+    // already at the start of the period
+    let start = Utc.at(2024, 3, 1, 0, 0, 0).unwrap();
+    assert_eq!(start.ceil_to_month(), LocalResult::Single(start));
+    assert_eq!(start.ceil_to_year(), Utc.at(2025, 1, 1, 0, 0, 0));
+    let new_year = Utc.at(2024, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(new_year.ceil_to_month(), LocalResult::Single(new_year));
+    assert_eq!(new_year.ceil_to_year(), LocalResult::Single(new_year));
+    let just_after = start + TimeDelta::nanoseconds(1);
+    assert_eq!(just_after.ceil_to_month(), Utc.at(2024, 4, 1, 0, 0, 0));
+
+    // December rolls over into the next year
+    let december = Utc.at(2024, 12, 31, 23, 59, 59).unwrap();
+    assert_eq!(december.ceil_to_month(), Utc.at(2025, 1, 1, 0, 0, 0));
+    assert_eq!(december.ceil_to_year(), Utc.at(2025, 1, 1, 0, 0, 0));
+
+    // the periods are those of the local date
+    let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+    let local = Utc.at(2024, 4, 1, 2, 0, 0).unwrap().with_timezone(&tz);
+    assert_eq!(local.ceil_to_month(), tz.at(2024, 4, 1, 0, 0, 0));
+    let summer = Cet.at(2022, 10, 15, 12, 0, 0).unwrap();
+    assert_eq!(summer.ceil_to_month().unwrap().offset(), &FixedOffset::east_opt(3600).unwrap());
+
+    assert_eq!(DateTime::<Utc>::MAX_UTC.ceil_to_month(), LocalResult::None);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.ceil_to_year(), LocalResult::None);
+}
+
+#[test]
+fn test_datetime_next_occurrence_of() {
+    let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let dt = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(9, 30, 0).unwrap();
+
+    assert_eq!(dt.next_occurrence_of(hms(12, 0, 0)), Utc.at(2023, 5, 1, 12, 0, 0));
+    assert_eq!(dt.next_occurrence_of(hms(9, 30, 1)), Utc.at(2023, 5, 1, 9, 30, 1));
+    assert_eq!(dt.next_occurrence_of(hms(9, 30, 0)), Utc.at(2023, 5, 2, 9, 30, 0));
+    assert_eq!(dt.next_occurrence_of(hms(8, 0, 0)), Utc.at(2023, 5, 2, 8, 0, 0));
+
+    // the wall-clock time in the local offset is used
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let local = dt.with_timezone(&kst); // 18:30 local
+    assert_eq!(local.next_occurrence_of(hms(12, 0, 0)), kst.at(2023, 5, 2, 12, 0, 0));
+    assert_eq!(local.next_occurrence_of(hms(20, 0, 0)), kst.at(2023, 5, 1, 20, 0, 0));
+
+    // the target day has a DST gap or fold
+    let before_gap = Cet.at(2022, 3, 26, 12, 0, 0).unwrap();
+    assert_eq!(before_gap.next_occurrence_of(hms(2, 30, 0)), LocalResult::None);
+    assert_eq!(before_gap.next_occurrence_of(hms(3, 30, 0)), Cet.at(2022, 3, 27, 3, 30, 0));
+    let before_fold = Cet.at(2022, 10, 29, 12, 0, 0).unwrap();
+    assert!(matches!(before_fold.next_occurrence_of(hms(2, 30, 0)), LocalResult::Ambiguous(..)));
+
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.next_occurrence_of(hms(0, 0, 0)), LocalResult::None);
+}
+
+/// A test zone at UTC-3 that moves to UTC-2 at 2022-11-05 02:30 UTC, so the local clock jumps
+/// from 23:30 to 00:30 and the gap does not start at midnight.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct MidnightGap;
+
+impl MidnightGap {
+    fn offset_at(utc: &NaiveDateTime) -> FixedOffset {
+        let change = NaiveDate::from_ymd_opt(2022, 11, 5).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        FixedOffset::east_opt(if *utc < change { -3 * 3600 } else { -2 * 3600 }).unwrap()
+    }
+}
+
+impl TimeZone for MidnightGap {
+    type Offset = FixedOffset;
+
+    fn from_offset(_: &FixedOffset) -> MidnightGap {
+        MidnightGap
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        // the offset only grows, so there are no ambiguous local times
+        let offsets =
+            [FixedOffset::west_opt(3 * 3600).unwrap(), FixedOffset::west_opt(2 * 3600).unwrap()];
+        match offsets.iter().find(|&&off| MidnightGap::offset_at(&(*local - off)) == off) {
+            Some(&off) => LocalResult::Single(off),
+            None => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        MidnightGap::offset_at(utc)
+    }
+}
+
+#[test]
+fn test_datetime_round_to_nearest_day() {
+    let at = |h, m| Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(h, m, 0).unwrap();
+    let midnight = |d| Utc.ymd_opt(2023, 5, d).unwrap().and_hms_opt(0, 0, 0);
+
+    assert_eq!(at(0, 0).round_to_nearest_day(), midnight(1));
+    assert_eq!(at(11, 0).round_to_nearest_day(), midnight(1));
+    assert_eq!(at(11, 59).round_to_nearest_day(), midnight(1));
+    assert_eq!(at(12, 0).round_to_nearest_day(), midnight(2));
+    assert_eq!(at(13, 0).round_to_nearest_day(), midnight(2));
+    assert_eq!(at(23, 59).round_to_nearest_day(), midnight(2));
+
+    // rounding happens on the local wall clock
+    let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    let dt = tz.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
+    assert_eq!(dt.round_to_nearest_day(), tz.ymd_opt(2023, 5, 2).unwrap().and_hms_opt(0, 0, 0));
+
+    // days shortened or lengthened by DST still round at noon
+    let dt = Cet.at(2022, 3, 27, 11, 0, 0).unwrap();
+    assert_eq!(dt.round_to_nearest_day(), Cet.at(2022, 3, 27, 0, 0, 0).single());
+    let dt = Cet.at(2022, 3, 27, 12, 0, 0).unwrap();
+    let rounded = dt.round_to_nearest_day().unwrap();
+    assert_eq!(rounded, Cet.at(2022, 3, 28, 0, 0, 0).unwrap());
+    assert_eq!(rounded.offset().local_minus_utc(), 2 * 3600);
+    let dt = Cet.at(2022, 10, 30, 14, 0, 0).unwrap();
+    let rounded = dt.round_to_nearest_day().unwrap();
+    assert_eq!(rounded, Cet.at(2022, 10, 31, 0, 0, 0).unwrap());
+    assert_eq!(rounded.offset().local_minus_utc(), 3600);
+
+    // a skipped midnight: the day starts when the gap that began the evening before ends
+    assert_eq!(MidnightGap.at(2022, 11, 5, 0, 0, 0), LocalResult::None);
+    let dt = MidnightGap.at(2022, 11, 4, 15, 0, 0).unwrap();
+    let rounded = dt.round_to_nearest_day().unwrap();
+    assert_eq!(rounded, MidnightGap.at(2022, 11, 5, 0, 30, 0).unwrap());
+    assert_eq!(rounded.naive_utc().to_string(), "2022-11-05 02:30:00");
+    let dt = MidnightGap.at(2022, 11, 5, 10, 20, 0).unwrap();
+    assert_eq!(
+        dt.quantize_local(TimeDelta::hours(1)),
+        MidnightGap.at(2022, 11, 5, 9, 30, 0).single()
+    );
+
+    let max = DateTime::<Utc>::MAX_UTC;
+    assert_eq!(max.round_to_nearest_day(), None);
+}
+
+#[test]
+fn test_datetime_with_time_from() {
+    let reference = Utc.at(2020, 1, 1, 14, 30, 0).unwrap();
+
+    let dt = Utc.at(2023, 5, 1, 8, 15, 0).unwrap();
+    assert_eq!(dt.with_time_from(&reference), Utc.at(2023, 5, 1, 14, 30, 0));
+    assert_eq!(reference.with_time_from(&reference), LocalResult::Single(reference));
+
+    // the wall clock time of `other` is used, not its instant
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let dt = kst.at(2023, 5, 1, 8, 15, 0).unwrap();
+    assert_eq!(dt.with_time_from(&reference), kst.at(2023, 5, 1, 14, 30, 0));
+    assert_eq!(reference.with_time_from(&dt), Utc.at(2020, 1, 1, 8, 15, 0));
+
+    // resolved again in the time zone of `self`
+    let gap = Utc.at(2020, 1, 1, 2, 30, 0).unwrap();
+    let dt = Cet.at(2022, 3, 27, 12, 0, 0).unwrap();
+    assert_eq!(dt.with_time_from(&gap), LocalResult::None);
+    let dt = Cet.at(2022, 10, 30, 12, 0, 0).unwrap();
+    assert_eq!(dt.with_time_from(&gap), Cet.at(2022, 10, 30, 2, 30, 0));
+    assert!(matches!(dt.with_time_from(&gap), LocalResult::Ambiguous(..)));
+}
+
+#[test]
+fn test_datetime_add_business_days() {
+    let at = |y, m, d| Utc.ymd_opt(y, m, d).unwrap().and_hms_opt(9, 30, 0).unwrap();
+
+    // 2023-05-05 is a Friday
+    let fri = at(2023, 5, 5);
+    assert_eq!(fri.add_business_days(0), Some(fri));
+    assert_eq!(fri.add_business_days(1), Some(at(2023, 5, 8)));
+    assert_eq!(fri.add_business_days(5), Some(at(2023, 5, 12)));
+    assert_eq!(fri.add_business_days(6), Some(at(2023, 5, 15)));
+    assert_eq!(fri.add_business_days(-1), Some(at(2023, 5, 4)));
+    assert_eq!(fri.add_business_days(-5), Some(at(2023, 4, 28)));
+    assert_eq!(at(2023, 5, 8).add_business_days(-1), Some(fri));
+    assert_eq!(at(2023, 5, 10).add_business_days(-7), Some(at(2023, 5, 1)));
+
+    // from a weekend
+    let sat = at(2023, 5, 6);
+    assert_eq!(sat.add_business_days(0), Some(sat));
+    assert_eq!(sat.add_business_days(1), Some(at(2023, 5, 8)));
+    assert_eq!(sat.add_business_days(5), Some(at(2023, 5, 12)));
+    assert_eq!(at(2023, 5, 7).add_business_days(-1), Some(fri));
+    assert_eq!(at(2023, 5, 7).add_business_days(-5), Some(at(2023, 5, 1)));
+
+    // consistent with the count
+    for n in -12..=12 {
+        let dt = fri.add_business_days(n).unwrap();
+        assert_eq!(fri.business_days_between(&dt), n);
+    }
+
+    // the local time is resolved again, here across the switch to summer time
+    let dt = Cet.ymd_opt(2022, 3, 25).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let next = dt.add_business_days(1).unwrap();
+    assert_eq!(next.naive_local(), dt.naive_local() + TimeDelta::days(3));
+    assert_eq!(next.offset().local_minus_utc(), 7200);
+
+    assert_eq!(fri.add_business_days(i64::MAX), None);
+    assert_eq!(fri.add_business_days(i64::MIN), None);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.add_business_days(1), None);
+}
+
+#[test]
+fn test_datetime_business_days_between() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let at = |y, m, d| Utc.ymd_opt(y, m, d).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+    // 2023-05-01 is a Monday
+    assert_eq!(at(2023, 5, 1).business_days_between(&at(2023, 5, 8)), 5);
+    assert_eq!(at(2023, 5, 8).business_days_between(&at(2023, 5, 1)), -5);
+    assert_eq!(at(2023, 5, 1).business_days_between(&at(2023, 5, 1)), 0);
+    assert_eq!(at(2023, 5, 6).business_days_between(&at(2023, 5, 8)), 0);
+    assert_eq!(at(2023, 5, 5).business_days_between(&at(2023, 5, 8)), 1);
+    assert_eq!(at(2023, 5, 4).business_days_between(&at(2023, 5, 10)), 4);
+    assert_eq!(at(2023, 1, 1).business_days_between(&at(2024, 1, 1)), 260);
+
+    // only the local dates matter
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let fri = kst.ymd_opt(2023, 5, 5).unwrap().and_hms_opt(23, 0, 0).unwrap();
+    let sat = kst.ymd_opt(2023, 5, 6).unwrap().and_hms_opt(1, 0, 0).unwrap();
+    assert_eq!(fri.business_days_between(&sat), 1);
+
+    let holidays = [ymd(2023, 5, 3), ymd(2023, 5, 3), ymd(2023, 5, 6), ymd(2023, 5, 8)];
+    assert_eq!(at(2023, 5, 1).business_days_between_excluding(&at(2023, 5, 8), &holidays), 4);
+    assert_eq!(at(2023, 5, 8).business_days_between_excluding(&at(2023, 5, 1), &holidays), -4);
+    assert_eq!(at(2023, 5, 1).business_days_between_excluding(&at(2023, 5, 9), &holidays), 4);
+}
+
+#[test]
+fn test_datetime_with_offset_from() {
+    let plus5 = FixedOffset::east_opt(5 * 60 * 60).unwrap();
+    let zero = FixedOffset::east_opt(0).unwrap();
+    let reference = plus5.ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let dt = zero.ymd_opt(2023, 6, 15).unwrap().and_hms_milli_opt(12, 0, 0, 250).unwrap();
+
+    let converted = dt.with_offset_from(&reference);
+    assert_eq!(converted, dt);
+    assert_eq!(converted.offset(), &plus5);
+    assert_eq!(converted.to_rfc3339(), "2023-06-15T17:00:00.250+05:00");
+    assert_eq!(converted.with_offset_from(&dt).to_rfc3339(), "2023-06-15T12:00:00.250+00:00");
+}
+
+#[test]
+fn test_datetime_shift_offset() {
+    let zero = FixedOffset::east_opt(0).unwrap();
+    let plus1 = FixedOffset::east_opt(60 * 60).unwrap();
+    let dt = zero.ymd_opt(2023, 6, 15).unwrap().and_hms_opt(12, 0, 0).unwrap();
+
+    let shifted = dt.shift_offset(plus1).unwrap();
+    assert_eq!(shifted.naive_local(), dt.naive_local());
+    assert_eq!(shifted.offset(), &plus1);
+    assert_eq!(shifted.to_rfc3339(), "2023-06-15T12:00:00+01:00");
+    assert_eq!(shifted, dt - TimeDelta::hours(1));
+    assert_eq!(shifted.shift_offset(FixedOffset::west_opt(60 * 60).unwrap()), Some(dt));
+    assert_eq!(dt.shift_offset(zero), Some(dt));
+
+    // the resulting offset must be less than a day
+    let plus23 = FixedOffset::east_opt(23 * 60 * 60).unwrap();
+    assert_eq!(shifted.shift_offset(plus23), None);
+
+    // the resulting instant must be in range
+    let max = zero.from_utc_datetime(&NaiveDateTime::MAX);
+    assert_eq!(max.shift_offset(FixedOffset::west_opt(60 * 60).unwrap()), None);
+}
+
+#[test]
+fn test_datetime_with_timezone_leap_second() {
+    let edt = FixedOffset::west_opt(4 * 60 * 60).unwrap();
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let leap = edt.ymd_opt(2015, 6, 30).unwrap().and_hms_micro_opt(19, 59, 59, 1_234_567).unwrap();
+    assert_eq!(leap.timestamp_subsec_nanos(), 1_234_567_000);
+
+    let utc = leap.with_timezone(&Utc);
+    assert_eq!(utc.timestamp_subsec_nanos(), 1_234_567_000);
+    assert_eq!(utc.to_rfc3339(), "2015-06-30T23:59:60.234567+00:00");
+
+    let roundtrip = utc.with_timezone(&kst).with_timezone(&edt);
+    assert_eq!(roundtrip.timestamp_subsec_nanos(), 1_234_567_000);
+    assert_eq!(roundtrip.to_rfc3339(), "2015-06-30T19:59:60.234567-04:00");
+    assert_eq!(roundtrip, leap);
+    assert_eq!(roundtrip.cmp_total(&leap), core::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_datetime_bounds() {
+    let nanos = TimeDelta::nanoseconds(1);
+    assert_eq!(DateTime::<Utc>::MIN_UTC.naive_utc(), NaiveDateTime::MIN);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.naive_utc(), NaiveDateTime::MAX);
+    assert_eq!(DateTime::<Utc>::MIN_UTC.checked_sub_signed(nanos), None);
+    assert_eq!(DateTime::<Utc>::MAX_UTC.checked_add_signed(nanos), None);
+    assert_eq!(
+        Utc.from_local_datetime(&NaiveDateTime::MAX).single(),
+        Some(DateTime::<Utc>::MAX_UTC)
+    );
+}
+
+#[test]
+fn test_datetime_timestamp_f64() {
+    let epoch = Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(epoch.timestamp_f64(), 0.0);
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(0.0), Some(epoch));
+
+    let dt = Utc.ymd_opt(2023, 4, 12).unwrap().and_hms_opt(8, 15, 2).unwrap();
+    assert_eq!(dt.timestamp_f64(), 1_681_287_302.0);
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(1_681_287_302.0), Some(dt));
+
+    // the fraction is only approximately representable
+    let dt = Utc.ymd_opt(2023, 4, 12).unwrap().and_hms_milli_opt(8, 15, 2, 123).unwrap();
+    assert!((dt.timestamp_f64() - 1_681_287_302.123).abs() < 1e-6);
+    let roundtrip = DateTime::<Utc>::from_timestamp_f64(dt.timestamp_f64()).unwrap();
+    assert!(roundtrip.approx_eq(&dt, TimeDelta::microseconds(1)));
+
+    // before the epoch the fraction counts forward from the previous second
+    let dt = Utc.ymd_opt(1969, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 750).unwrap();
+    assert_eq!(dt.timestamp_f64(), -0.25);
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(-0.25), Some(dt));
+
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(f64::NAN), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(f64::INFINITY), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_f64(1e300), None);
+}
+
+#[test]
+fn test_datetime_timestamp_parts() {
+    let dt = Utc.ymd_opt(2001, 9, 9).unwrap().and_hms_nano_opt(1, 46, 40, 555).unwrap();
+    assert_eq!(dt.as_timestamp_parts(), (1_000_000_000, 555));
+    assert_eq!(DateTime::<Utc>::from_timestamp_parts(1_000_000_000, 555), Some(dt));
+
+    // the parts do not depend on the offset
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    assert_eq!(dt.with_timezone(&kst).as_timestamp_parts(), (1_000_000_000, 555));
+
+    // leap second
+    let leap =
+        Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_234_567_890).unwrap();
+    let (secs, nanos) = leap.as_timestamp_parts();
+    assert_eq!((secs, nanos), (1_483_228_799, 1_234_567_890));
+    let roundtrip = DateTime::<Utc>::from_timestamp_parts(secs, nanos).unwrap();
+    assert_eq!(roundtrip, leap);
+    assert_eq!(roundtrip.to_rfc3339(), "2016-12-31T23:59:60.234567890+00:00");
+
+    // negative timestamps
+    let dt = Utc.ymd_opt(1969, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 100).unwrap();
+    assert_eq!(dt.as_timestamp_parts(), (-1, 100));
+    assert_eq!(DateTime::<Utc>::from_timestamp_parts(-1, 100), Some(dt));
+
+    // bounds
+    for &dt in [DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC].iter() {
+        let (secs, nanos) = dt.as_timestamp_parts();
+        assert_eq!(DateTime::<Utc>::from_timestamp_parts(secs, nanos), Some(dt));
+    }
+    assert_eq!(DateTime::<Utc>::from_timestamp_parts(0, 2_000_000_000), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_parts(i64::MAX, 0), None);
+}
+
+#[test]
+fn test_datetime_overflowing_add_signed() {
+    let dt = Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+    assert_eq!(
+        dt.overflowing_add_signed(TimeDelta::minutes(90)),
+        (Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(11, 30, 0).unwrap(), false)
+    );
+    assert_eq!(
+        dt.overflowing_add_signed(TimeDelta::minutes(-90)),
+        (Utc.ymd_opt(2022, 3, 1).unwrap().and_hms_opt(8, 30, 0).unwrap(), false)
+    );
+
+    // saturates at the bounds
+    assert_eq!(dt.overflowing_add_signed(TimeDelta::max_value()), (DateTime::<Utc>::MAX_UTC, true));
+    assert_eq!(dt.overflowing_add_signed(TimeDelta::min_value()), (DateTime::<Utc>::MIN_UTC, true));
+    assert_eq!(
+        DateTime::<Utc>::MAX_UTC.overflowing_add_signed(TimeDelta::nanoseconds(1)),
+        (DateTime::<Utc>::MAX_UTC, true)
+    );
+    assert_eq!(
+        DateTime::<Utc>::MAX_UTC.overflowing_add_signed(TimeDelta::zero()),
+        (DateTime::<Utc>::MAX_UTC, false)
+    );
+
+    // the offset is kept
+    let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+    let (clamped, overflowed) =
+        dt.with_timezone(&kst).overflowing_add_signed(TimeDelta::days(1_000_000_000));
+    assert!(overflowed);
+    assert_eq!(clamped, DateTime::<Utc>::MAX_UTC);
+    assert_eq!(*clamped.offset(), kst);
+}
+
+#[test]
+fn test_datetime_ctime_string() {
+    let dt = Utc.ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9).unwrap();
+    assert_eq!(dt.to_ctime_string(), "Wed Feb 18 23:16:09 2015");
+    assert_eq!(DateTime::<Utc>::parse_from_ctime(&dt.to_ctime_string()), Ok(dt));
+
+    let dt = Utc.ymd_opt(2015, 3, 1).unwrap().and_hms_opt(9, 5, 0).unwrap();
+    assert_eq!(dt.to_ctime_string(), "Sun Mar  1 09:05:00 2015");
+    assert_eq!(DateTime::<Utc>::parse_from_ctime(&dt.to_ctime_string()), Ok(dt));
+    assert_eq!(DateTime::<Utc>::parse_from_ctime("Sun Mar 1 09:05:00 2015"), Ok(dt));
+
+    // the local time is written, without the offset
+    let dt = FixedOffset::east_opt(9 * 3600)
+        .unwrap()
+        .ymd_opt(2015, 3, 1)
+        .unwrap()
+        .and_hms_opt(9, 5, 0)
+        .unwrap();
+    assert_eq!(dt.to_ctime_string(), "Sun Mar  1 09:05:00 2015");
+
+    assert!(DateTime::<Utc>::parse_from_ctime("Mon Mar  1 09:05:00 2015").is_err());
+    assert!(DateTime::<Utc>::parse_from_ctime("Sun Mar  1 09:05:00").is_err());
+}
+
+#[test]
+fn test_datetime_from_timestamp_micros() {
+    let epoch = Utc.ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(epoch.timestamp_micros(), 0);
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(0), Some(epoch));
+
+    let dt = Utc.ymd_opt(2023, 5, 17).unwrap().and_hms_micro_opt(8, 30, 15, 123_456).unwrap();
+    assert_eq!(dt.timestamp_micros(), 1_684_312_215_123_456);
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(1_684_312_215_123_456), Some(dt));
+
+    let before_epoch =
+        Utc.ymd_opt(1969, 12, 31).unwrap().and_hms_micro_opt(23, 59, 59, 999_999).unwrap();
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(-1), Some(before_epoch));
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(-1_000_000).unwrap().timestamp(), -1);
+
+    // i64 microseconds span about 292,000 years either side of the epoch, beyond the supported range
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(i64::MAX), None);
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(i64::MIN), None);
+    let max = DateTime::<Utc>::MAX_UTC.trunc_subsecs(6);
+    assert_eq!(DateTime::<Utc>::from_timestamp_micros(max.timestamp_micros()), Some(max));
+}
+
+#[test]
+fn test_datetime_into_timezone() {
+    let dt = Cet.at(2022, 10, 30, 2, 30, 0).earliest().unwrap();
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+
+    let borrowed = dt.with_timezone(&tokyo);
+    let consumed = dt.into_timezone(tokyo);
+    assert_eq!(consumed, borrowed);
+    assert_eq!(consumed.offset(), borrowed.offset());
+    assert_eq!(consumed.naive_local(), borrowed.naive_local());
+
+    let back = consumed.into_timezone(Cet);
+    assert_eq!(back, dt);
+    assert_eq!(back.offset(), dt.offset());
+    assert_eq!(dt.into_timezone(Utc), dt.with_timezone(&Utc));
+}
+
+#[test]
+fn test_datetime_quantize_local() {
+    let dt = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_milli_opt(10, 52, 30, 500).unwrap();
+    let at = |h, m| Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(h, m, 0);
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(15)), at(10, 45));
+    assert_eq!(dt.quantize_local(TimeDelta::hours(1)), at(10, 0));
+    assert_eq!(dt.quantize_local(TimeDelta::days(1)), at(0, 0));
+    assert_eq!(at(10, 45).unwrap().quantize_local(TimeDelta::minutes(15)), at(10, 45));
+
+    // the grid is anchored at local midnight, not at the UNIX epoch
+    let tz = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+    let dt = tz.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(10, 52, 30).unwrap();
+    let expected = tz.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(10, 0, 0);
+    assert_eq!(dt.quantize_local(TimeDelta::hours(1)), expected);
+
+    // spring forward: 02:00 to 03:00 is skipped
+    let dt = Cet.at(2022, 3, 27, 3, 20, 0).unwrap();
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(15)), Cet.at(2022, 3, 27, 3, 15, 0).single());
+    assert_eq!(dt.quantize_local(TimeDelta::hours(1)), Cet.at(2022, 3, 27, 3, 0, 0).single());
+    let dt = Cet.at(2022, 3, 27, 1, 59, 0).unwrap();
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(15)), Cet.at(2022, 3, 27, 1, 45, 0).single());
+
+    // fall back: 02:00 to 03:00 is repeated
+    let dt = Cet.at(2022, 10, 30, 2, 40, 0).latest().unwrap();
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(15)), Cet.at(2022, 10, 30, 2, 30, 0).latest());
+
+    assert_eq!(dt.quantize_local(TimeDelta::zero()), None);
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(-15)), None);
+    assert_eq!(dt.quantize_local(TimeDelta::minutes(7)), None);
+    assert_eq!(dt.quantize_local(TimeDelta::days(2)), None);
+}
+
+#[test]
+fn test_datetime_seconds_since_f64() {
+    let start = Utc.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = start + TimeDelta::milliseconds(1500);
+    assert_eq!(end.seconds_since_f64(&start), 1.5);
+    assert_eq!(start.seconds_since_f64(&end), -1.5);
+    assert_eq!(start.seconds_since_f64(&start), 0.0);
+
+    let end = start + TimeDelta::microseconds(250);
+    assert!((end.seconds_since_f64(&start) - 0.000_25).abs() < 1e-12);
+    assert!((start.seconds_since_f64(&end) + 0.000_25).abs() < 1e-12);
+
+    // the offsets do not matter, only the instants
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let end = (start + TimeDelta::nanoseconds(3_000_000_001)).with_timezone(&tokyo);
+    assert!((end.seconds_since_f64(&start) - 3.000_000_001).abs() < 1e-12);
+}
+
+#[test]
+fn test_earliest_latest() {
+    let utc = |h| Utc.ymd_opt(2023, 6, 1).unwrap().and_hms_opt(h, 0, 0).unwrap();
+    let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+    let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+    let times = vec![
+        utc(12).with_timezone(&kst),
+        utc(9).with_timezone(&edt),
+        utc(15).with_timezone(&FixedOffset::east_opt(0).unwrap()),
+        utc(10).with_timezone(&kst),
+    ];
+
+    let first = earliest(times.clone()).unwrap();
+    assert_eq!(first, utc(9));
+    assert_eq!(first.offset(), &edt);
+    assert_eq!(latest(times.iter().cloned()).unwrap(), utc(15));
+
+    // the first of several equal instants is returned
+    let ties = vec![utc(9).with_timezone(&kst), utc(9).with_timezone(&edt)];
+    assert_eq!(earliest(ties.clone()).unwrap().offset(), &kst);
+    assert_eq!(latest(ties).unwrap().offset(), &kst);
+
+    assert_eq!(earliest(Vec::<DateTime<Utc>>::new()), None);
+    assert_eq!(latest(Vec::<DateTime<Utc>>::new()), None);
+}
@@ -33,6 +33,8 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(all(feature = "alloc", not(any(feature = "std", test))))]
+use alloc::borrow::Cow;
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
@@ -42,6 +44,8 @@ use core::borrow::Borrow;
 use core::fmt;
 use core::str::FromStr;
 #[cfg(any(feature = "std", test))]
+use std::borrow::Cow;
+#[cfg(any(feature = "std", test))]
 use std::error::Error;
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
@@ -56,6 +60,9 @@ use crate::{Month, ParseMonthError, ParseWeekdayError, Weekday};
 pub(crate) mod locales;
 
 pub use parse::parse;
+pub(crate) use parse::parse_rfc3339_fast;
+#[cfg(any(feature = "alloc", feature = "std", test))]
+pub use parse::TimestampParser;
 pub use parsed::Parsed;
 /// L10n locales.
 #[cfg(feature = "unstable-locales")]
@@ -64,7 +71,7 @@ pub use strftime::StrftimeItems;
 
 #[cfg(not(feature = "unstable-locales"))]
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 struct Locale;
 
 /// An uninhabited type used for `InternalNumeric` and `InternalFixed` below.
@@ -215,6 +222,7 @@ pub enum Fixed {
     /// Offset from the local time to UTC with seconds (`+09:00:00` or `-04:00:00` or `+00:00:00`).
     ///
     /// In the parser, the colon can be omitted and/or surrounded with any amount of whitespace.
+    /// The seconds are optional, but must be preceded by a colon when present.
     /// The offset is limited from `-24:00:00` to `+24:00:00`,
     /// which is the same as [`FixedOffset`](../offset/struct.FixedOffset.html)'s range.
     TimezoneOffsetDoubleColon,
@@ -272,6 +280,19 @@ enum InternalInternal {
     Nanosecond6NoDot,
     /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to 9 and there is no leading dot.
     Nanosecond9NoDot,
+    /// Same as [`Nanosecond`](#variant.Nanosecond) but the accuracy is fixed to the given
+    /// number of digits (1 to 9). Further digits are truncated.
+    NanosecondDigits(u8),
+    /// Same as [`NanosecondDigits`](#variant.NanosecondDigits) but there is no leading dot.
+    NanosecondDigitsNoDot(u8),
+    /// Offset as a single-letter military time zone: `Z` for UTC, `A` to `M` (skipping `J`)
+    /// for +1 to +12 hours and `N` to `Y` for -1 to -12 hours.
+    ///
+    /// In the parser, `J` (local time) is accepted but leaves the offset unknown.
+    /// Offsets that are not whole hours between -12 and +12 are formatted like
+    /// [`TimezoneOffset`](#variant.TimezoneOffset) instead, e.g. `+0530`,
+    /// and the parser accepts that form as well.
+    TimezoneOffsetMilitary,
 }
 
 #[cfg(any(feature = "alloc", feature = "std", test))]
@@ -341,6 +362,9 @@ macro_rules! internal_fix {
     ($x:ident) => {
         Item::Fixed(Fixed::Internal(InternalFixed { val: InternalInternal::$x }))
     };
+    ($x:ident($arg:expr)) => {
+        Item::Fixed(Fixed::Internal(InternalFixed { val: InternalInternal::$x($arg) }))
+    };
 }
 
 /// An error from the `parse` function.
@@ -420,8 +444,10 @@ impl Error for ParseError {
 // to be used in this module and submodules
 const OUT_OF_RANGE: ParseError = ParseError(ParseErrorKind::OutOfRange);
 const IMPOSSIBLE: ParseError = ParseError(ParseErrorKind::Impossible);
-const NOT_ENOUGH: ParseError = ParseError(ParseErrorKind::NotEnough);
-const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
+// also used by `DateTime::parse_from_str_with_tz_resolver`
+pub(crate) const NOT_ENOUGH: ParseError = ParseError(ParseErrorKind::NotEnough);
+// also used by `DateTime::parse_from_rfc3339_bytes`
+pub(crate) const INVALID: ParseError = ParseError(ParseErrorKind::Invalid);
 const TOO_SHORT: ParseError = ParseError(ParseErrorKind::TooShort);
 const TOO_LONG: ParseError = ParseError(ParseErrorKind::TooLong);
 const BAD_FORMAT: ParseError = ParseError(ParseErrorKind::BadFormat);
@@ -437,16 +463,22 @@ pub fn format_item<'a>(
     item: &Item<'a>,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, None)?;
+    format_inner(&mut result, date, time, name_and_offset(off), item, None)?;
     w.pad(&result)
 }
 
+/// Borrows the name from an `(name, offset)` pair as given to the public format functions.
+#[cfg(any(feature = "alloc", feature = "std", test))]
+fn name_and_offset(off: Option<&(String, FixedOffset)>) -> Option<(&str, FixedOffset)> {
+    off.map(|&(ref name, off)| (name.as_str(), off))
+}
+
 #[cfg(any(feature = "alloc", feature = "std", test))]
 fn format_inner<'a>(
     result: &mut String,
     date: Option<&NaiveDate>,
     time: Option<&NaiveTime>,
-    off: Option<&(String, FixedOffset)>,
+    off: Option<(&str, FixedOffset)>,
     item: &Item<'a>,
     _locale: Option<Locale>,
 ) -> fmt::Result {
@@ -500,10 +532,10 @@ fn format_inner<'a>(
             use self::Numeric::*;
 
             let week_from_sun = |d: &NaiveDate| {
-                (d.ordinal() as i32 - d.weekday().num_days_from_sunday() as i32 + 7) / 7
+                (d.ordinal() as i32 - d.weekday().num_days_from_sunday() as i32 + 6) / 7
             };
             let week_from_mon = |d: &NaiveDate| {
-                (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 7) / 7
+                (d.ordinal() as i32 - d.weekday().num_days_from_monday() as i32 + 6) / 7
             };
 
             let (width, v) = match *spec {
@@ -530,7 +562,7 @@ fn format_inner<'a>(
                     1,
                     match (date, time, off) {
                         (Some(d), Some(t), None) => Some(d.and_time(*t).timestamp()),
-                        (Some(d), Some(t), Some(&(_, off))) => {
+                        (Some(d), Some(t), Some((_, off))) => {
                             Some((d.and_time(*t) - off).timestamp())
                         }
                         (_, _, _) => None,
@@ -676,31 +708,65 @@ fn format_inner<'a>(
                             let nano = t.nanosecond() % 1_000_000_000;
                             write!(result, "{:09}", nano)
                         }),
-                    TimezoneName => off.map(|&(ref name, _)| {
+                    Internal(InternalFixed { val: InternalInternal::NanosecondDigits(digits) }) => {
+                        time.map(|t| {
+                            let digits = usize::from(digits);
+                            let nano = t.nanosecond() % 1_000_000_000;
+                            let frac = nano / 10u32.pow(9 - digits as u32);
+                            write!(result, ".{:01$}", frac, digits)
+                        })
+                    }
+                    Internal(InternalFixed {
+                        val: InternalInternal::NanosecondDigitsNoDot(digits),
+                    }) => time.map(|t| {
+                        let digits = usize::from(digits);
+                        let nano = t.nanosecond() % 1_000_000_000;
+                        let frac = nano / 10u32.pow(9 - digits as u32);
+                        write!(result, "{:01$}", frac, digits)
+                    }),
+                    TimezoneName => off.map(|(name, _)| {
                         result.push_str(name);
                         Ok(())
                     }),
                     TimezoneOffsetColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Single)),
+                        .map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Single)),
                     TimezoneOffsetDoubleColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Double)),
+                        .map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Double)),
                     TimezoneOffsetTripleColon => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::Triple)),
-                    TimezoneOffsetColonZ => off
-                        .map(|&(_, off)| write_local_minus_utc(result, off, true, Colons::Single)),
+                        .map(|(_, off)| write_local_minus_utc(result, off, false, Colons::Triple)),
+                    TimezoneOffsetColonZ => {
+                        off.map(|(_, off)| write_local_minus_utc(result, off, true, Colons::Single))
+                    }
                     TimezoneOffset => {
-                        off.map(|&(_, off)| write_local_minus_utc(result, off, false, Colons::None))
+                        off.map(|(_, off)| write_local_minus_utc(result, off, false, Colons::None))
                     }
                     TimezoneOffsetZ => {
-                        off.map(|&(_, off)| write_local_minus_utc(result, off, true, Colons::None))
+                        off.map(|(_, off)| write_local_minus_utc(result, off, true, Colons::None))
                     }
                     Internal(InternalFixed { val: InternalInternal::TimezoneOffsetPermissive }) => {
                         panic!("Do not try to write %#z it is undefined")
                     }
+                    Internal(InternalFixed { val: InternalInternal::TimezoneOffsetMilitary }) => {
+                        off.map(|(_, off)| {
+                            let secs = off.local_minus_utc();
+                            let letter = match (secs / 3600, secs % 3600) {
+                                (0, 0) => b'Z',
+                                (hours @ 1..=9, 0) => b'A' + hours as u8 - 1,
+                                (hours @ 10..=12, 0) => b'K' + hours as u8 - 10,
+                                (hours @ -12..=-1, 0) => b'N' + (-hours) as u8 - 1,
+                                // no letter for this offset, so write it like `%z`
+                                _ => {
+                                    return write_local_minus_utc(result, off, false, Colons::None)
+                                }
+                            };
+                            result.push(char::from(letter));
+                            Ok(())
+                        })
+                    }
                     RFC2822 =>
                     // same as `%a, %d %b %Y %H:%M:%S %z`
                     {
-                        if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
+                        if let (Some(d), Some(t), Some((_, off))) = (date, time, off) {
                             let sec = t.second() + t.nanosecond() / 1_000_000_000;
                             write!(
                                 result,
@@ -721,7 +787,7 @@ fn format_inner<'a>(
                     RFC3339 =>
                     // same as `%Y-%m-%dT%H:%M:%S%.f%:z`
                     {
-                        if let (Some(d), Some(t), Some(&(_, off))) = (date, time, off) {
+                        if let (Some(d), Some(t), Some((_, off))) = (date, time, off) {
                             // reuse `Debug` impls which already print ISO 8601 format.
                             // this is faster in this way.
                             write!(result, "{:?}T{:?}", d, t)?;
@@ -754,13 +820,29 @@ pub fn format<'a, I, B>(
     off: Option<&(String, FixedOffset)>,
     items: I,
 ) -> fmt::Result
+where
+    I: Iterator<Item = B> + Clone,
+    B: Borrow<Item<'a>>,
+{
+    format_items(w, date, time, name_and_offset(off), items, None)
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+fn format_items<'a, I, B>(
+    w: &mut fmt::Formatter,
+    date: Option<&NaiveDate>,
+    time: Option<&NaiveTime>,
+    off: Option<(&str, FixedOffset)>,
+    items: I,
+    locale: Option<Locale>,
+) -> fmt::Result
 where
     I: Iterator<Item = B> + Clone,
     B: Borrow<Item<'a>>,
 {
     let mut result = String::new();
     for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), None)?;
+        format_inner(&mut result, date, time, off, item.borrow(), locale)?;
     }
     w.pad(&result)
 }
@@ -784,7 +866,7 @@ pub struct DelayedFormat<I> {
     /// The time view, if any.
     time: Option<NaiveTime>,
     /// The name and local-to-UTC difference for the offset (timezone), if any.
-    off: Option<(String, FixedOffset)>,
+    off: Option<(Cow<'static, str>, FixedOffset)>,
     /// An iterator returning formatting items.
     items: I,
     /// Locale used for text.
@@ -818,17 +900,41 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     where
         Off: Offset + fmt::Display,
     {
-        let name_and_diff = (offset.to_string(), offset.fix());
+        DelayedFormat::new_with_offset_name(
+            date,
+            time,
+            offset.to_string().into(),
+            offset.fix(),
+            items,
+        )
+    }
+
+    /// Makes a new `DelayedFormat` value out of local date and time, and the name and value
+    /// of the UTC offset, as given by [`TimeZone::offset_name`](crate::TimeZone::offset_name).
+    pub(crate) fn new_with_offset_name(
+        date: Option<NaiveDate>,
+        time: Option<NaiveTime>,
+        name: Cow<'static, str>,
+        offset: FixedOffset,
+        items: I,
+    ) -> DelayedFormat<I> {
         DelayedFormat {
             date,
             time,
-            off: Some(name_and_diff),
+            off: Some((name, offset)),
             items,
             #[cfg(feature = "unstable-locales")]
             locale: None,
         }
     }
 
+    /// Sets the locale used for text.
+    #[cfg(feature = "unstable-locales")]
+    pub(crate) fn with_locale(mut self, locale: Locale) -> DelayedFormat<I> {
+        self.locale = Some(locale);
+        self
+    }
+
     /// Makes a new `DelayedFormat` value out of local date and time and locale.
     #[cfg(feature = "unstable-locales")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable-locales")))]
@@ -854,8 +960,7 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
     where
         Off: Offset + fmt::Display,
     {
-        let name_and_diff = (offset.to_string(), offset.fix());
-        DelayedFormat { date, time, off: Some(name_and_diff), items, locale: Some(locale) }
+        DelayedFormat::new_with_offset(date, time, offset, items).with_locale(locale)
     }
 }
 
@@ -863,20 +968,12 @@ impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> DelayedFormat<I> {
 impl<'a, I: Iterator<Item = B> + Clone, B: Borrow<Item<'a>>> fmt::Display for DelayedFormat<I> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         #[cfg(feature = "unstable-locales")]
-        {
-            if let Some(locale) = self.locale {
-                return format_localized(
-                    f,
-                    self.date.as_ref(),
-                    self.time.as_ref(),
-                    self.off.as_ref(),
-                    self.items.clone(),
-                    locale,
-                );
-            }
-        }
+        let locale = self.locale;
+        #[cfg(not(feature = "unstable-locales"))]
+        let locale = None;
 
-        format(f, self.date.as_ref(), self.time.as_ref(), self.off.as_ref(), self.items.clone())
+        let off = self.off.as_ref().map(|&(ref name, off)| (&**name, off));
+        format_items(f, self.date.as_ref(), self.time.as_ref(), off, self.items.clone(), locale)
     }
 }
 
@@ -930,7 +1027,7 @@ pub fn format_item_localized<'a>(
     locale: Locale,
 ) -> fmt::Result {
     let mut result = String::new();
-    format_inner(&mut result, date, time, off, item, Some(locale))?;
+    format_inner(&mut result, date, time, name_and_offset(off), item, Some(locale))?;
     w.pad(&result)
 }
 
@@ -950,11 +1047,7 @@ where
     I: Iterator<Item = B> + Clone,
     B: Borrow<Item<'a>>,
 {
-    let mut result = String::new();
-    for item in items {
-        format_inner(&mut result, date, time, off, item.borrow(), Some(locale))?;
-    }
-    w.pad(&result)
+    format_items(w, date, time, name_and_offset(off), items, Some(locale))
 }
 
 /// Parsing a `str` into a `Month` uses the format [`%W`](./format/strftime/index.html).
@@ -10,11 +10,22 @@ use core::borrow::Borrow;
 use core::str;
 use core::usize;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
 use super::scan;
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use super::StrftimeItems;
 use super::{Fixed, InternalFixed, InternalInternal, Item, Numeric, Pad, Parsed};
 use super::{ParseError, ParseErrorKind, ParseResult};
 use super::{BAD_FORMAT, INVALID, NOT_ENOUGH, OUT_OF_RANGE, TOO_LONG, TOO_SHORT};
-use crate::{DateTime, FixedOffset, Weekday};
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::naive::NaiveDateTime;
+use crate::naive::{NaiveDate, NaiveTime};
+use crate::{DateTime, FixedOffset, TimeDelta, TimeZone, Weekday};
 
 fn set_weekday_with_num_days_from_sunday(p: &mut Parsed, v: i64) -> ParseResult<()> {
     p.set_weekday(match v {
@@ -224,6 +235,82 @@ fn parse_rfc3339<'a>(parsed: &mut Parsed, mut s: &'a str) -> ParseResult<(&'a st
     Ok((s, ()))
 }
 
+/// Parses the most common layout of an RFC 3339 date-and-time string directly,
+/// without going through `Parsed`.
+///
+/// Only `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)` is recognized here (with case-insensitive
+/// `T` and `Z`). Any other input, including one with out-of-range fields, returns `None` so that
+/// the caller can fall back to [`parse_rfc3339`] which reports the precise error.
+pub(crate) fn parse_rfc3339_fast(s: &str) -> Option<DateTime<FixedOffset>> {
+    fn number(bytes: &[u8]) -> Option<u32> {
+        bytes.iter().try_fold(0u32, |n, &c| match c {
+            b'0'..=b'9' => Some(n * 10 + u32::from(c - b'0')),
+            _ => None,
+        })
+    }
+
+    let b = s.as_bytes();
+    if b.len() < 20
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || (b[10] | 32) != b't'
+        || b[13] != b':'
+        || b[16] != b':'
+    {
+        return None;
+    }
+
+    let year = number(&b[0..4])? as i32;
+    let month = number(&b[5..7])?;
+    let day = number(&b[8..10])?;
+    let hour = number(&b[11..13])?;
+    let minute = number(&b[14..16])?;
+    let second = number(&b[17..19])?;
+
+    // fractional seconds: at least one digit, only the first 9 digits are significant
+    let mut rest = &b[19..];
+    let mut nano = 0;
+    if rest[0] == b'.' {
+        let ndigits = rest[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if ndigits == 0 {
+            return None;
+        }
+        let significant = core::cmp::min(ndigits, 9);
+        nano = number(&rest[1..1 + significant])? * 10u32.pow((9 - significant) as u32);
+        rest = &rest[1 + ndigits..];
+    }
+
+    let offset = match *rest {
+        [b'z'] | [b'Z'] => 0,
+        [sign, h1, h2, b':', m1, m2] if sign == b'+' || sign == b'-' => {
+            let hours = number(&[h1, h2])? as i32;
+            let minutes = number(&[m1, m2])? as i32;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let seconds = hours * 3600 + minutes * 60;
+            if sign == b'-' {
+                -seconds
+            } else {
+                seconds
+            }
+        }
+        _ => return None,
+    };
+
+    let (second, nano) = match second {
+        60 => (59, nano + 1_000_000_000),
+        _ => (second, nano),
+    };
+    let datetime = NaiveDate::from_ymd_opt(year, month, day)?
+        .and_time(NaiveTime::from_hms_nano_opt(hour, minute, second, nano)?);
+    let offset = FixedOffset::east_opt(offset)?;
+
+    // this is used to prevent an overflow when calling FixedOffset::from_local_datetime
+    datetime.checked_sub_signed(TimeDelta::seconds(i64::from(offset.local_minus_utc())))?;
+    offset.from_local_datetime(&datetime).single()
+}
+
 /// Tries to parse given string into `parsed` with given formatting items.
 /// Returns `Ok` when the entire string has been parsed (otherwise `parsed` should not be used).
 /// There should be no trailing string after parsing;
@@ -385,7 +472,11 @@ where
                         s = &s[2..];
                     }
 
-                    &Nanosecond | &Nanosecond3 | &Nanosecond6 | &Nanosecond9 => {
+                    &Nanosecond
+                    | &Nanosecond3
+                    | &Nanosecond6
+                    | &Nanosecond9
+                    | &Internal(InternalFixed { val: InternalInternal::NanosecondDigits(_) }) => {
                         if s.starts_with('.') {
                             let nano = try_consume!(scan::nanosecond(&s[1..]));
                             parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
@@ -416,14 +507,36 @@ where
                         parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
                     }
 
+                    &Internal(InternalFixed {
+                        val: InternalInternal::NanosecondDigitsNoDot(digits),
+                    }) => {
+                        let digits = usize::from(digits);
+                        if s.len() < digits {
+                            return Err((s, TOO_SHORT));
+                        }
+                        let nano = try_consume!(scan::nanosecond_fixed(s, digits));
+                        parsed.set_nanosecond(nano).map_err(|e| (s, e))?;
+                    }
+
                     &TimezoneName => {
-                        try_consume!(scan::timezone_name_skip(s));
+                        #[cfg_attr(
+                            not(any(feature = "alloc", feature = "std")),
+                            allow(unused_variables)
+                        )]
+                        let name = try_consume!(scan::timezone_name(s));
+                        #[cfg(any(feature = "alloc", feature = "std"))]
+                        parsed.record_timezone_name(name);
+                    }
+
+                    &TimezoneOffsetDoubleColon => {
+                        let offset = try_consume!(scan::timezone_offset_with_seconds(
+                            s.trim_left(),
+                            scan::colon_or_space
+                        ));
+                        parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
                     }
 
-                    &TimezoneOffsetColon
-                    | &TimezoneOffsetDoubleColon
-                    | &TimezoneOffsetTripleColon
-                    | &TimezoneOffset => {
+                    &TimezoneOffsetColon | &TimezoneOffsetTripleColon | &TimezoneOffset => {
                         let offset = try_consume!(scan::timezone_offset(
                             s.trim_left(),
                             scan::colon_or_space
@@ -447,6 +560,14 @@ where
                         ));
                         parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
                     }
+                    &Internal(InternalFixed { val: InternalInternal::TimezoneOffsetMilitary }) => {
+                        // `J` (local time) leaves the offset unknown
+                        if let Some(offset) =
+                            try_consume!(scan::timezone_offset_military(s.trim_left()))
+                        {
+                            parsed.set_offset(i64::from(offset)).map_err(|e| (s, e))?;
+                        }
+                    }
 
                     &RFC2822 => try_consume!(parse_rfc2822(parsed, s)),
                     &RFC3339 => try_consume!(parse_rfc3339(parsed, s)),
@@ -521,6 +642,53 @@ impl str::FromStr for DateTime<FixedOffset> {
     }
 }
 
+/// A parser for a fixed format string, which is compiled once and then reused for many inputs.
+///
+/// Parsing with [`NaiveDateTime::parse_from_str`] reparses the format string for every input.
+/// `TimestampParser` parses it only once into a list of [`Item`]s, which makes it a better fit
+/// for parsing large numbers of strings sharing one format, such as the timestamps in log files.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::TimestampParser;
+/// use chrono::NaiveDate;
+///
+/// let parser = TimestampParser::new("%Y-%m-%d %H:%M:%S%.f");
+/// assert_eq!(
+///     parser.parse("2015-09-05 23:56:04.5"),
+///     Ok(NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_milli_opt(23, 56, 4, 500).unwrap())
+/// );
+/// assert!(parser.parse("2015-09-05").is_err());
+/// ```
+#[cfg(any(feature = "alloc", feature = "std", test))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[derive(Clone, Debug)]
+pub struct TimestampParser<'a> {
+    items: Vec<Item<'a>>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<'a> TimestampParser<'a> {
+    /// Compiles the given format string, using the syntax of the [`strftime`](../strftime/index.html)
+    /// module.
+    ///
+    /// An invalid format string is not reported here, but makes every call to
+    /// [`parse`](#method.parse) fail with the same error as `NaiveDateTime::parse_from_str`.
+    pub fn new(fmt: &'a str) -> TimestampParser<'a> {
+        TimestampParser { items: StrftimeItems::new(fmt).collect() }
+    }
+
+    /// Parses a string into a `NaiveDateTime` with the compiled format.
+    ///
+    /// This gives the same result as `NaiveDateTime::parse_from_str(s, fmt)`.
+    pub fn parse(&self, s: &str) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, self.items.iter())?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn test_parse() {
@@ -543,6 +711,14 @@ fn test_parse() {
             $(expected.$k = Some($v);)*
             assert_eq!(parse_all($fmt, &$items), Ok(expected))
         });
+        // the time zone name is only recorded when an allocator is available
+        ($fmt:expr, $items:expr; $($k:ident: $v:expr),*; timezone_name: $name:expr) => (#[allow(unused_mut)] {
+            let mut expected = Parsed::new();
+            $(expected.$k = Some($v);)*
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            expected.record_timezone_name($name);
+            assert_eq!(parse_all($fmt, &$items), Ok(expected))
+        });
     }
 
     // empty string
@@ -735,6 +911,14 @@ fn test_parse() {
     check!("        4",    [internal_fix!(Nanosecond9NoDot)]; INVALID);
     check!(".42100000",    [internal_fix!(Nanosecond9NoDot)]; INVALID);
 
+    check!("4",            [internal_fix!(NanosecondDigitsNoDot(2))]; TOO_SHORT);
+    check!("42",           [internal_fix!(NanosecondDigitsNoDot(2))]; nanosecond: 420_000_000);
+    check!("4213",         [internal_fix!(NanosecondDigitsNoDot(2)), num!(Second)]; nanosecond: 420_000_000, second: 13);
+    check!("4219508",      [internal_fix!(NanosecondDigitsNoDot(7))]; nanosecond: 421_950_800);
+    check!(".42",          [internal_fix!(NanosecondDigitsNoDot(2))]; INVALID);
+    check!(".4219",        [internal_fix!(NanosecondDigits(2))]; nanosecond: 421_900_000);
+    check!("",             [internal_fix!(NanosecondDigits(2))]; );
+
     // fixed: timezone offsets
     check!("+00:00",    [fix!(TimezoneOffset)]; offset: 0);
     check!("-00:00",    [fix!(TimezoneOffset)]; offset: 0);
@@ -764,6 +948,12 @@ fn test_parse() {
     check!("+1234",     [fix!(TimezoneOffset)]; offset: 754 * 60);
     check!("+12345",    [fix!(TimezoneOffset)]; TOO_LONG);
     check!("+12345",    [fix!(TimezoneOffset), num!(Day)]; offset: 754 * 60, day: 5);
+    check!("+05:30:00", [fix!(TimezoneOffsetDoubleColon)]; offset: 330 * 60);
+    check!("-00:17:30", [fix!(TimezoneOffsetDoubleColon)]; offset: -(17 * 60 + 30));
+    check!("+05:30",    [fix!(TimezoneOffsetDoubleColon)]; offset: 330 * 60);
+    check!("+05:30:60", [fix!(TimezoneOffsetDoubleColon)]; OUT_OF_RANGE);
+    check!("+05:30:0",  [fix!(TimezoneOffsetDoubleColon)]; TOO_SHORT);
+    check!("+05:30:00", [fix!(TimezoneOffset)]; TOO_LONG);
     check!("Z",         [fix!(TimezoneOffset)]; INVALID);
     check!("z",         [fix!(TimezoneOffset)]; INVALID);
     check!("Z",         [fix!(TimezoneOffsetZ)]; offset: 0);
@@ -777,7 +967,24 @@ fn test_parse() {
     check!("z",         [internal_fix!(TimezoneOffsetPermissive)]; offset: 0);
     check!("+12:00",    [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
     check!("+12",       [internal_fix!(TimezoneOffsetPermissive)]; offset: 12 * 60 * 60);
-    check!("CEST 5",    [fix!(TimezoneName), lit!(" "), num!(Day)]; day: 5);
+    check!("Z",         [internal_fix!(TimezoneOffsetMilitary)]; offset: 0);
+    check!("A",         [internal_fix!(TimezoneOffsetMilitary)]; offset: 3600);
+    check!("i",         [internal_fix!(TimezoneOffsetMilitary)]; offset: 9 * 3600);
+    check!("K",         [internal_fix!(TimezoneOffsetMilitary)]; offset: 10 * 3600);
+    check!("M",         [internal_fix!(TimezoneOffsetMilitary)]; offset: 12 * 3600);
+    check!("N",         [internal_fix!(TimezoneOffsetMilitary)]; offset: -3600);
+    check!("Y",         [internal_fix!(TimezoneOffsetMilitary)]; offset: -12 * 3600);
+    check!("J",         [internal_fix!(TimezoneOffsetMilitary)]; );
+    check!(" Z",        [internal_fix!(TimezoneOffsetMilitary)]; offset: 0);
+    check!("+01:00",    [internal_fix!(TimezoneOffsetMilitary)]; offset: 3600);
+    check!("+0530",     [internal_fix!(TimezoneOffsetMilitary)]; offset: 19_800);
+    check!("-0930",     [internal_fix!(TimezoneOffsetMilitary)]; offset: -34_200);
+    check!("+",         [internal_fix!(TimezoneOffsetMilitary)]; TOO_SHORT);
+    check!("UTC",       [internal_fix!(TimezoneOffsetMilitary)]; TOO_LONG);
+    check!("",          [internal_fix!(TimezoneOffsetMilitary)]; TOO_SHORT);
+    check!("CEST 5",    [fix!(TimezoneName), lit!(" "), num!(Day)];
+           day: 5; timezone_name: "CEST");
+    check!(" 5",        [fix!(TimezoneName), lit!(" "), num!(Day)]; day: 5);
 
     // some practical examples
     check!("2015-02-04T14:37:05+09:00",
@@ -801,7 +1008,8 @@ fn test_parse() {
             num!(Day), sp!(" "), num!(Hour), lit!(":"), num!(Minute), lit!(":"),
             num!(Second), sp!(" "), fix!(TimezoneName), sp!(" "), num!(Year)];
             year: 2020, month: 8, day: 2, weekday: Weekday::Sun,
-            hour_div_12: 1, hour_mod_12: 1, minute: 39, second: 15);
+            hour_div_12: 1, hour_mod_12: 1, minute: 39, second: 15;
+            timezone_name: "CEST");
     check!("20060102150405",
            [num!(Year), num!(Month), num!(Day), num!(Hour), num!(Minute), num!(Second)];
            year: 2006, month: 1, day: 2, hour_div_12: 1, hour_mod_12: 3, minute: 4, second: 5);
@@ -984,3 +1192,178 @@ fn test_rfc3339() {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_rfc3339_fast_path() {
+    use super::*;
+    use crate::offset::FixedOffset;
+    use crate::{DateTime, SecondsFormat, TimeZone, Utc};
+
+    fn parse_general(s: &str) -> ParseResult<DateTime<FixedOffset>> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, [Item::Fixed(Fixed::RFC3339)].iter())?;
+        parsed.to_datetime()
+    }
+
+    // the result of `parse_from_rfc3339` should match the general parser exactly,
+    // including the offset (which the `DateTime` comparison ignores)
+    fn check(s: &str) {
+        let expected = parse_general(s);
+        let actual = DateTime::<FixedOffset>::parse_from_rfc3339(s);
+        assert_eq!(actual, expected, "mismatch for {:?}", s);
+        assert_eq!(actual.map(|dt| dt.offset().fix()), expected.map(|dt| dt.offset().fix()));
+        if let Some(dt) = parse_rfc3339_fast(s) {
+            assert_eq!(Ok(dt), expected, "fast path mismatch for {:?}", s);
+        }
+    }
+
+    let corpus = [
+        "2015-01-20T17:35:20-08:00",
+        "1944-06-06T04:04:00Z",
+        "1944-06-06t04:04:00z",
+        "2001-09-11T09:45:00-08:00",
+        "2015-01-20T17:35:20.001-08:00",
+        "2015-01-20T17:35:20.000031-08:00",
+        "2015-01-20T17:35:20.000000004-08:00",
+        "2015-01-20T17:35:20.000000000452-08:00",
+        "2015-02-18T23:59:60.234567+05:00",
+        "2015-02-18T23:16:09Z",
+        "2015-02-18T23:16:09UTC",
+        "2015-02-18T23:16:09+23:59",
+        "2015-02-18T23:16:09-23:59",
+        "2015-02-18T23:16:09+24:00",
+        "2015-02-18T23:16:09+05:60",
+        "2015-02-18T23:16:09+0500",
+        "2015-02-18T23:16:09+05",
+        "2015-02-18T23:16:09.Z",
+        "2015-02-18T23:16:09.+05:00",
+        "2015-02-18T23:16:09",
+        "2015-02-18 23:16:09Z",
+        "2015-02-18T23:16:09Z ",
+        "2015-02-30T17:35:20-08:00",
+        "2015-13-20T17:35:20-08:00",
+        "2015-01-20T25:35:20-08:00",
+        "2015-01-20T17:65:20-08:00",
+        "2015-01-20T17:35:90-08:00",
+        "2015-01-20T17:35:61-08:00",
+        "0000-01-01T00:00:00Z",
+        "0000-01-01T00:00:00+01:00",
+        "9999-12-31T23:59:59.999999999Z",
+        "9999-12-31T23:59:59.999999999-23:59",
+        "+2015-01-20T17:35:20Z",
+        "15-01-20T17:35:20Z",
+        "2015-1-20T17:35:20Z",
+        "2015-01-20T17:35:20−08:00",
+        "",
+    ];
+    for &s in corpus.iter() {
+        check(s);
+    }
+
+    // round-trip a range of values through all the usual output shapes
+    let offsets = [0, 1, -1, 3600, -3600, 19800, -(23 * 3600 + 59 * 60)];
+    for &offset in offsets.iter() {
+        let tz = FixedOffset::east_opt(offset).unwrap();
+        for &(y, m, d, h, mi, sec, nano) in [
+            (1970, 1, 1, 0, 0, 0, 0),
+            (2016, 12, 31, 23, 59, 59, 1_000_000_000),
+            (2020, 2, 29, 12, 34, 56, 789_000_000),
+            (1, 6, 15, 6, 7, 8, 123_456),
+            (9999, 12, 31, 23, 59, 59, 999_999_999),
+        ]
+        .iter()
+        {
+            let dt = Utc.ymd_opt(y, m, d).unwrap().and_hms_nano_opt(h, mi, sec, nano).unwrap();
+            let dt = dt.with_timezone(&tz);
+            for &secform in [
+                SecondsFormat::Secs,
+                SecondsFormat::Millis,
+                SecondsFormat::Micros,
+                SecondsFormat::Nanos,
+                SecondsFormat::AutoSi,
+            ]
+            .iter()
+            {
+                for &use_z in [false, true].iter() {
+                    check(&dt.to_rfc3339_opts(secform, use_z));
+                }
+            }
+        }
+    }
+
+    // the common shapes take the fast path, unusual ones fall back
+    assert!(parse_rfc3339_fast("2015-02-18T23:59:60.234567+05:00").is_some());
+    assert!(parse_rfc3339_fast("2015-02-18t23:16:09z").is_some());
+    assert!(parse_rfc3339_fast("2015-02-18T23:16:09UTC").is_none());
+    assert!(parse_rfc3339_fast("2015-02-30T17:35:20-08:00").is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn test_timestamp_parser() {
+    use crate::NaiveDateTime;
+
+    let formats = ["%Y-%m-%d %H:%M:%S%.f", "%d/%m/%Y %I:%M %p", "%s", "%Y-%m-%d %Q"];
+    let lines = [
+        "2023-04-12 08:15:02.123456",
+        "2023-04-12 08:15:02",
+        "2023-04-12 8:15:2.5",
+        "2016-12-31 23:59:60.999",
+        "2023-02-29 00:00:00",
+        "2023-04-12 24:00:00",
+        "12/04/2023 08:15 PM",
+        "12/04/2023 13:15 PM",
+        "1681287302",
+        "2023-04-12",
+        "2023-04-12 08:15:02 trailing",
+        "",
+    ];
+    for &fmt in formats.iter() {
+        let parser = TimestampParser::new(fmt);
+        for &line in lines.iter() {
+            assert_eq!(parser.parse(line), NaiveDateTime::parse_from_str(line, fmt));
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "alloc", feature = "std")))]
+#[test]
+fn test_parse_timezone_name() {
+    use crate::NaiveDateTime;
+
+    let parse_name = |s| {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new("%Y-%m-%d %H:%M:%S %Z")).map(|_| parsed)
+    };
+
+    let parsed = parse_name("2023-01-15 10:30:00 PST").unwrap();
+    assert_eq!(parsed.timezone_name(), Some("PST"));
+    assert_eq!(parsed.offset, None);
+    let dt = NaiveDateTime::parse_from_str("2023-01-15 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    assert_eq!(parsed.to_naive_datetime_with_offset(0), Ok(dt));
+
+    let parsed = parse_name("2023-01-15 10:30:00 GMT").unwrap();
+    assert_eq!(parsed.timezone_name(), Some("GMT"));
+
+    // like before names were recorded, differing names are not an error; the first one is kept
+    let mut parsed = Parsed::new();
+    assert_eq!(parse(&mut parsed, "PST 5 PDT", StrftimeItems::new("%Z %d %Z")), Ok(()));
+    assert_eq!(parsed.timezone_name(), Some("PST"));
+    assert_eq!(parsed.day, Some(5));
+
+    // while setting the name directly checks it for consistency
+    assert_eq!(parsed.set_timezone_name("PST"), Ok(()));
+    assert_eq!(parsed.set_timezone_name("PDT"), Err(super::IMPOSSIBLE));
+}
+
+#[cfg(all(test, not(any(feature = "alloc", feature = "std"))))]
+#[test]
+fn test_parse_timezone_name_not_recorded() {
+    // without an allocator `%Z` only skips the name, as it always did
+    let mut parsed = Parsed::new();
+    assert_eq!(parse(&mut parsed, "PST 5 PDT", StrftimeItems::new("%Z %d %Z")), Ok(()));
+    let mut expected = Parsed::new();
+    expected.day = Some(5);
+    assert_eq!(parsed, expected);
+}
@@ -4,6 +4,11 @@
 //! A collection of parsed date and time items.
 //! They can be constructed incrementally while being checked for consistency.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 
 use num_integer::div_rem;
@@ -103,6 +108,10 @@ pub struct Parsed {
     /// Offset from the local time to UTC, in seconds.
     pub offset: Option<i32>,
 
+    /// Time zone name or abbreviation as it appeared in the input, see `timezone_name()`.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    timezone_name: Option<String>,
+
     /// A dummy field to make this type not fully destructible (required for API stability).
     _dummy: (),
 }
@@ -282,6 +291,58 @@ impl Parsed {
         set_if_consistent(&mut self.offset, i32::try_from(value).map_err(|_| OUT_OF_RANGE)?)
     }
 
+    /// Tries to set the time zone name returned by [`timezone_name`](#method.timezone_name)
+    /// from given value.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[inline]
+    pub fn set_timezone_name(&mut self, value: &str) -> ParseResult<()> {
+        set_if_consistent(&mut self.timezone_name, value.to_string())
+    }
+
+    /// Keeps the first non-empty time zone name matched by `%Z`.
+    ///
+    /// Unlike `set_timezone_name` this never fails, as the names are not interpreted.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub(super) fn record_timezone_name(&mut self, name: &str) {
+        if self.timezone_name.is_none() && !name.is_empty() {
+            self.timezone_name = Some(name.to_string());
+        }
+    }
+
+    /// Returns the time zone name or abbreviation as it appeared in the input, e.g. `PST`.
+    ///
+    /// This is recorded for inspection only: an abbreviation does not uniquely determine
+    /// an offset, so it is never used by the `to_*` methods.
+    /// If `%Z` matched several names, the first one is returned.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn timezone_name(&self) -> Option<&str> {
+        self.timezone_name.as_ref().map(|name| name.as_str())
+    }
+
+    /// Resolves a two-digit year, given without its century, to the year in
+    /// `[pivot, pivot + 100)` with the same last two digits. The same is done for the ISO week
+    /// date year.
+    ///
+    /// Without this, such a year is resolved to one in `[1970, 2070)`.
+    pub(crate) fn resolve_two_digit_years(&mut self, pivot: i32) -> ParseResult<()> {
+        fn resolve(
+            year: &mut Option<i32>,
+            div_100: Option<i32>,
+            mod_100: Option<i32>,
+            pivot: i32,
+        ) -> ParseResult<()> {
+            if let (None, None, Some(r @ 0..=99)) = (*year, div_100, mod_100) {
+                let resolved = pivot.checked_add((r - pivot).rem_euclid(100));
+                *year = Some(resolved.ok_or(OUT_OF_RANGE)?);
+            }
+            Ok(())
+        }
+        resolve(&mut self.year, self.year_div_100, self.year_mod_100, pivot)?;
+        resolve(&mut self.isoyear, self.isoyear_div_100, self.isoyear_mod_100, pivot)
+    }
+
     /// Returns a parsed naive date out of given fields.
     ///
     /// This method is able to determine the date from given subset of fields:
@@ -384,8 +445,8 @@ impl Parsed {
         let verify_ordinal = |date: NaiveDate| {
             let ordinal = date.ordinal();
             let weekday = date.weekday();
-            let week_from_sun = (ordinal as i32 - weekday.num_days_from_sunday() as i32 + 7) / 7;
-            let week_from_mon = (ordinal as i32 - weekday.num_days_from_monday() as i32 + 7) / 7;
+            let week_from_sun = (ordinal as i32 - weekday.num_days_from_sunday() as i32 + 6) / 7;
+            let week_from_mon = (ordinal as i32 - weekday.num_days_from_monday() as i32 + 6) / 7;
             self.ordinal.unwrap_or(ordinal) == ordinal
                 && self.week_from_sun.map_or(week_from_sun, |v| v as i32) == week_from_sun
                 && self.week_from_mon.map_or(week_from_mon, |v| v as i32) == week_from_mon
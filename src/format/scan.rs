@@ -211,13 +211,23 @@ pub(super) fn timezone_offset<F>(s: &str, consume_colon: F) -> ParseResult<(&str
 where
     F: FnMut(&str) -> ParseResult<&str>,
 {
-    timezone_offset_internal(s, consume_colon, false)
+    timezone_offset_internal(s, consume_colon, false, false)
+}
+
+/// Same as `timezone_offset` but also allows for a trailing `:SS` seconds component,
+/// as used by historical offsets like `+00:17:30`.
+pub(super) fn timezone_offset_with_seconds<F>(s: &str, consume_colon: F) -> ParseResult<(&str, i32)>
+where
+    F: FnMut(&str) -> ParseResult<&str>,
+{
+    timezone_offset_internal(s, consume_colon, false, true)
 }
 
 fn timezone_offset_internal<F>(
     mut s: &str,
     mut consume_colon: F,
     allow_missing_minutes: bool,
+    allow_seconds: bool,
 ) -> ParseResult<(&str, i32)>
 where
     F: FnMut(&str) -> ParseResult<&str>,
@@ -267,7 +277,20 @@ where
         _ => return Err(TOO_SHORT),
     };
 
-    let seconds = hours * 3600 + minutes * 60;
+    // seconds (00--59), only when preceded by a colon
+    let secs = if allow_seconds && s.starts_with(':') {
+        let secs = match digits(&s[1..])? {
+            (s1 @ b'0'..=b'5', s2 @ b'0'..=b'9') => i32::from((s1 - b'0') * 10 + (s2 - b'0')),
+            (b'6'..=b'9', b'0'..=b'9') => return Err(OUT_OF_RANGE),
+            _ => return Err(INVALID),
+        };
+        s = &s[3..];
+        secs
+    } else {
+        0
+    };
+
+    let seconds = hours * 3600 + minutes * 60 + secs;
     Ok((s, if negative { -seconds } else { seconds }))
 }
 
@@ -302,10 +325,35 @@ where
 {
     match s.as_bytes().first() {
         Some(&b'z') | Some(&b'Z') => Ok((&s[1..], 0)),
-        _ => timezone_offset_internal(s, colon, true),
+        _ => timezone_offset_internal(s, colon, true, false),
     }
 }
 
+/// Parses a single-letter military time zone, case-insensitively.
+/// Returns `None` for `J`, which stands for the (unknown) local time.
+///
+/// Also accepts a numeric offset like `timezone_offset`, which is written for offsets
+/// that have no letter.
+pub(super) fn timezone_offset_military(s: &str) -> ParseResult<(&str, Option<i32>)> {
+    let letter = match s.as_bytes().first() {
+        Some(&b'+') | Some(&b'-') => {
+            let (s, offset) = timezone_offset(s, colon_or_space)?;
+            return Ok((s, Some(offset)));
+        }
+        Some(&c) => c | 32,
+        None => return Err(TOO_SHORT),
+    };
+    let hours = match letter {
+        b'z' => 0,
+        b'a'..=b'i' => i32::from(letter - b'a') + 1,
+        b'j' => return Ok((&s[1..], None)),
+        b'k'..=b'm' => i32::from(letter - b'k') + 10,
+        b'n'..=b'y' => -(i32::from(letter - b'n') + 1),
+        _ => return Err(INVALID),
+    };
+    Ok((&s[1..], Some(hours * 3600)))
+}
+
 /// Same as `timezone_offset` but also allows for RFC 2822 legacy timezones.
 /// May return `None` which indicates an insufficient offset data (i.e. `-0000`).
 pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, Option<i32>)> {
@@ -344,9 +392,10 @@ pub(super) fn timezone_offset_2822(s: &str) -> ParseResult<(&str, Option<i32>)>
 }
 
 /// Tries to consume everything until next whitespace-like symbol.
-/// Does not provide any offset information from the consumed data.
-pub(super) fn timezone_name_skip(s: &str) -> ParseResult<(&str, ())> {
-    Ok((s.trim_left_matches(|c: char| !c.is_whitespace()), ()))
+/// Returns the consumed name, but does not provide any offset information from it.
+pub(super) fn timezone_name(s: &str) -> ParseResult<(&str, &str)> {
+    let rest = s.trim_left_matches(|c: char| !c.is_whitespace());
+    Ok((rest, &s[..s.len() - rest.len()]))
 }
 
 /// Tries to consume an RFC2822 comment including preceding ` `.
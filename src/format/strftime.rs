@@ -28,7 +28,7 @@ The following specifiers are available both to formatting and parsing.
 | `%w`  | `0`      | Sunday = 0, Monday = 1, ..., Saturday = 6.                                 |
 | `%u`  | `7`      | Monday = 1, Tuesday = 2, ..., Sunday = 7. (ISO 8601)                       |
 |       |          |                                                                            |
-| `%U`  | `28`     | Week number starting with Sunday (00--53), zero-padded to 2 digits. [^2]   |
+| `%U`  | `27`     | Week number starting with Sunday (00--53), zero-padded to 2 digits. [^2]   |
 | `%W`  | `27`     | Same as `%U`, but week 1 starts with the first Monday in that year instead.|
 |       |          |                                                                            |
 | `%G`  | `2001`   | Same as `%Y` but uses the year number in ISO 8601 week date. [^3]          |
@@ -61,6 +61,8 @@ The following specifiers are available both to formatting and parsing.
 | `%3f` | `026`         | Similar to `%.3f` but without the leading dot. [^7]                   |
 | `%6f` | `026490`      | Similar to `%.6f` but without the leading dot. [^7]                   |
 | `%9f` | `026490000`   | Similar to `%.9f` but without the leading dot. [^7]                   |
+| `%.2f`| `.02`         | Like `%.3f`, but with any fixed length from 1 to 9. [^7]              |
+| `%2f` | `02`          | Similar to `%.2f` but without the leading dot. [^7]                   |
 |       |               |                                                                       |
 | `%R`  | `00:34`       | Hour-minute format. Same as `%H:%M`.                                  |
 | `%T`  | `00:34:60`    | Hour-minute-second format. Same as `%H:%M:%S`.                        |
@@ -74,6 +76,7 @@ The following specifiers are available both to formatting and parsing.
 |`%::z`|`+09:30:00`| Offset from the local time to UTC with seconds.                            |
 |`%:::z`| `+09`    | Offset from the local time to UTC without minutes.                         |
 | `%#z` | `+09`    | *Parsing only:* Same as `%z` but allows minutes to be missing or present.  |
+| `%#Z` | `I`      | Offset as a single-letter military time zone (`Z` is UTC). [^9]            |
 |       |          |                                                                            |
 |       |          | **DATE & TIME SPECIFIERS:**                                                |
 |`%c`|`Sun Jul  8 00:34:60 2001`|Locale's date and time (e.g., Thu Mar  3 23:05:25 2005).       |
@@ -95,6 +98,9 @@ Modifier | Description
 `%_?`    | Uses spaces as a padding. (e.g. `%j` = `012`, `%_j` = ` 12`)
 `%0?`    | Uses zeroes as a padding. (e.g. `%e` = ` 9`, `%0e` = `09`)
 
+The padding only affects formatting. When parsing, numeric specifiers accept values
+with or without leading zeroes or spaces, so `%I` reads both `9` and `09`.
+
 Notes:
 
 [^1]: `%C`, `%y`:
@@ -103,6 +109,8 @@ Notes:
 [^2]: `%U`:
    Week 1 starts with the first Sunday in that year.
    It is possible to have week 0 for days before the first Sunday.
+   When parsing, `%U` or `%W` combined with the year (`%Y`) and a day of the week
+   (e.g. `%w`, `%u` or `%a`) determines the date.
 
 [^3]: `%G`, `%g`, `%V`:
    Week 1 is the first week with at least 4 days in that year.
@@ -132,7 +140,7 @@ Notes:
    For the purpose of Chrono, it only accounts for non-leap seconds
    so it slightly differs from ISO C `strftime` behavior.
 
-[^7]: `%f`, `%.f`, `%.3f`, `%.6f`, `%.9f`, `%3f`, `%6f`, `%9f`:
+[^7]: `%f`, `%.f`, `%.3f`, `%.6f`, `%.9f`, `%3f`, `%6f`, `%9f`, `%.Nf`, `%Nf`:
    <br>
    The default `%f` is right-aligned and always zero-padded to 9 digits
    for the compatibility with glibc and others,
@@ -162,6 +170,11 @@ Notes:
    E.g. 70ms after the last second under `%3f` will print `070` (note: not `07`),
    and parsing `07`, `070000` etc. will yield the same.
    Note that they can read nothing if the fractional part is zero.
+   <br>
+   <br>
+   The variants `%.Nf` and `%Nf` accept any length `N` from 1 to 9, e.g. `%.2f` for centiseconds.
+   They print exactly `N` fractional digits, truncating the rest.
+   When parsing, `%.Nf` behaves like `%.3f` and `%Nf` reads exactly `N` digits.
 
 [^8]: `%Z`:
    Offset will not be populated from the parsed data, nor will it be validated.
@@ -172,6 +185,17 @@ Notes:
    It is not possible to reliably convert from an abbreviation to an offset,
    for example CDT can mean either Central Daylight Time (North America) or
    China Daylight Time.
+   Where an allocator is available, the first skipped name is still recorded in
+   [`Parsed::timezone_name`](../struct.Parsed.html#method.timezone_name)
+   for inspection.
+
+[^9]: `%#Z`:
+   `A` to `I` are +01:00 to +09:00, `K` to `M` are +10:00 to +12:00,
+   `N` to `Y` are -01:00 to -12:00, and `Z` is UTC.
+   Letters are parsed case-insensitively.
+   `J` stands for the local time: it is accepted when parsing but leaves the offset unknown.
+   An offset that has no letter, such as +05:30, is formatted like `%z` instead,
+   and parsing accepts that form too, so the output can always be parsed back.
 */
 
 #[cfg(feature = "unstable-locales")]
@@ -267,7 +291,7 @@ impl<'a> StrftimeItems<'a> {
     }
 }
 
-const HAVE_ALTERNATES: &str = "z";
+const HAVE_ALTERNATES: &str = "zZ";
 
 impl<'a> Iterator for StrftimeItems<'a> {
     type Item = Item<'a>;
@@ -373,7 +397,13 @@ impl<'a> Iterator for StrftimeItems<'a> {
                     'W' => num0!(WeekFromMon),
                     'X' => recons_from_slice!(self.t_fmt),
                     'Y' => num0!(Year),
-                    'Z' => fix!(TimezoneName),
+                    'Z' => {
+                        if is_alternate {
+                            internal_fix!(TimezoneOffsetMilitary)
+                        } else {
+                            fix!(TimezoneName)
+                        }
+                    }
                     'a' => fix!(ShortWeekdayName),
                     'b' | 'h' => fix!(ShortMonthName),
                     'c' => recons_from_slice!(self.d_t_fmt),
@@ -440,6 +470,10 @@ impl<'a> Iterator for StrftimeItems<'a> {
                             'f' => fix!(Nanosecond9),
                             _ => Item::Error,
                         },
+                        d @ '1'..='8' => match next!() {
+                            'f' => internal_fix!(NanosecondDigits(d as u8 - b'0')),
+                            _ => Item::Error,
+                        },
                         'f' => fix!(Nanosecond),
                         _ => Item::Error,
                     },
@@ -455,6 +489,10 @@ impl<'a> Iterator for StrftimeItems<'a> {
                         'f' => internal_fix!(Nanosecond9NoDot),
                         _ => Item::Error,
                     },
+                    d @ '1'..='8' => match next!() {
+                        'f' => internal_fix!(NanosecondDigitsNoDot(d as u8 - b'0')),
+                        _ => Item::Error,
+                    },
                     '%' => lit!("%"),
                     _ => Item::Error, // no such specifier
                 };
@@ -550,6 +588,13 @@ fn test_strftime_items() {
     assert_eq!(parse_and_collect("%_e"), [nums!(Day)]);
     assert_eq!(parse_and_collect("%z"), [fix!(TimezoneOffset)]);
     assert_eq!(parse_and_collect("%#z"), [internal_fix!(TimezoneOffsetPermissive)]);
+    assert_eq!(parse_and_collect("%#Z"), [internal_fix!(TimezoneOffsetMilitary)]);
+    assert_eq!(parse_and_collect("%.2f"), [internal_fix!(NanosecondDigits(2))]);
+    assert_eq!(parse_and_collect("%.3f"), [fix!(Nanosecond3)]);
+    assert_eq!(parse_and_collect("%7f"), [internal_fix!(NanosecondDigitsNoDot(7))]);
+    assert_eq!(parse_and_collect("%0f"), [num0!(Nanosecond)]);
+    assert_eq!(parse_and_collect("%.0f"), [Item::Error]);
+    assert_eq!(parse_and_collect("%.2d"), [Item::Error]);
     assert_eq!(parse_and_collect("%#m"), [Item::Error]);
 }
 
@@ -580,7 +625,7 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%A").to_string(), "Sunday");
     assert_eq!(dt.format("%w").to_string(), "0");
     assert_eq!(dt.format("%u").to_string(), "7");
-    assert_eq!(dt.format("%U").to_string(), "28");
+    assert_eq!(dt.format("%U").to_string(), "27");
     assert_eq!(dt.format("%W").to_string(), "27");
     assert_eq!(dt.format("%G").to_string(), "2001");
     assert_eq!(dt.format("%g").to_string(), "01");
@@ -611,6 +656,14 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%3f").to_string(), "026");
     assert_eq!(dt.format("%6f").to_string(), "026490");
     assert_eq!(dt.format("%9f").to_string(), "026490708");
+    assert_eq!(dt.format("%.2f").to_string(), ".02");
+    assert_eq!(dt.format("%.4f").to_string(), ".0264");
+    assert_eq!(dt.format("%.7f").to_string(), ".0264907");
+    assert_eq!(dt.format("%1f").to_string(), "0");
+    assert_eq!(dt.format("%2f").to_string(), "02");
+    assert_eq!(dt.format("%4f").to_string(), "0264");
+    assert_eq!(dt.format("%7f").to_string(), "0264907");
+    assert_eq!(dt.format("%8f").to_string(), "02649070");
     assert_eq!(dt.format("%R").to_string(), "00:34");
     assert_eq!(dt.format("%T").to_string(), "00:34:60");
     assert_eq!(dt.format("%X").to_string(), "00:34:60");
@@ -656,6 +709,71 @@ fn test_strftime_docs() {
     assert_eq!(dt.format("%%").to_string(), "%");
 }
 
+#[test]
+fn test_strftime_parse_colon_offsets() {
+    use crate::{DateTime, FixedOffset, TimeZone};
+
+    let cases: &[(&str, &[i32])] = &[
+        ("%Y-%m-%dT%H:%M:%S%:z", &[9 * 3600 + 30 * 60, -4 * 3600, 0]),
+        ("%Y-%m-%dT%H:%M:%S%::z", &[9 * 3600 + 30 * 60, -4 * 3600, -(17 * 60 + 30), 0]),
+    ];
+    for &(fmt, offsets) in cases {
+        for &secs in offsets {
+            let offset = FixedOffset::east_opt(secs).unwrap();
+            let dt = offset.ymd_opt(2001, 7, 8).unwrap().and_hms_opt(0, 34, 59).unwrap();
+            let s = dt.format(fmt).to_string();
+            let parsed = DateTime::<FixedOffset>::parse_from_str(&s, fmt).unwrap();
+            assert_eq!(parsed, dt);
+            assert_eq!(parsed.offset(), dt.offset());
+        }
+    }
+
+    let dt = DateTime::<FixedOffset>::parse_from_str("2001-07-08 00:34:59 +05:30:00", "%F %T %::z")
+        .unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap());
+    let dt = DateTime::<FixedOffset>::parse_from_str("2001-07-08 00:34:59 -00:17:30", "%F %T %::z")
+        .unwrap();
+    assert_eq!(dt.offset(), &FixedOffset::west_opt(17 * 60 + 30).unwrap());
+}
+
+#[test]
+fn test_strftime_military_time_zones() {
+    use crate::{DateTime, FixedOffset, NaiveDateTime, TimeZone};
+
+    let parse = |s: &str| DateTime::<FixedOffset>::parse_from_str(s, "%Y-%m-%d %H:%M:%S %#Z");
+    let hours = |h| FixedOffset::east_opt(h * 3600).unwrap();
+    assert_eq!(parse("2023-05-01 12:00:00 A").unwrap().offset(), &hours(1));
+    assert_eq!(parse("2023-05-01 12:00:00 M").unwrap().offset(), &hours(12));
+    assert_eq!(parse("2023-05-01 12:00:00 N").unwrap().offset(), &hours(-1));
+    assert_eq!(parse("2023-05-01 12:00:00 Y").unwrap().offset(), &hours(-12));
+    assert_eq!(parse("2023-05-01 12:00:00 Z").unwrap().offset(), &hours(0));
+    assert!(parse("2023-05-01 12:00:00 J").is_err());
+    assert!(parse("2023-05-01 12:00:00 UTC").is_err());
+
+    // `J` is the local time, so only a naive value can be parsed
+    let naive = NaiveDateTime::parse_from_str("2023-05-01 12:00:00 J", "%Y-%m-%d %H:%M:%S %#Z");
+    assert_eq!(naive, Ok(parse("2023-05-01 12:00:00 Z").unwrap().naive_utc()));
+
+    for h in -12..=12 {
+        let dt = hours(h).ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let s = dt.format("%Y-%m-%d %H:%M:%S %#Z").to_string();
+        assert_eq!(parse(&s), Ok(dt));
+        assert_eq!(parse(&s).unwrap().offset(), dt.offset());
+    }
+    let dt = hours(3).ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%#Z").to_string(), "C");
+
+    let half_hour = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    let dt = half_hour.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%#Z").to_string(), "+0530");
+    let s = dt.format("%Y-%m-%d %H:%M:%S %#Z").to_string();
+    assert_eq!(parse(&s), Ok(dt));
+    assert_eq!(parse(&s).unwrap().offset(), &half_hour);
+    assert_eq!(parse("2023-05-01 12:00:00 -03:30").unwrap().offset().local_minus_utc(), -12_600);
+    let dt = hours(14).ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(dt.format("%#Z").to_string(), "+1400");
+}
+
 #[cfg(feature = "unstable-locales")]
 #[test]
 fn test_strftime_docs_localized() {
@@ -0,0 +1,372 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A half-open range of time between two `DateTime`s.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+use core::{fmt, hash};
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::datetime::start_of_local_day;
+use crate::offset::TimeZone;
+use crate::{DateTime, TimeDelta};
+
+/// A half-open interval `[start, end)` between two [`DateTime`]s.
+///
+/// The start is included and the end is excluded, so adjacent intervals such as
+/// `[09:00, 10:00)` and `[10:00, 11:00)` neither overlap nor leave a gap.
+/// An interval with `start == end` is empty and contains no instant.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Interval, TimeDelta, TimeZone, Utc};
+///
+/// let start = Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+/// let end = Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(17, 0, 0).unwrap();
+/// let workday = Interval::new(start, end).unwrap();
+///
+/// assert!(workday.contains(&start));
+/// assert!(!workday.contains(&end));
+/// assert_eq!(workday.duration(), TimeDelta::hours(8));
+/// assert!(Interval::new(end, start).is_none());
+/// ```
+#[derive(Clone)]
+pub struct Interval<Tz: TimeZone> {
+    start: DateTime<Tz>,
+    end: DateTime<Tz>,
+}
+
+impl<Tz: TimeZone> Interval<Tz> {
+    /// Makes a new `Interval` from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Returns `None` if `start` is later than `end`.
+    pub fn new(start: DateTime<Tz>, end: DateTime<Tz>) -> Option<Interval<Tz>> {
+        if start > end {
+            return None;
+        }
+        Some(Interval { start, end })
+    }
+
+    /// Returns the start of the interval, which is included in it.
+    pub fn start(&self) -> &DateTime<Tz> {
+        &self.start
+    }
+
+    /// Returns the end of the interval, which is not included in it.
+    pub fn end(&self) -> &DateTime<Tz> {
+        &self.end
+    }
+
+    /// Returns true if `dt` lies within `[start, end)`.
+    pub fn contains(&self, dt: &DateTime<Tz>) -> bool {
+        self.start <= *dt && *dt < self.end
+    }
+
+    /// Returns true if the two intervals share at least one instant.
+    ///
+    /// Intervals that merely touch, where one ends at the start of the other, do not overlap.
+    pub fn overlaps(&self, other: &Interval<Tz>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the interval of instants contained in both intervals.
+    ///
+    /// Returns `None` if the intervals do not overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeZone, Utc};
+    ///
+    /// let at = |h| Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(h, 0, 0).unwrap();
+    /// let morning = Interval::new(at(8), at(12)).unwrap();
+    /// let meeting = Interval::new(at(11), at(13)).unwrap();
+    ///
+    /// assert_eq!(morning.intersection(&meeting), Interval::new(at(11), at(12)));
+    /// assert_eq!(morning.intersection(&Interval::new(at(12), at(13)).unwrap()), None);
+    /// ```
+    pub fn intersection(&self, other: &Interval<Tz>) -> Option<Interval<Tz>> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let start = if self.start >= other.start { &self.start } else { &other.start };
+        let end = if self.end <= other.end { &self.end } else { &other.end };
+        Some(Interval { start: start.clone(), end: end.clone() })
+    }
+
+    /// Returns the length of the interval, which is never negative.
+    pub fn duration(&self) -> TimeDelta {
+        self.end.clone().signed_duration_since(self.start.clone())
+    }
+
+    /// Splits the interval at every local midnight, returning one contiguous piece per local
+    /// calendar day it touches.
+    ///
+    /// Days are delimited by the first instant of each local date, so a day shortened or
+    /// lengthened by a DST transition yields a piece of 23 or 25 hours.
+    /// A local date that is skipped entirely by an offset change yields no piece.
+    /// An empty interval returns no pieces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Interval, TimeDelta, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(22, 0, 0).unwrap();
+    /// let interval = Interval::new(start, start + TimeDelta::hours(4)).unwrap();
+    /// let pieces = interval.split_by_day();
+    ///
+    /// assert_eq!(pieces.len(), 2);
+    /// assert_eq!(pieces[0].duration(), TimeDelta::hours(2));
+    /// assert_eq!(pieces[1].start(), &Utc.ymd_opt(2022, 6, 2).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn split_by_day(&self) -> Vec<Interval<Tz>> {
+        let tz = self.start.timezone();
+        let last_date = self.end.date_naive();
+        let mut pieces = Vec::new();
+        let mut start = self.start.clone();
+        while start < self.end {
+            // the next date that has a start, skipping dates that do not exist locally
+            let mut date = start.date_naive().succ_opt();
+            let mut next_day = None;
+            while let Some(d) = date.filter(|d| *d <= last_date) {
+                next_day = start_of_local_day(&tz, d);
+                if next_day.is_some() {
+                    break;
+                }
+                date = d.succ_opt();
+            }
+            let end = match next_day {
+                Some(next) if next > start && next < self.end => next,
+                _ => self.end.clone(),
+            };
+            pieces.push(Interval { start, end: end.clone() });
+            start = end;
+        }
+        pieces
+    }
+}
+
+impl<Tz: TimeZone> PartialEq for Interval<Tz> {
+    fn eq(&self, other: &Interval<Tz>) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Tz: TimeZone> Eq for Interval<Tz> {}
+
+impl<Tz: TimeZone> hash::Hash for Interval<Tz> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+impl<Tz: TimeZone> fmt::Debug for Interval<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{:?}, {:?})", self.start, self.end)
+    }
+}
+
+impl<Tz: TimeZone> fmt::Display for Interval<Tz>
+where
+    Tz::Offset: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+    use crate::naive::{NaiveDate, NaiveDateTime};
+    use crate::offset::tests::Cet;
+    use crate::offset::{FixedOffset, LocalResult, TimeZone, Utc};
+    use crate::{DateTime, TimeDelta};
+
+    /// A test zone that moves from UTC-10 to UTC+14 at 2011-12-30 10:00 UTC, skipping the
+    /// local date 2011-12-30 entirely, like Pacific/Apia.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    struct SkippedDay;
+
+    impl SkippedDay {
+        fn offset_at(utc: &NaiveDateTime) -> FixedOffset {
+            let change =
+                NaiveDate::from_ymd_opt(2011, 12, 30).unwrap().and_hms_opt(10, 0, 0).unwrap();
+            FixedOffset::east_opt(if *utc < change { -10 * 3600 } else { 14 * 3600 }).unwrap()
+        }
+    }
+
+    impl TimeZone for SkippedDay {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> SkippedDay {
+            SkippedDay
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            // the offset only grows, so there are no ambiguous local times
+            let offsets = [
+                FixedOffset::west_opt(10 * 3600).unwrap(),
+                FixedOffset::east_opt(14 * 3600).unwrap(),
+            ];
+            match offsets.iter().find(|&&off| SkippedDay::offset_at(&(*local - off)) == off) {
+                Some(&off) => LocalResult::Single(off),
+                None => LocalResult::None,
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            SkippedDay::offset_at(utc)
+        }
+    }
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_interval_new() {
+        assert!(Interval::new(at(9), at(10)).is_some());
+        assert!(Interval::new(at(9), at(9)).is_some());
+        assert!(Interval::new(at(10), at(9)).is_none());
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        let interval = Interval::new(at(9), at(10)).unwrap();
+        assert!(interval.contains(&at(9)));
+        assert!(interval.contains(&(at(10) - TimeDelta::nanoseconds(1))));
+        assert!(!interval.contains(&at(10)));
+        assert!(!interval.contains(&(at(9) - TimeDelta::nanoseconds(1))));
+
+        let empty = Interval::new(at(9), at(9)).unwrap();
+        assert!(!empty.contains(&at(9)));
+    }
+
+    #[test]
+    fn test_interval_overlaps() {
+        let a = Interval::new(at(9), at(12)).unwrap();
+        let b = Interval::new(at(11), at(13)).unwrap();
+        let c = Interval::new(at(12), at(13)).unwrap();
+        let d = Interval::new(at(14), at(15)).unwrap();
+        let inner = Interval::new(at(10), at(11)).unwrap();
+
+        assert!(a.overlaps(&b) && b.overlaps(&a));
+        assert!(a.overlaps(&inner) && inner.overlaps(&a));
+        assert!(!a.overlaps(&c) && !c.overlaps(&a));
+        assert!(!a.overlaps(&d) && !d.overlaps(&a));
+    }
+
+    #[test]
+    fn test_interval_intersection() {
+        let a = Interval::new(at(9), at(12)).unwrap();
+        let b = Interval::new(at(11), at(13)).unwrap();
+        let inner = Interval::new(at(10), at(11)).unwrap();
+
+        assert_eq!(a.intersection(&b), Interval::new(at(11), at(12)));
+        assert_eq!(b.intersection(&a), Interval::new(at(11), at(12)));
+        assert_eq!(a.intersection(&inner), Some(inner.clone()));
+        assert_eq!(a.intersection(&a), Some(a.clone()));
+        assert_eq!(a.intersection(&Interval::new(at(12), at(13)).unwrap()), None);
+        assert_eq!(a.intersection(&Interval::new(at(14), at(15)).unwrap()), None);
+    }
+
+    #[test]
+    fn test_interval_duration_and_display() {
+        let interval = Interval::new(at(9), at(17)).unwrap();
+        assert_eq!(interval.duration(), TimeDelta::hours(8));
+        assert_eq!(interval.start(), &at(9));
+        assert_eq!(interval.end(), &at(17));
+        assert_eq!(interval.to_string(), "[2022-06-01 09:00:00 UTC, 2022-06-01 17:00:00 UTC)");
+
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let interval =
+            Interval::new(at(9).with_timezone(&offset), at(10).with_timezone(&offset)).unwrap();
+        assert_eq!(
+            interval.to_string(),
+            "[2022-06-01 10:00:00 +01:00, 2022-06-01 11:00:00 +01:00)"
+        );
+    }
+
+    #[test]
+    fn test_interval_split_by_day() {
+        let start = Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        let interval = Interval::new(start, start + TimeDelta::hours(50)).unwrap();
+        let pieces = interval.split_by_day();
+        let midnight = |d| Utc.ymd_opt(2022, 6, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                Interval::new(start, midnight(2)).unwrap(),
+                Interval::new(midnight(2), midnight(3)).unwrap(),
+                Interval::new(midnight(3), start + TimeDelta::hours(50)).unwrap(),
+            ]
+        );
+
+        let within = Interval::new(at(9), at(17)).unwrap();
+        assert_eq!(within.split_by_day(), vec![within.clone()]);
+
+        let until_midnight = Interval::new(at(9), midnight(2)).unwrap();
+        assert_eq!(until_midnight.split_by_day(), vec![until_midnight.clone()]);
+
+        assert!(Interval::new(at(9), at(9)).unwrap().split_by_day().is_empty());
+    }
+
+    #[test]
+    fn test_interval_split_by_day_dst() {
+        let start = Cet.at(2022, 3, 26, 12, 0, 0).unwrap();
+        let end = Cet.at(2022, 3, 28, 0, 0, 0).unwrap();
+        let pieces = Interval::new(start, end).unwrap().split_by_day();
+        let durations: Vec<_> = pieces.iter().map(|p| p.duration()).collect();
+        assert_eq!(durations, vec![TimeDelta::hours(12), TimeDelta::hours(23)]);
+
+        let start = Cet.at(2022, 10, 30, 0, 0, 0).unwrap();
+        let end = Cet.at(2022, 10, 31, 0, 0, 0).unwrap();
+        let pieces = Interval::new(start, end).unwrap().split_by_day();
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].duration(), TimeDelta::hours(25));
+    }
+
+    #[test]
+    fn test_interval_split_by_day_skipped_day() {
+        assert_eq!(SkippedDay.at(2011, 12, 30, 12, 0, 0), LocalResult::None);
+        let start = SkippedDay.at(2011, 12, 28, 12, 0, 0).unwrap();
+        let end = SkippedDay.at(2012, 1, 1, 12, 0, 0).unwrap();
+        let pieces = Interval::new(start, end).unwrap().split_by_day();
+        let midnight = |y, m, d| SkippedDay.at(y, m, d, 0, 0, 0).unwrap();
+        assert_eq!(
+            pieces,
+            vec![
+                Interval::new(start, midnight(2011, 12, 29)).unwrap(),
+                Interval::new(midnight(2011, 12, 29), midnight(2011, 12, 31)).unwrap(),
+                Interval::new(midnight(2011, 12, 31), midnight(2012, 1, 1)).unwrap(),
+                Interval::new(midnight(2012, 1, 1), end).unwrap(),
+            ]
+        );
+        // 2011-12-29 is a whole day, it is just followed by 2011-12-31
+        assert_eq!(pieces[1].duration(), TimeDelta::hours(24));
+
+        // an interval ending an hour before the offset change, on the day before the skipped date
+        let end = SkippedDay.at(2011, 12, 31, 0, 0, 0).unwrap() - TimeDelta::hours(1);
+        let pieces = Interval::new(start, end).unwrap().split_by_day();
+        assert_eq!(pieces.len(), 2);
+        assert_eq!(pieces[1].end(), &end);
+    }
+}
@@ -33,11 +33,13 @@
 //! Optional features:
 //!
 //! - [`serde`][]: Enable serialization/deserialization via serde.
+//! - [`time`][]: Enable conversions from and to the types of the `time` crate.
 //! - `unstable-locales`: Enable localization. This adds various methods with a
 //!   `_localized` suffix. The implementation and API may change or even be
 //!   removed in a patch release. Feedback welcome.
 //!
 //! [`serde`]: https://github.com/serde-rs/serde
+//! [`time`]: https://github.com/time-rs/time
 //! [wasm-bindgen]: https://github.com/rustwasm/wasm-bindgen
 //!
 //! See the [cargo docs][] for examples of specifying features.
@@ -438,7 +440,12 @@ pub use date::{Date, MAX_DATE, MIN_DATE};
 
 mod datetime;
 #[allow(deprecated)]
-pub use datetime::{DateTime, SecondsFormat, MAX_DATETIME, MIN_DATETIME};
+pub use datetime::{
+    earliest, latest, DateTime, DateTimeBuilder, SecondsFormat, MAX_DATETIME, MIN_DATETIME,
+};
+
+mod interval;
+pub use interval::Interval;
 
 pub mod format;
 /// L10n locales.
@@ -449,7 +456,7 @@ pub use format::{ParseError, ParseResult};
 
 pub mod naive;
 #[doc(no_inline)]
-pub use naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveWeek};
+pub use naive::{Days, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveValue, NaiveWeek};
 
 pub mod offset;
 #[cfg(feature = "clock")]
@@ -457,7 +464,7 @@ pub mod offset;
 #[doc(no_inline)]
 pub use offset::Local;
 #[doc(no_inline)]
-pub use offset::{FixedOffset, LocalResult, Offset, TimeZone, Utc};
+pub use offset::{FixedOffset, LocalResult, NamedOffset, Offset, TimeZone, Utc};
 
 mod round;
 pub use round::{DurationRound, RoundingError, SubsecRound};
@@ -468,8 +475,14 @@ pub use weekday::{ParseWeekdayError, Weekday};
 mod month;
 pub use month::{Month, Months, ParseMonthError};
 
+mod relative_delta;
+pub use relative_delta::RelativeDelta;
+
 mod traits;
-pub use traits::{Datelike, Timelike};
+pub use traits::{Datelike, Era, Timelike};
+
+#[cfg(feature = "time")]
+mod time_crate;
 
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
@@ -488,6 +501,8 @@ pub use naive::__BenchYearFlags;
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde {
     pub use super::datetime::serde::*;
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub use crate::serde_strftime as strftime;
 }
 
 /// Out of range error type used in various converting APIs
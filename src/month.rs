@@ -1,3 +1,4 @@
+use core::ops::{Add, Mul, Sub};
 use core::{convert::TryFrom, fmt};
 
 #[cfg(feature = "rkyv")]
@@ -186,6 +187,38 @@ impl Months {
     }
 }
 
+/// Formats the number of months, e.g. `1 month` or `3 months`.
+impl fmt::Display for Months {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.0, if self.0 == 1 { "month" } else { "months" })
+    }
+}
+
+impl Add<Months> for Months {
+    type Output = Months;
+
+    fn add(self, rhs: Months) -> Months {
+        Months(self.0.checked_add(rhs.0).expect("`Months + Months` overflowed"))
+    }
+}
+
+/// Panics if `rhs` is larger than `self`, as `Months` cannot be negative.
+impl Sub<Months> for Months {
+    type Output = Months;
+
+    fn sub(self, rhs: Months) -> Months {
+        Months(self.0.checked_sub(rhs.0).expect("`Months - Months` underflowed"))
+    }
+}
+
+impl Mul<u32> for Months {
+    type Output = Months;
+
+    fn mul(self, rhs: u32) -> Months {
+        Months(self.0.checked_mul(rhs).expect("`Months * u32` overflowed"))
+    }
+}
+
 /// An error resulting from reading `<Month>` value with `FromStr`.
 #[derive(Clone, PartialEq, Eq)]
 pub struct ParseMonthError {
@@ -307,9 +340,26 @@ mod month_serde {
 mod tests {
     use core::convert::TryFrom;
 
-    use super::Month;
+    use super::{Month, Months};
     use crate::{Datelike, OutOfRange, TimeZone, Utc};
 
+    #[test]
+    fn test_months_arithmetic() {
+        assert_eq!(Months::new(2) + Months::new(3), Months::new(5));
+        assert_eq!(Months::new(5) - Months::new(3), Months::new(2));
+        assert_eq!(Months::new(4) * 3, Months::new(12));
+
+        assert_eq!(Months::new(0).to_string(), "0 months");
+        assert_eq!(Months::new(1).to_string(), "1 month");
+        assert_eq!(Months::new(12).to_string(), "12 months");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_months_sub_underflow() {
+        let _ = Months::new(1) - Months::new(2);
+    }
+
     #[test]
     fn test_month_enum_try_from() {
         assert_eq!(Month::try_from(1), Ok(Month::January));
@@ -3,10 +3,15 @@
 
 //! ISO 8601 calendar date without timezone.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::convert::TryFrom;
-use core::ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Mul, RangeInclusive, Sub, SubAssign};
 use core::{fmt, str};
 
 use num_integer::div_mod_floor;
@@ -27,6 +32,9 @@ use super::isoweek;
 const MAX_YEAR: i32 = internals::MAX_YEAR;
 const MIN_YEAR: i32 = internals::MIN_YEAR;
 
+// Julian Day Number of 0000-12-31, which is day 0 in `num_days_from_ce`.
+const JULIAN_DAY_OF_CE: i64 = 1_721_425;
+
 //   MAX_YEAR-12-31 minus 0000-01-01
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + (0001-01-01 minus 0000-01-01) - 1 day
 // = ((MAX_YEAR+1)-01-01 minus 0001-01-01) + 365 days
@@ -130,6 +138,85 @@ impl Days {
     }
 }
 
+/// Formats the number of days, e.g. `1 day` or `3 days`.
+impl fmt::Display for Days {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.0, if self.0 == 1 { "day" } else { "days" })
+    }
+}
+
+impl Add<Days> for Days {
+    type Output = Days;
+
+    fn add(self, rhs: Days) -> Days {
+        Days(self.0.checked_add(rhs.0).expect("`Days + Days` overflowed"))
+    }
+}
+
+/// Panics if `rhs` is larger than `self`, as `Days` cannot be negative.
+impl Sub<Days> for Days {
+    type Output = Days;
+
+    fn sub(self, rhs: Days) -> Days {
+        Days(self.0.checked_sub(rhs.0).expect("`Days - Days` underflowed"))
+    }
+}
+
+impl Mul<u64> for Days {
+    type Output = Days;
+
+    fn mul(self, rhs: u64) -> Days {
+        Days(self.0.checked_mul(rhs).expect("`Days * u64` overflowed"))
+    }
+}
+
+/// The month and day to use for fields missing from the input of
+/// [`NaiveDate::parse_partial`].
+///
+/// Both default to 1, so that a missing month or day gives the first one of the year or month.
+///
+/// # Example
+///
+/// ```
+/// use chrono::naive::PartialDefaults;
+///
+/// let defaults = PartialDefaults::default().month(6).day(15);
+/// assert_eq!(defaults, PartialDefaults::new(6, 15));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PartialDefaults {
+    month: u32,
+    day: u32,
+}
+
+impl PartialDefaults {
+    /// Makes new defaults for the month (starting from 1) and the day of the month.
+    #[inline]
+    pub fn new(month: u32, day: u32) -> PartialDefaults {
+        PartialDefaults { month, day }
+    }
+
+    /// Sets the default month number (starting from 1).
+    #[inline]
+    pub fn month(mut self, month: u32) -> PartialDefaults {
+        self.month = month;
+        self
+    }
+
+    /// Sets the default day of the month (starting from 1).
+    #[inline]
+    pub fn day(mut self, day: u32) -> PartialDefaults {
+        self.day = day;
+        self
+    }
+}
+
+impl Default for PartialDefaults {
+    fn default() -> PartialDefaults {
+        PartialDefaults { month: 1, day: 1 }
+    }
+}
+
 /// ISO 8601 calendar date without timezone.
 /// Allows for every [proleptic Gregorian date](#calendar-date)
 /// from Jan 1, 262145 BCE to Dec 31, 262143 CE.
@@ -416,9 +503,10 @@ impl NaiveDate {
     /// assert_eq!(from_ndays_opt(-1),           Some(from_ymd(0, 12, 30)));
     /// assert_eq!(from_ndays_opt(100_000_000),  None);
     /// assert_eq!(from_ndays_opt(-100_000_000), None);
+    /// assert_eq!(from_ndays_opt(std::i32::MAX), None);
     /// ```
     pub fn from_num_days_from_ce_opt(days: i32) -> Option<NaiveDate> {
-        let days = days + 365; // make December 31, 1 BCE equal to day 0
+        let days = days.checked_add(365)?; // make December 31, 1 BCE equal to day 0
         let (year_div_400, cycle) = div_mod_floor(days, 146_097);
         let (year_mod_400, ordinal) = internals::cycle_to_yo(cycle as u32);
         let flags = YearFlags::from_year_mod_400(year_mod_400 as i32);
@@ -467,6 +555,29 @@ impl NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day)
     }
 
+    /// Makes a new `NaiveDate` for the `n`th `weekday` of the given year, such as the first
+    /// Monday of 2024.  `n` is 1-indexed.
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    /// assert_eq!(NaiveDate::nth_weekday_of_year(2024, Weekday::Mon, 1),
+    ///            NaiveDate::from_ymd_opt(2024, 1, 1));
+    /// assert_eq!(NaiveDate::nth_weekday_of_year(2024, Weekday::Fri, 2),
+    ///            NaiveDate::from_ymd_opt(2024, 1, 12));
+    /// ```
+    ///
+    /// Returns `None` if `n` out-of-range; ie. if `n` is larger than the number of `weekday` in
+    /// `year` (52 or 53), or if `n == 0`.
+    pub fn nth_weekday_of_year(year: i32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+        if n == 0 {
+            return None;
+        }
+        let first = NaiveDate::from_yo_opt(year, 1)?.weekday();
+        let first_to_dow = (7 + weekday.number_from_monday() - first.number_from_monday()) % 7;
+        let ordinal = (u32::from(n) - 1) * 7 + first_to_dow + 1;
+        NaiveDate::from_yo_opt(year, ordinal)
+    }
+
     /// Parses a string with the specified format string and returns a new `NaiveDate`.
     /// See the [`format::strftime` module](../format/strftime/index.html)
     /// on the supported escape sequences.
@@ -515,6 +626,78 @@ impl NaiveDate {
         parsed.to_naive_date()
     }
 
+    /// Parses a string with the specified format string like
+    /// [`parse_from_str`](#method.parse_from_str), but resolves a two-digit year (`%y`, `%g`)
+    /// to the year in `[pivot, pivot + 100)` ending in those digits.
+    ///
+    /// `parse_from_str` acts like a pivot of 1970: `00` to `69` are years of the 2000s and
+    /// `70` to `99` of the 1900s. A year given in full (`%Y`) is not affected by the pivot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let parse = |s| NaiveDate::parse_from_str_with_year_pivot(s, "%d/%m/%y", 1950);
+    /// assert_eq!(parse("01/02/49"), Ok(NaiveDate::from_ymd_opt(2049, 2, 1).unwrap()));
+    /// assert_eq!(parse("01/02/50"), Ok(NaiveDate::from_ymd_opt(1950, 2, 1).unwrap()));
+    /// assert_eq!(
+    ///     NaiveDate::parse_from_str("01/02/50", "%d/%m/%y"),
+    ///     Ok(NaiveDate::from_ymd_opt(2050, 2, 1).unwrap())
+    /// );
+    /// ```
+    pub fn parse_from_str_with_year_pivot(
+        s: &str,
+        fmt: &str,
+        pivot: i32,
+    ) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.resolve_two_digit_years(pivot)?;
+        parsed.to_naive_date()
+    }
+
+    /// Parses a string with the specified format string like
+    /// [`parse_from_str`](#method.parse_from_str), taking the month and the day from `defaults`
+    /// when the input does not give them.
+    ///
+    /// The year is always required. The month and the day are only defaulted for a calendar
+    /// date: if the input gives a day of the year (`%j`) or a week number (`%U`, `%W`, `%V`),
+    /// the date is determined from those fields as usual.
+    ///
+    /// A default that does not make a valid date, such as day 31 for a month of 30 days,
+    /// is an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::naive::{NaiveDate, PartialDefaults};
+    ///
+    /// let defaults = PartialDefaults::default();
+    /// assert_eq!(
+    ///     NaiveDate::parse_partial("2024-03", "%Y-%m", defaults),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+    /// );
+    /// assert_eq!(
+    ///     NaiveDate::parse_partial("2024", "%Y", defaults.month(7)),
+    ///     Ok(NaiveDate::from_ymd_opt(2024, 7, 1).unwrap())
+    /// );
+    /// assert!(NaiveDate::parse_partial("03-15", "%m-%d", defaults).is_err());
+    /// ```
+    pub fn parse_partial(s: &str, fmt: &str, defaults: PartialDefaults) -> ParseResult<NaiveDate> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        if parsed.ordinal.is_none()
+            && parsed.week_from_sun.is_none()
+            && parsed.week_from_mon.is_none()
+            && parsed.isoweek.is_none()
+        {
+            parsed.month = parsed.month.or(Some(defaults.month));
+            parsed.day = parsed.day.or(Some(defaults.day));
+        }
+        parsed.to_naive_date()
+    }
+
     /// Add a duration in [`Months`] to the date
     ///
     /// If the day would be out of range for the resulting month, use the last day for that month.
@@ -659,7 +842,31 @@ impl NaiveDate {
     }
 
     fn diff_days(self, days: i64) -> Option<Self> {
-        self.checked_add_signed(TimeDelta::days(days))
+        // the range of `NaiveDate` spans far less than `i32::MAX` days
+        let days = i32::try_from(days).ok()?;
+        self.checked_add_signed(TimeDelta::days(i64::from(days)))
+    }
+
+    /// Makes a new `NaiveDate` with the year number changed, clamping a leap day
+    /// (February 29) to February 28 when the target year is not a leap year.
+    ///
+    /// Unlike [`with_year`](#method.with_year) this never fails for a valid year.
+    ///
+    /// Panics when the resulting year is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    /// assert_eq!(d.with_year_clamped(2023), NaiveDate::from_ymd_opt(2023, 2, 28).unwrap());
+    /// assert_eq!(d.with_year_clamped(2028), NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+    /// ```
+    pub fn with_year_clamped(&self, year: i32) -> NaiveDate {
+        self.with_year(year)
+            .or_else(|| self.with_day(28)?.with_year(year))
+            .expect("out-of-range year")
     }
 
     /// Makes a new `NaiveDateTime` from the current date and given `NaiveTime`.
@@ -946,6 +1153,98 @@ impl NaiveDate {
         self.with_of(self.of().pred()).or_else(|| NaiveDate::from_ymd_opt(self.year() - 1, 12, 31))
     }
 
+    /// Makes a new `NaiveDate` for the first date strictly after the current date
+    /// that falls on the given day of the week.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let wed = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    /// assert_eq!(wed.next_weekday(Weekday::Mon), NaiveDate::from_ymd_opt(2022, 6, 20));
+    /// assert_eq!(wed.next_weekday(Weekday::Wed), NaiveDate::from_ymd_opt(2022, 6, 22));
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        self.succ_opt()?.next_or_same_weekday(weekday)
+    }
+
+    /// Makes a new `NaiveDate` for the first date on or after the current date
+    /// that falls on the given day of the week.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let wed = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    /// assert_eq!(wed.next_or_same_weekday(Weekday::Mon), NaiveDate::from_ymd_opt(2022, 6, 20));
+    /// assert_eq!(wed.next_or_same_weekday(Weekday::Wed), Some(wed));
+    /// ```
+    pub fn next_or_same_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        let days = (7 + weekday.num_days_from_monday() - self.weekday().num_days_from_monday()) % 7;
+        self.checked_add_days(Days::new(u64::from(days)))
+    }
+
+    /// Makes a new `NaiveDate` for the last date strictly before the current date
+    /// that falls on the given day of the week.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let wed = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    /// assert_eq!(wed.prev_weekday(Weekday::Fri), NaiveDate::from_ymd_opt(2022, 6, 10));
+    /// assert_eq!(wed.prev_weekday(Weekday::Wed), NaiveDate::from_ymd_opt(2022, 6, 8));
+    /// ```
+    pub fn prev_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        self.pred_opt()?.prev_or_same_weekday(weekday)
+    }
+
+    /// Makes a new `NaiveDate` for the last date on or before the current date
+    /// that falls on the given day of the week.
+    ///
+    /// Returns `None` when the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let wed = NaiveDate::from_ymd_opt(2022, 6, 15).unwrap();
+    /// assert_eq!(wed.prev_or_same_weekday(Weekday::Fri), NaiveDate::from_ymd_opt(2022, 6, 10));
+    /// assert_eq!(wed.prev_or_same_weekday(Weekday::Wed), Some(wed));
+    /// ```
+    pub fn prev_or_same_weekday(&self, weekday: Weekday) -> Option<NaiveDate> {
+        let days = (7 + self.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        self.checked_sub_days(Days::new(u64::from(days)))
+    }
+
+    /// Returns which occurrence of its day of the week this date is within its month,
+    /// starting from 1: for instance 2 for the second Tuesday of a month.
+    ///
+    /// This is the inverse of [`NaiveDate::from_weekday_of_month_opt`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, NaiveDate};
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+    /// assert_eq!(d.weekday_ordinal(), 2);
+    /// assert_eq!(NaiveDate::from_weekday_of_month_opt(2024, 3, d.weekday(), 2), Some(d));
+    /// ```
+    pub fn weekday_ordinal(&self) -> u8 {
+        (self.day0() / 7 + 1) as u8
+    }
+
     /// Adds the `days` part of given `Duration` to the current date.
     ///
     /// Returns `None` when it will result in overflow.
@@ -977,6 +1276,28 @@ impl NaiveDate {
         NaiveDate::from_of(year_div_400 * 400 + year_mod_400 as i32, Of::new(ordinal, flags))
     }
 
+    /// Adds the `days` part of given `TimeDelta` to the current date,
+    /// saturating at [`NaiveDate::MIN`] and [`NaiveDate::MAX`] instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, NaiveDate};
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap();
+    /// assert_eq!(d.saturating_add_signed(TimeDelta::days(40)),
+    ///            NaiveDate::from_ymd_opt(2015, 10, 15).unwrap());
+    /// assert_eq!(d.saturating_add_signed(TimeDelta::days(1_000_000_000)), NaiveDate::MAX);
+    /// assert_eq!(d.saturating_add_signed(TimeDelta::days(-1_000_000_000)), NaiveDate::MIN);
+    /// ```
+    pub fn saturating_add_signed(self, rhs: TimeDelta) -> NaiveDate {
+        match self.checked_add_signed(rhs) {
+            Some(date) => date,
+            None if rhs < TimeDelta::zero() => NaiveDate::MIN,
+            None => NaiveDate::MAX,
+        }
+    }
+
     /// Subtracts the `days` part of given `TimeDelta` from the current date.
     ///
     /// Returns `None` when it will result in overflow.
@@ -1042,6 +1363,48 @@ impl NaiveDate {
         )
     }
 
+    /// Returns the number of days from `other` to the current date,
+    /// which is negative if `other` is later.
+    ///
+    /// This is the same as `self.signed_duration_since(other).num_days()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(from_ymd(2014, 1, 1).days_since(from_ymd(2013, 9, 23)), 100);
+    /// assert_eq!(from_ymd(2013, 9, 23).days_since(from_ymd(2014, 1, 1)), -100);
+    /// assert_eq!(from_ymd(2014, 1, 1).days_since(from_ymd(2014, 1, 1)), 0);
+    /// ```
+    #[inline]
+    pub fn days_since(&self, other: NaiveDate) -> i64 {
+        self.signed_duration_since(other).num_days()
+    }
+
+    /// Returns the number of days from `other` to the current date as [`Days`].
+    ///
+    /// Returns `None` if `other` is later than the current date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(from_ymd(2014, 1, 1).checked_days_since(from_ymd(2013, 9, 23)), Some(Days::new(100)));
+    /// assert_eq!(from_ymd(2013, 9, 23).checked_days_since(from_ymd(2014, 1, 1)), None);
+    /// ```
+    pub fn checked_days_since(&self, other: NaiveDate) -> Option<Days> {
+        match self.days_since(other) {
+            days if days >= 0 => Some(Days::new(days as u64)),
+            _ => None,
+        }
+    }
+
     /// Formats the date with the specified formatting items.
     /// Otherwise it is the same as the ordinary `format` method.
     ///
@@ -1119,6 +1482,34 @@ impl NaiveDate {
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Returns the day of the month with its English ordinal suffix, such as `1st` or `22nd`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2015, 9, 2).unwrap();
+    /// assert_eq!(d.day_with_ordinal_suffix(), "2nd");
+    /// assert_eq!(format!("{} {}", d.format("%B"), d.day_with_ordinal_suffix()), "September 2nd");
+    /// assert_eq!(NaiveDate::from_ymd_opt(2015, 9, 12).unwrap().day_with_ordinal_suffix(), "12th");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn day_with_ordinal_suffix(&self) -> String {
+        let day = self.day();
+        let suffix = match (day % 10, day / 10) {
+            (_, 1) => "th", // 11th to 19th
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        let mut s = day.to_string();
+        s.push_str(suffix);
+        s
+    }
+
     /// Returns an iterator that steps by days across all representable dates.
     ///
     /// # Example
@@ -1181,6 +1572,28 @@ impl NaiveDate {
         NaiveDateWeeksIterator { value: *self }
     }
 
+    /// Returns an iterator over the first days of the months within `[start, end)`.
+    ///
+    /// If `start` is not the first day of a month, the iteration begins at the first day of the
+    /// following month; the month containing `start` is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// let starts: Vec<_> = NaiveDate::iter_month_starts(ymd(2023, 11, 15), ymd(2024, 2, 1)).collect();
+    /// assert_eq!(starts, [ymd(2023, 12, 1), ymd(2024, 1, 1)]);
+    /// ```
+    pub fn iter_month_starts(start: NaiveDate, end: NaiveDate) -> NaiveDateMonthStartsIterator {
+        let next = match start.day() {
+            1 => Some(start),
+            _ => start.with_day(1).and_then(|d| d.checked_add_months(Months::new(1))),
+        };
+        NaiveDateMonthStartsIterator { next, end }
+    }
+
     /// Returns the [`NaiveWeek`] that the date belongs to, starting with the [`Weekday`]
     /// specified.
     #[inline]
@@ -1188,6 +1601,91 @@ impl NaiveDate {
         NaiveWeek { date: *self, start }
     }
 
+    /// Returns the week of the month that the date falls in, starting from 1, with weeks
+    /// starting on the [`Weekday`] specified.
+    ///
+    /// This is the row of the date in a calendar grid of the month: the week containing the
+    /// first day of the month is week 1, even if it is not a full week.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// // October 1, 2023 is a Sunday
+    /// let oct = |d| NaiveDate::from_ymd_opt(2023, 10, d).unwrap();
+    /// assert_eq!(oct(1).week_of_month(Weekday::Mon), 1);
+    /// assert_eq!(oct(2).week_of_month(Weekday::Mon), 2);
+    /// assert_eq!(oct(2).week_of_month(Weekday::Sun), 1);
+    /// assert_eq!(oct(31).week_of_month(Weekday::Mon), 6);
+    /// ```
+    pub fn week_of_month(&self, start: Weekday) -> u32 {
+        let first = (self.weekday().num_days_from_monday() + 7 - self.day0() % 7) % 7;
+        let offset = (first + 7 - start.num_days_from_monday()) % 7;
+        (self.day0() + offset) / 7 + 1
+    }
+
+    /// Encodes the date as 4 bytes: the number of days since January 1, 1 CE (day 1),
+    /// as a little-endian `i32`.
+    ///
+    /// This is a stable binary format for compact storage; see [`from_bytes`](#method.from_bytes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// let d = NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+    /// assert_eq!(d.to_bytes(), 738_641i32.to_le_bytes());
+    /// assert_eq!(NaiveDate::from_bytes(d.to_bytes()), Some(d));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.num_days_from_ce().to_le_bytes()
+    }
+
+    /// Decodes a date encoded with [`to_bytes`](#method.to_bytes).
+    ///
+    /// Returns `None` if the day number is out of range.
+    pub fn from_bytes(bytes: [u8; 4]) -> Option<NaiveDate> {
+        NaiveDate::from_num_days_from_ce_opt(i32::from_le_bytes(bytes))
+    }
+
+    /// Returns the Julian Day Number of the date.
+    ///
+    /// This is the number of days since January 1, 4713 BCE in the proleptic Julian calendar
+    /// (November 24, 4714 BCE in the proleptic Gregorian calendar), and is the Julian Date at
+    /// noon of this date. For example, January 1, 1970 is day 2,440,588.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().to_julian_day_number(), 2_440_588);
+    /// assert_eq!(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().to_julian_day_number(), 2_451_545);
+    /// ```
+    pub fn to_julian_day_number(&self) -> i64 {
+        i64::from(self.num_days_from_ce()) + JULIAN_DAY_OF_CE
+    }
+
+    /// Makes a new `NaiveDate` from a Julian Day Number,
+    /// the inverse of [`to_julian_day_number`](#method.to_julian_day_number).
+    ///
+    /// Returns `None` if the date is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// assert_eq!(NaiveDate::from_julian_day_number(2_440_588), NaiveDate::from_ymd_opt(1970, 1, 1));
+    /// assert_eq!(NaiveDate::from_julian_day_number(0), NaiveDate::from_ymd_opt(-4713, 11, 24));
+    /// ```
+    pub fn from_julian_day_number(jdn: i64) -> Option<NaiveDate> {
+        let days = jdn.checked_sub(JULIAN_DAY_OF_CE)?;
+        NaiveDate::from_num_days_from_ce_opt(i32::try_from(days).ok()?)
+    }
+
     /// The minimum possible `NaiveDate` (January 1, 262145 BCE).
     pub const MIN: NaiveDate = NaiveDate { ymdf: (MIN_YEAR << 13) | (1 << 4) | 0o07 /*FE*/ };
     /// The maximum possible `NaiveDate` (December 31, 262143 CE).
@@ -1630,6 +2128,24 @@ impl Sub<Months> for NaiveDate {
 impl Add<Days> for NaiveDate {
     type Output = NaiveDate;
 
+    /// Adds a number of days, the panicking version of
+    /// [`checked_add_days`](NaiveDate::checked_add_days).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(from_ymd(2014, 1, 31) + Days::new(1), from_ymd(2014, 2, 1));
+    /// assert_eq!(from_ymd(2014, 12, 31) + Days::new(1), from_ymd(2015, 1, 1));
+    /// assert_eq!(from_ymd(2016, 2, 28) + Days::new(1), from_ymd(2016, 2, 29));
+    /// ```
     fn add(self, days: Days) -> Self::Output {
         self.checked_add_days(days).unwrap()
     }
@@ -1638,6 +2154,23 @@ impl Add<Days> for NaiveDate {
 impl Sub<Days> for NaiveDate {
     type Output = NaiveDate;
 
+    /// Subtracts a number of days, the panicking version of
+    /// [`checked_sub_days`](NaiveDate::checked_sub_days).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting date would be out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Days, NaiveDate};
+    ///
+    /// let from_ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    ///
+    /// assert_eq!(from_ymd(2014, 3, 1) - Days::new(1), from_ymd(2014, 2, 28));
+    /// assert_eq!(from_ymd(2015, 1, 1) - Days::new(1), from_ymd(2014, 12, 31));
+    /// ```
     fn sub(self, days: Days) -> Self::Output {
         self.checked_sub_days(days).unwrap()
     }
@@ -1790,6 +2323,23 @@ impl DoubleEndedIterator for NaiveDateWeeksIterator {
     }
 }
 
+/// Iterator over the first days of the months in a range of `NaiveDate`s.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NaiveDateMonthStartsIterator {
+    next: Option<NaiveDate>,
+    end: NaiveDate,
+}
+
+impl Iterator for NaiveDateMonthStartsIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.filter(|d| *d < self.end)?;
+        self.next = current.checked_add_months(Months::new(1));
+        Some(current)
+    }
+}
+
 // TODO: NaiveDateDaysIterator and NaiveDateWeeksIterator should implement FusedIterator,
 // TrustedLen, and Step once they becomes stable.
 // See: https://github.com/chronotope/chrono/issues/208
@@ -2063,13 +2613,15 @@ mod serde {
 #[cfg(test)]
 mod tests {
     use super::{
-        Days, Months, NaiveDate, MAX_DAYS_FROM_YEAR_0, MAX_YEAR, MIN_DAYS_FROM_YEAR_0, MIN_YEAR,
+        Days, Months, NaiveDate, PartialDefaults, MAX_DAYS_FROM_YEAR_0, MAX_YEAR,
+        MIN_DAYS_FROM_YEAR_0, MIN_YEAR,
     };
+    use crate::format::ParseErrorKind;
     use crate::time_delta::TimeDelta;
     use crate::{Datelike, Weekday};
     use std::{
         convert::{TryFrom, TryInto},
-        i32, u32,
+        i32, i64, u32, u8,
     };
 
     #[test]
@@ -2228,6 +2780,22 @@ mod tests {
         assert_eq!(yo_opt(2014, 300), Some(ymd(2014, 10, 27)));
         assert_eq!(yo_opt(2014, 365), Some(ymd(2014, 12, 31)));
         assert_eq!(yo_opt(2014, 366), None);
+
+        assert_eq!(yo_opt(2024, 60), Some(ymd(2024, 2, 29)));
+        assert_eq!(yo_opt(2023, 365), Some(ymd(2023, 12, 31)));
+        assert_eq!(yo_opt(2023, 366), None);
+
+        // the inverse of `ordinal()`
+        for &year in &[2023, 2024, -4, MIN_YEAR, MAX_YEAR] {
+            let mut date = ymd(year, 1, 1);
+            while date.year() == year {
+                assert_eq!(yo_opt(year, date.ordinal()), Some(date));
+                match date.succ_opt() {
+                    Some(next) => date = next,
+                    None => break,
+                }
+            }
+        }
     }
 
     #[test]
@@ -2345,6 +2913,29 @@ mod tests {
         assert_eq!(from_ndays_from_ce(NaiveDate::MAX.num_days_from_ce() + 1), None);
     }
 
+    #[test]
+    fn test_date_nth_weekday_of_year() {
+        let ywn = NaiveDate::nth_weekday_of_year;
+        assert_eq!(ywn(2024, Weekday::Mon, 0), None);
+        assert_eq!(ywn(2024, Weekday::Mon, 1), NaiveDate::from_ymd_opt(2024, 1, 1));
+        assert_eq!(ywn(2024, Weekday::Sun, 1), NaiveDate::from_ymd_opt(2024, 1, 7));
+        assert_eq!(ywn(2024, Weekday::Mon, 52), NaiveDate::from_ymd_opt(2024, 12, 23));
+
+        // 2023 starts and ends on a Sunday, so it has 53 Sundays but only 52 Mondays
+        assert_eq!(ywn(2023, Weekday::Mon, 52), NaiveDate::from_ymd_opt(2023, 12, 25));
+        assert_eq!(ywn(2023, Weekday::Mon, 53), None);
+        assert_eq!(ywn(2023, Weekday::Sun, 53), NaiveDate::from_ymd_opt(2023, 12, 31));
+        assert_eq!(ywn(2023, Weekday::Sun, 54), None);
+
+        // leap years have two weekdays appearing 53 times
+        assert_eq!(ywn(2024, Weekday::Mon, 53), NaiveDate::from_ymd_opt(2024, 12, 30));
+        assert_eq!(ywn(2024, Weekday::Tue, 53), NaiveDate::from_ymd_opt(2024, 12, 31));
+        assert_eq!(ywn(2024, Weekday::Wed, 53), None);
+
+        assert_eq!(ywn(MAX_YEAR + 1, Weekday::Mon, 1), None);
+        assert_eq!(ywn(2024, Weekday::Mon, u8::MAX), None);
+    }
+
     #[test]
     fn test_date_from_weekday_of_month_opt() {
         let ymwd = NaiveDate::from_weekday_of_month_opt;
@@ -2454,6 +3045,14 @@ mod tests {
         assert_eq!(d.with_year(2001), None);
         assert_eq!(d.with_year(2004), Some(NaiveDate::from_ymd_opt(2004, 2, 29).unwrap()));
         assert_eq!(d.with_year(i32::MAX), None);
+        assert_eq!(d.with_year_clamped(1900), NaiveDate::from_ymd_opt(1900, 2, 28).unwrap());
+        assert_eq!(d.with_year_clamped(2004), NaiveDate::from_ymd_opt(2004, 2, 29).unwrap());
+
+        let d = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(d.with_year_clamped(2023), NaiveDate::from_ymd_opt(2023, 2, 28).unwrap());
+        assert_eq!(d.with_year_clamped(2028), NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+        let d = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+        assert_eq!(d.with_year_clamped(2023), NaiveDate::from_ymd_opt(2023, 3, 31).unwrap());
 
         let d = NaiveDate::from_ymd_opt(2000, 4, 30).unwrap();
         assert_eq!(d.with_month(0), None);
@@ -2482,6 +3081,12 @@ mod tests {
         assert_eq!(d.with_ordinal(u32::MAX), None);
     }
 
+    #[test]
+    #[should_panic]
+    fn test_date_with_year_clamped_out_of_range() {
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().with_year_clamped(MAX_YEAR + 1);
+    }
+
     #[test]
     fn test_date_num_days_from_ce() {
         assert_eq!(NaiveDate::from_ymd_opt(1, 1, 1).unwrap().num_days_from_ce(), 1);
@@ -2514,6 +3119,198 @@ mod tests {
         assert_eq!(ymd(NaiveDate::MIN.year(), 1, 1).pred_opt(), None);
     }
 
+    #[test]
+    fn test_date_bytes() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for &date in &[
+            NaiveDate::MIN,
+            ymd(-1, 12, 31),
+            ymd(0, 1, 1),
+            ymd(1, 1, 1),
+            ymd(1970, 1, 1),
+            ymd(2000, 2, 29),
+            ymd(2023, 5, 1),
+            NaiveDate::MAX,
+        ] {
+            assert_eq!(NaiveDate::from_bytes(date.to_bytes()), Some(date));
+        }
+        assert_eq!(ymd(1, 1, 1).to_bytes(), [1, 0, 0, 0]);
+        assert_eq!(ymd(0, 12, 31).to_bytes(), [0, 0, 0, 0]);
+        assert_eq!(ymd(0, 12, 30).to_bytes(), [0xff, 0xff, 0xff, 0xff]);
+
+        let after_max = NaiveDate::MAX.num_days_from_ce() + 1;
+        let before_min = NaiveDate::MIN.num_days_from_ce() - 1;
+        assert_eq!(NaiveDate::from_bytes(after_max.to_le_bytes()), None);
+        assert_eq!(NaiveDate::from_bytes(before_min.to_le_bytes()), None);
+        assert_eq!(NaiveDate::from_bytes(i32::MAX.to_le_bytes()), None);
+    }
+
+    #[test]
+    fn test_date_julian_day_number() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(1970, 1, 1).to_julian_day_number(), 2_440_588);
+        assert_eq!(ymd(2000, 1, 1).to_julian_day_number(), 2_451_545);
+        assert_eq!(ymd(1858, 11, 17).to_julian_day_number(), 2_400_001);
+        assert_eq!(ymd(1582, 10, 15).to_julian_day_number(), 2_299_161);
+        assert_eq!(ymd(-4713, 11, 24).to_julian_day_number(), 0);
+
+        for &date in
+            &[NaiveDate::MIN, ymd(-4713, 11, 23), ymd(1, 1, 1), ymd(2023, 5, 17), NaiveDate::MAX]
+        {
+            assert_eq!(NaiveDate::from_julian_day_number(date.to_julian_day_number()), Some(date));
+        }
+        assert_eq!(
+            NaiveDate::from_julian_day_number(NaiveDate::MAX.to_julian_day_number() + 1),
+            None
+        );
+        assert_eq!(
+            NaiveDate::from_julian_day_number(NaiveDate::MIN.to_julian_day_number() - 1),
+            None
+        );
+        assert_eq!(NaiveDate::from_julian_day_number(i64::MAX), None);
+        assert_eq!(NaiveDate::from_julian_day_number(i64::MIN), None);
+    }
+
+    #[test]
+    fn test_date_week_of_month() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 2023-10-01 is a Sunday
+        assert_eq!(ymd(2023, 10, 1).week_of_month(Weekday::Mon), 1);
+        assert_eq!(ymd(2023, 10, 2).week_of_month(Weekday::Mon), 2);
+        assert_eq!(ymd(2023, 10, 8).week_of_month(Weekday::Mon), 2);
+        assert_eq!(ymd(2023, 10, 9).week_of_month(Weekday::Mon), 3);
+        assert_eq!(ymd(2023, 10, 31).week_of_month(Weekday::Mon), 6);
+        assert_eq!(ymd(2023, 10, 1).week_of_month(Weekday::Sun), 1);
+        assert_eq!(ymd(2023, 10, 7).week_of_month(Weekday::Sun), 1);
+        assert_eq!(ymd(2023, 10, 8).week_of_month(Weekday::Sun), 2);
+        assert_eq!(ymd(2023, 10, 31).week_of_month(Weekday::Sun), 5);
+
+        // 2023-05-01 is a Monday
+        assert_eq!(ymd(2023, 5, 1).week_of_month(Weekday::Mon), 1);
+        assert_eq!(ymd(2023, 5, 8).week_of_month(Weekday::Mon), 2);
+        assert_eq!(ymd(2023, 5, 31).week_of_month(Weekday::Mon), 5);
+        assert_eq!(ymd(2023, 5, 1).week_of_month(Weekday::Sun), 1);
+        assert_eq!(ymd(2023, 5, 7).week_of_month(Weekday::Sun), 2);
+        assert_eq!(ymd(2023, 5, 8).week_of_month(Weekday::Sun), 2);
+        assert_eq!(ymd(2023, 5, 31).week_of_month(Weekday::Sun), 5);
+
+        // February 2015 fits in exactly four rows starting on Sunday
+        assert_eq!(ymd(2015, 2, 28).week_of_month(Weekday::Sun), 4);
+        assert_eq!(ymd(2015, 2, 28).week_of_month(Weekday::Mon), 5);
+
+        // agrees with a naive count for every start day
+        for &start in &[Weekday::Mon, Weekday::Wed, Weekday::Sat, Weekday::Sun] {
+            let mut week = 1;
+            for date in ymd(2024, 2, 1).iter_days().take_while(|d| d.month() == 2) {
+                if date.day() > 1 && date.weekday() == start {
+                    week += 1;
+                }
+                assert_eq!(date.week_of_month(start), week);
+            }
+        }
+    }
+
+    #[test]
+    fn test_days_arithmetic() {
+        assert_eq!(Days::new(2) + Days::new(3), Days::new(5));
+        assert_eq!(Days::new(5) - Days::new(3), Days::new(2));
+        assert_eq!(Days::new(7) * 2, Days::new(14));
+
+        assert_eq!(Days::new(0).to_string(), "0 days");
+        assert_eq!(Days::new(1).to_string(), "1 day");
+        assert_eq!(Days::new(3).to_string(), "3 days");
+
+        let date = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap();
+        assert_eq!(date + Days::new(7) * 2, NaiveDate::from_ymd_opt(2022, 1, 15).unwrap());
+    }
+
+    #[test]
+    fn test_date_add_sub_days_operators() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(2023, 1, 31) + Days::new(1), ymd(2023, 2, 1));
+        assert_eq!(ymd(2024, 2, 28) + Days::new(1), ymd(2024, 2, 29));
+        assert_eq!(ymd(2023, 2, 28) + Days::new(1), ymd(2023, 3, 1));
+        assert_eq!(ymd(2023, 1, 1) - Days::new(1), ymd(2022, 12, 31));
+        assert_eq!(ymd(2023, 1, 10) - Days::new(40), ymd(2022, 12, 1));
+        assert_eq!(ymd(2023, 5, 17) + Days::new(0), ymd(2023, 5, 17));
+        assert_eq!(ymd(2023, 5, 17) - Days::new(365) + Days::new(365), ymd(2023, 5, 17));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_date_add_days_overflow() {
+        let _ = NaiveDate::MAX + Days::new(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_date_sub_days_underflow() {
+        let _ = NaiveDate::MIN - Days::new(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_days_sub_underflow() {
+        let _ = Days::new(1) - Days::new(2);
+    }
+
+    #[test]
+    fn test_date_bounds() {
+        use crate::NaiveDateTime;
+
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(NaiveDate::MIN, ymd(-262144, 1, 1));
+        assert_eq!(NaiveDate::MAX, ymd(262143, 12, 31));
+        assert_eq!(NaiveDate::MIN.pred_opt(), None);
+        assert_eq!(NaiveDate::MAX.succ_opt(), None);
+        assert_eq!(NaiveDate::from_ymd_opt(-262145, 12, 31), None);
+        assert_eq!(NaiveDate::from_ymd_opt(262144, 1, 1), None);
+
+        let nanos = TimeDelta::nanoseconds(1);
+        assert_eq!(NaiveDateTime::MIN, ymd(-262144, 1, 1).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(
+            NaiveDateTime::MAX,
+            ymd(262143, 12, 31).and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap()
+        );
+        assert_eq!(NaiveDateTime::MIN.checked_sub_signed(nanos), None);
+        assert_eq!(NaiveDateTime::MAX.checked_add_signed(nanos), None);
+        assert!(NaiveDateTime::MAX.checked_sub_signed(nanos).is_some());
+    }
+
+    #[test]
+    fn test_date_weekday_navigation() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let wed = ymd(2022, 6, 15);
+        assert_eq!(wed.weekday(), Weekday::Wed);
+
+        assert_eq!(wed.next_weekday(Weekday::Mon), Some(ymd(2022, 6, 20)));
+        assert_eq!(wed.next_weekday(Weekday::Thu), Some(ymd(2022, 6, 16)));
+        assert_eq!(wed.next_weekday(Weekday::Wed), Some(ymd(2022, 6, 22)));
+        assert_eq!(wed.next_or_same_weekday(Weekday::Mon), Some(ymd(2022, 6, 20)));
+        assert_eq!(wed.next_or_same_weekday(Weekday::Wed), Some(wed));
+
+        assert_eq!(wed.prev_weekday(Weekday::Fri), Some(ymd(2022, 6, 10)));
+        assert_eq!(wed.prev_weekday(Weekday::Tue), Some(ymd(2022, 6, 14)));
+        assert_eq!(wed.prev_weekday(Weekday::Wed), Some(ymd(2022, 6, 8)));
+        assert_eq!(wed.prev_or_same_weekday(Weekday::Fri), Some(ymd(2022, 6, 10)));
+        assert_eq!(wed.prev_or_same_weekday(Weekday::Wed), Some(wed));
+
+        // across month and year boundaries
+        assert_eq!(ymd(2022, 12, 30).next_weekday(Weekday::Mon), Some(ymd(2023, 1, 2)));
+        assert_eq!(ymd(2023, 1, 2).prev_weekday(Weekday::Fri), Some(ymd(2022, 12, 30)));
+
+        // near the bounds
+        let max_weekday = NaiveDate::MAX.weekday();
+        assert_eq!(NaiveDate::MAX.next_weekday(max_weekday.succ()), None);
+        assert_eq!(NaiveDate::MAX.next_or_same_weekday(max_weekday), Some(NaiveDate::MAX));
+        assert_eq!(NaiveDate::MAX.next_or_same_weekday(max_weekday.succ()), None);
+        let min_weekday = NaiveDate::MIN.weekday();
+        assert_eq!(NaiveDate::MIN.prev_weekday(min_weekday.pred()), None);
+        assert_eq!(NaiveDate::MIN.prev_or_same_weekday(min_weekday), Some(NaiveDate::MIN));
+        assert_eq!(NaiveDate::MIN.prev_or_same_weekday(min_weekday.pred()), None);
+    }
+
     #[test]
     fn test_date_add() {
         fn check((y1, m1, d1): (i32, u32, u32), rhs: TimeDelta, ymd: Option<(i32, u32, u32)>) {
@@ -2564,6 +3361,28 @@ mod tests {
         check((MIN_YEAR, 1, 1), (0, 1, 1), TimeDelta::days(MIN_DAYS_FROM_YEAR_0 as i64));
     }
 
+    #[test]
+    fn test_date_days_since() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(ymd(2014, 1, 11).days_since(ymd(2014, 1, 1)), 10);
+        assert_eq!(ymd(2014, 1, 11).checked_days_since(ymd(2014, 1, 1)), Some(Days::new(10)));
+        assert_eq!(ymd(2014, 1, 1).days_since(ymd(2014, 1, 11)), -10);
+        assert_eq!(ymd(2014, 1, 1).checked_days_since(ymd(2014, 1, 11)), None);
+        assert_eq!(ymd(2014, 1, 1).days_since(ymd(2014, 1, 1)), 0);
+        assert_eq!(ymd(2014, 1, 1).checked_days_since(ymd(2014, 1, 1)), Some(Days::new(0)));
+
+        // round-trips with `Days` arithmetic
+        let (start, end) = (ymd(2020, 2, 28), ymd(2024, 3, 1));
+        let days = end.checked_days_since(start).unwrap();
+        assert_eq!(start.checked_add_days(days), Some(end));
+
+        assert_eq!(
+            NaiveDate::MAX.days_since(NaiveDate::MIN),
+            MAX_DAYS_FROM_YEAR_0 as i64 - MIN_DAYS_FROM_YEAR_0 as i64
+        );
+    }
+
     #[test]
     fn test_date_add_days() {
         fn check((y1, m1, d1): (i32, u32, u32), rhs: Days, ymd: Option<(i32, u32, u32)>) {
@@ -2588,6 +3407,32 @@ mod tests {
             Some((MAX_YEAR, 12, 31)),
         );
         check((0, 1, 1), Days::new(u64::try_from(MAX_DAYS_FROM_YEAR_0).unwrap() + 1), None);
+        check((0, 1, 1), Days::new(u64::max_value()), None);
+        check((0, 1, 1), Days::new(i64::max_value() as u64), None);
+        assert_eq!(NaiveDate::MAX.checked_sub_days(Days::new(i64::max_value() as u64)), None);
+    }
+
+    #[test]
+    fn test_date_saturating_add_signed() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let date = ymd(2014, 1, 1);
+
+        assert_eq!(date.saturating_add_signed(TimeDelta::zero()), date);
+        assert_eq!(date.saturating_add_signed(TimeDelta::days(365)), ymd(2015, 1, 1));
+        assert_eq!(date.saturating_add_signed(TimeDelta::days(-365)), ymd(2013, 1, 1));
+
+        assert_eq!(date.saturating_add_signed(TimeDelta::max_value()), NaiveDate::MAX);
+        assert_eq!(date.saturating_add_signed(TimeDelta::min_value()), NaiveDate::MIN);
+        assert_eq!(NaiveDate::MAX.saturating_add_signed(TimeDelta::days(1)), NaiveDate::MAX);
+        assert_eq!(NaiveDate::MIN.saturating_add_signed(TimeDelta::days(-1)), NaiveDate::MIN);
+        assert_eq!(
+            NaiveDate::MAX.saturating_add_signed(TimeDelta::days(-1)),
+            ymd(MAX_YEAR, 12, 30)
+        );
+
+        // only whole days count
+        assert_eq!(NaiveDate::MAX.saturating_add_signed(TimeDelta::hours(23)), NaiveDate::MAX);
+        assert_eq!(date.saturating_add_signed(TimeDelta::hours(-23)), date);
     }
 
     #[test]
@@ -2720,6 +3565,155 @@ mod tests {
         assert!(NaiveDate::parse_from_str("2014", "%Y").is_err()); // insufficient
     }
 
+    #[test]
+    fn test_date_weekday_ordinal() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(ymd(2024, 3, 12).weekday(), Weekday::Tue);
+        assert_eq!(ymd(2024, 3, 12).weekday_ordinal(), 2);
+        for m in 1..=12 {
+            assert_eq!(ymd(2024, m, 1).weekday_ordinal(), 1);
+        }
+        assert_eq!(ymd(2024, 3, 7).weekday_ordinal(), 1);
+        assert_eq!(ymd(2024, 3, 8).weekday_ordinal(), 2);
+        assert_eq!(ymd(2024, 3, 29).weekday_ordinal(), 5);
+        assert_eq!(ymd(2024, 3, 31).weekday_ordinal(), 5);
+        assert_eq!(NaiveDate::MIN.weekday_ordinal(), 1);
+        assert_eq!(NaiveDate::MAX.weekday_ordinal(), 5);
+
+        // round-trips through `from_weekday_of_month_opt`
+        for date in ymd(2023, 12, 25).iter_days().take(100) {
+            let n = date.weekday_ordinal();
+            assert_eq!(
+                NaiveDate::from_weekday_of_month_opt(date.year(), date.month(), date.weekday(), n),
+                Some(date)
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_day_with_ordinal_suffix() {
+        let suffixed = |d| NaiveDate::from_ymd_opt(2023, 1, d).unwrap().day_with_ordinal_suffix();
+        assert_eq!(suffixed(1), "1st");
+        assert_eq!(suffixed(2), "2nd");
+        assert_eq!(suffixed(3), "3rd");
+        assert_eq!(suffixed(4), "4th");
+        assert_eq!(suffixed(10), "10th");
+        assert_eq!(suffixed(11), "11th");
+        assert_eq!(suffixed(12), "12th");
+        assert_eq!(suffixed(13), "13th");
+        assert_eq!(suffixed(20), "20th");
+        assert_eq!(suffixed(21), "21st");
+        assert_eq!(suffixed(22), "22nd");
+        assert_eq!(suffixed(23), "23rd");
+        assert_eq!(suffixed(30), "30th");
+        assert_eq!(suffixed(31), "31st");
+    }
+
+    #[test]
+    fn test_date_parse_partial() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let defaults = PartialDefaults::default();
+
+        assert_eq!(NaiveDate::parse_partial("2024", "%Y", defaults), Ok(ymd(2024, 1, 1)));
+        assert_eq!(NaiveDate::parse_partial("2024-03", "%Y-%m", defaults), Ok(ymd(2024, 3, 1)));
+        assert_eq!(
+            NaiveDate::parse_partial("2024-03-15", "%Y-%m-%d", defaults),
+            Ok(ymd(2024, 3, 15))
+        );
+        assert_eq!(NaiveDate::parse_partial("Mar 2024", "%b %Y", defaults), Ok(ymd(2024, 3, 1)));
+
+        let defaults = PartialDefaults::new(6, 15);
+        assert_eq!(NaiveDate::parse_partial("2024", "%Y", defaults), Ok(ymd(2024, 6, 15)));
+        assert_eq!(NaiveDate::parse_partial("2024-03", "%Y-%m", defaults), Ok(ymd(2024, 3, 15)));
+        assert_eq!(NaiveDate::parse_partial("2024 5", "%Y %d", defaults), Ok(ymd(2024, 6, 5)));
+
+        // ordinal and week dates are not defaulted
+        assert_eq!(NaiveDate::parse_partial("2024-100", "%Y-%j", defaults), Ok(ymd(2024, 4, 9)));
+        assert_eq!(
+            NaiveDate::parse_partial("2024-W10-1", "%G-W%V-%u", defaults),
+            Ok(ymd(2024, 3, 4))
+        );
+
+        // the year is required, and the defaults must make a valid date
+        assert_eq!(
+            NaiveDate::parse_partial("03-15", "%m-%d", defaults).unwrap_err().kind(),
+            ParseErrorKind::NotEnough
+        );
+        let end_of_month = PartialDefaults::default().day(31);
+        assert_eq!(
+            NaiveDate::parse_partial("2024-03", "%Y-%m", end_of_month),
+            Ok(ymd(2024, 3, 31))
+        );
+        assert!(NaiveDate::parse_partial("2024-04", "%Y-%m", end_of_month).is_err());
+        assert!(NaiveDate::parse_partial("2024", "%Y", defaults.month(13)).is_err());
+    }
+
+    #[test]
+    fn test_date_parse_from_str_with_year_pivot() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let parse = |s, pivot| NaiveDate::parse_from_str_with_year_pivot(s, "%y-%m-%d", pivot);
+
+        assert_eq!(parse("49-01-01", 1950), Ok(ymd(2049, 1, 1)));
+        assert_eq!(NaiveDate::parse_from_str("49-01-01", "%y-%m-%d"), Ok(ymd(2049, 1, 1)));
+        assert_eq!(parse("50-01-01", 1950), Ok(ymd(1950, 1, 1)));
+        assert_eq!(NaiveDate::parse_from_str("50-01-01", "%y-%m-%d"), Ok(ymd(2050, 1, 1)));
+
+        // the default pivot
+        assert_eq!(parse("69-01-01", 1970), Ok(ymd(2069, 1, 1)));
+        assert_eq!(parse("70-01-01", 1970), Ok(ymd(1970, 1, 1)));
+
+        assert_eq!(parse("99-12-31", 2000), Ok(ymd(2099, 12, 31)));
+        assert_eq!(parse("00-01-01", 1901), Ok(ymd(2000, 1, 1)));
+        assert_eq!(parse("23-06-15", 1823), Ok(ymd(1823, 6, 15)));
+
+        // full years and explicit centuries are kept
+        let parse_with = |s, fmt| NaiveDate::parse_from_str_with_year_pivot(s, fmt, 1950);
+        assert_eq!(parse_with("2075-01-01", "%Y-%m-%d"), Ok(ymd(2075, 1, 1)));
+        assert_eq!(parse_with("2075-01-01", "%C%y-%m-%d"), Ok(ymd(2075, 1, 1)));
+
+        // the ISO week date year is resolved the same way
+        assert_eq!(parse_with("50-W01-1", "%g-W%V-%u"), Ok(ymd(1950, 1, 2)));
+        assert_eq!(parse_with("49-W01-1", "%g-W%V-%u"), Ok(ymd(2049, 1, 4)));
+
+        assert!(
+            NaiveDate::parse_from_str_with_year_pivot("49-01-01", "%y-%m-%d", i32::MAX).is_err()
+        );
+    }
+
+    #[test]
+    fn test_date_parse_from_str_week_numbers() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let date = ymd(2023, 7, 12);
+        assert_eq!(date.format("%Y %U %w").to_string(), "2023 28 3");
+        assert_eq!(date.format("%Y %W %u").to_string(), "2023 28 3");
+        assert_eq!(NaiveDate::parse_from_str("2023 28 3", "%Y %U %w"), Ok(date));
+        assert_eq!(NaiveDate::parse_from_str("2023 28 3", "%Y %W %u"), Ok(date));
+
+        // a Sunday on the 189th day of the year (a multiple of 7) is still in week 27
+        assert_eq!(ymd(2001, 7, 8).format("%U %W").to_string(), "27 27");
+        assert_eq!(NaiveDate::parse_from_str("2001 27 0", "%Y %U %w"), Ok(ymd(2001, 7, 8)));
+        assert_eq!(NaiveDate::parse_from_str("2024 01 1", "%Y %W %u"), Ok(ymd(2024, 1, 1)));
+        assert_eq!(NaiveDate::parse_from_str("2024 00 1", "%Y %U %w"), Ok(ymd(2024, 1, 1)));
+
+        // week 0 holds the days before the first Sunday (`%U`) or Monday (`%W`)
+        assert_eq!(NaiveDate::parse_from_str("2022 00 6", "%Y %U %w"), Ok(ymd(2022, 1, 1)));
+        assert_eq!(NaiveDate::parse_from_str("2022 00 7", "%Y %W %u"), Ok(ymd(2022, 1, 2)));
+        assert!(NaiveDate::parse_from_str("2022 00 5", "%Y %U %w").is_err()); // 2021-12-31
+
+        // round-trip every day of a few years with differing first weekdays and leap years
+        for &year in &[2000, 2021, 2022, 2023, 2024] {
+            let mut date = ymd(year, 1, 1);
+            while date.year() == year {
+                for &fmt in &["%Y %U %w", "%Y %W %u", "%Y %U %a", "%Y %W %A"] {
+                    let s = date.format(fmt).to_string();
+                    assert_eq!(NaiveDate::parse_from_str(&s, fmt), Ok(date), "{} with {}", s, fmt);
+                }
+                date = date.succ_opt().unwrap();
+            }
+        }
+    }
+
     #[test]
     fn test_date_format() {
         let d = NaiveDate::from_ymd_opt(2012, 3, 4).unwrap();
@@ -2756,7 +3750,7 @@ mod tests {
         // corner cases
         assert_eq!(
             NaiveDate::from_ymd_opt(2007, 12, 31).unwrap().format("%G,%g,%U,%W,%V").to_string(),
-            "2008,08,53,53,01"
+            "2008,08,52,53,01"
         );
         assert_eq!(
             NaiveDate::from_ymd_opt(2010, 1, 3).unwrap().format("%G,%g,%U,%W,%V").to_string(),
@@ -2785,6 +3779,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_month_starts_iterator() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let starts = |start, end| NaiveDate::iter_month_starts(start, end).collect::<Vec<_>>();
+
+        assert_eq!(
+            starts(ymd(2023, 1, 15), ymd(2023, 4, 10)),
+            [ymd(2023, 2, 1), ymd(2023, 3, 1), ymd(2023, 4, 1)]
+        );
+        // `start` is included when it is a month start, `end` never is
+        assert_eq!(starts(ymd(2023, 1, 1), ymd(2023, 3, 1)), [ymd(2023, 1, 1), ymd(2023, 2, 1)]);
+        assert_eq!(starts(ymd(2023, 12, 31), ymd(2024, 1, 2)), [ymd(2024, 1, 1)]);
+
+        // within a single month
+        assert_eq!(starts(ymd(2023, 3, 5), ymd(2023, 3, 25)), []);
+        assert_eq!(starts(ymd(2023, 3, 1), ymd(2023, 3, 25)), [ymd(2023, 3, 1)]);
+
+        // empty or reversed ranges
+        assert_eq!(starts(ymd(2023, 3, 1), ymd(2023, 3, 1)), []);
+        assert_eq!(starts(ymd(2023, 6, 1), ymd(2023, 3, 1)), []);
+
+        // the end of the representable range
+        assert_eq!(starts(ymd(MAX_YEAR, 11, 15), NaiveDate::MAX), [ymd(MAX_YEAR, 12, 1)]);
+        assert_eq!(starts(ymd(MAX_YEAR, 12, 2), NaiveDate::MAX), []);
+    }
+
     #[test]
     fn test_naiveweek() {
         let date = NaiveDate::from_ymd_opt(2022, 5, 18).unwrap();
@@ -3,6 +3,11 @@
 
 //! ISO 8601 date and time without timezone.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use core::borrow::Borrow;
 use core::convert::TryFrom;
@@ -13,12 +18,16 @@ use num_integer::div_mod_floor;
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::datetime::RFC3339_PREFIX;
 #[cfg(any(feature = "alloc", feature = "std", test))]
 use crate::format::DelayedFormat;
 use crate::format::{parse, ParseError, ParseResult, Parsed, StrftimeItems};
 use crate::format::{Fixed, Item, Numeric, Pad};
 use crate::naive::{Days, IsoWeek, NaiveDate, NaiveTime};
-use crate::{DateTime, Datelike, LocalResult, Months, TimeDelta, TimeZone, Timelike, Weekday};
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::SecondsFormat;
+use crate::{DateTime, Datelike, LocalResult, Months, TimeDelta, TimeZone, Timelike, Utc, Weekday};
 
 /// Tools to help serializing/deserializing `NaiveDateTime`s
 #[cfg(feature = "serde")]
@@ -264,6 +273,63 @@ impl NaiveDateTime {
         parsed.to_naive_datetime_with_offset(0) // no offset adjustment
     }
 
+    /// Parses a string with the specified format string like
+    /// [`parse_from_str`](#method.parse_from_str), but also accepts a trailing offset after the
+    /// formatted fields.
+    ///
+    /// The offset can be `Z`, `UTC` or numeric like `+05:00` or `-0800`, optionally preceded
+    /// by whitespace. It is ignored: the result is the local date and time as written, not
+    /// converted to UTC.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, NaiveDateTime};
+    ///
+    /// let expected = NaiveDate::from_ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9);
+    /// let fmt = "%Y-%m-%dT%H:%M:%S";
+    /// assert_eq!(NaiveDateTime::parse_from_str_ignoring_offset("2015-02-18T23:16:09Z", fmt).ok(), expected);
+    /// assert_eq!(NaiveDateTime::parse_from_str_ignoring_offset("2015-02-18T23:16:09+05:00", fmt).ok(), expected);
+    /// assert!(NaiveDateTime::parse_from_str("2015-02-18T23:16:09Z", fmt).is_err());
+    /// ```
+    pub fn parse_from_str_ignoring_offset(s: &str, fmt: &str) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        if let Err(e) = parse(&mut parsed, s, StrftimeItems::new(fmt)) {
+            let offset: &[Item] = &[Item::Fixed(Fixed::TimezoneOffsetZ)];
+            parsed = Parsed::new();
+            parse(&mut parsed, s, StrftimeItems::new(fmt).chain(offset.iter().cloned()))
+                .map_err(|_| e)?;
+        }
+        parsed.to_naive_datetime_with_offset(0) // the offset is deliberately ignored
+    }
+
+    /// Parses a string with the specified format string like
+    /// [`parse_from_str`](#method.parse_from_str), but resolves a two-digit year to the year in
+    /// `[pivot, pivot + 100)` ending in those digits.
+    ///
+    /// See [`NaiveDate::parse_from_str_with_year_pivot`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let parse = |s| NaiveDateTime::parse_from_str_with_year_pivot(s, "%y-%m-%d %H:%M", 1950);
+    /// let date = NaiveDate::from_ymd_opt(1950, 7, 8).unwrap();
+    /// assert_eq!(parse("50-07-08 09:10"), Ok(date.and_hms_opt(9, 10, 0).unwrap()));
+    /// ```
+    pub fn parse_from_str_with_year_pivot(
+        s: &str,
+        fmt: &str,
+        pivot: i32,
+    ) -> ParseResult<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, StrftimeItems::new(fmt))?;
+        parsed.resolve_two_digit_years(pivot)?;
+        parsed.to_naive_datetime_with_offset(0) // no offset adjustment
+    }
+
     /// Retrieves a date component.
     ///
     /// # Example
@@ -562,9 +628,10 @@ impl NaiveDateTime {
 
     /// Adds given `Months` to the current date and time.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// The time of day is kept, and the day is clamped to the last day of the resulting month
+    /// (see [`NaiveDate::checked_add_months`]).
     ///
-    /// Overflow returns `None`.
+    /// Returns `None` when it will result in overflow.
     ///
     /// # Example
     ///
@@ -663,9 +730,10 @@ impl NaiveDateTime {
 
     /// Subtracts given `Months` from the current date and time.
     ///
-    /// Returns `None` when it will result in overflow.
+    /// The time of day is kept, and the day is clamped to the last day of the resulting month
+    /// (see [`NaiveDate::checked_sub_months`]).
     ///
-    /// Overflow returns `None`.
+    /// Returns `None` when it will result in overflow.
     ///
     /// # Example
     ///
@@ -822,6 +890,32 @@ impl NaiveDateTime {
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Returns an ISO 8601 date and time string such as `2018-01-26T18:30:09.453`, with
+    /// subseconds formatted as per `SecondsFormat`.
+    ///
+    /// This uses the same layout as
+    /// [`DateTime::to_rfc3339_opts`](../struct.DateTime.html#method.to_rfc3339_opts),
+    /// without the offset suffix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, SecondsFormat};
+    ///
+    /// let dt = NaiveDate::from_ymd_opt(2018, 1, 26).unwrap().and_hms_micro_opt(18, 30, 9, 453_829).unwrap();
+    /// assert_eq!(dt.to_string_opts(SecondsFormat::Secs), "2018-01-26T18:30:09");
+    /// assert_eq!(dt.to_string_opts(SecondsFormat::Millis), "2018-01-26T18:30:09.453");
+    /// assert_eq!(dt.to_string_opts(SecondsFormat::AutoSi), "2018-01-26T18:30:09.453829");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_string_opts(&self, secform: SecondsFormat) -> String {
+        match secform.subsec_item() {
+            None => self.format_with_items(RFC3339_PREFIX.iter()).to_string(),
+            Some(s) => self.format_with_items(RFC3339_PREFIX.iter().chain([s].iter())).to_string(),
+        }
+    }
+
     /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Tz>`
     /// with the provided timezone, if possible.
     ///
@@ -839,13 +933,51 @@ impl NaiveDateTime {
     /// use chrono::{NaiveDate, Utc};
     /// let dt = NaiveDate::from_ymd_opt(2015, 9, 5).unwrap().and_hms_opt(23, 56, 4).unwrap().and_local_timezone(Utc).unwrap();
     /// assert_eq!(dt.timezone(), Utc);
+    /// ```
     pub fn and_local_timezone<Tz: TimeZone>(&self, tz: Tz) -> LocalResult<DateTime<Tz>> {
         tz.from_local_datetime(self)
     }
 
-    /// The minimum possible `NaiveDateTime`.
+    /// Converts the `NaiveDateTime`, read as a local time in `tz`, into the timezone-aware
+    /// `DateTime<Tz>`.
+    ///
+    /// This is the same as [`and_local_timezone`](#method.and_local_timezone), under the name
+    /// that pairs with [`and_utc`](#method.and_utc). See there for when it can fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate};
+    /// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// let dt = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap().and_hms_opt(19, 32, 33).unwrap().and_timezone(tz).unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2023-01-30T19:32:33+09:00");
+    /// ```
+    #[inline]
+    pub fn and_timezone<Tz: TimeZone>(&self, tz: Tz) -> LocalResult<DateTime<Tz>> {
+        self.and_local_timezone(tz)
+    }
+
+    /// Converts the `NaiveDateTime` into the timezone-aware `DateTime<Utc>`.
+    ///
+    /// This is the same as `self.and_local_timezone(Utc).unwrap()`, but never fails
+    /// as UTC has no offset transitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Utc};
+    /// let dt = NaiveDate::from_ymd_opt(2023, 1, 30).unwrap().and_hms_opt(19, 32, 33).unwrap().and_utc();
+    /// assert_eq!(dt.timezone(), Utc);
+    /// assert_eq!(dt.to_rfc3339(), "2023-01-30T19:32:33+00:00");
+    /// ```
+    #[inline]
+    pub fn and_utc(&self) -> DateTime<Utc> {
+        DateTime::from_utc(*self, Utc)
+    }
+
+    /// The minimum possible `NaiveDateTime` (January 1, 262145 BCE, 00:00:00).
     pub const MIN: Self = Self { date: NaiveDate::MIN, time: NaiveTime::MIN };
-    /// The maximum possible `NaiveDateTime`.
+    /// The maximum possible `NaiveDateTime` (December 31, 262143 CE, 23:59:59.999999999).
     pub const MAX: Self = Self { date: NaiveDate::MAX, time: NaiveTime::MAX };
 }
 
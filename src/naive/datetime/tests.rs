@@ -1,7 +1,8 @@
 use super::NaiveDateTime;
 use crate::naive::NaiveDate;
+use crate::offset::tests::Cet;
 use crate::time_delta::TimeDelta;
-use crate::{Datelike, FixedOffset, Utc};
+use crate::{DateTime, Datelike, FixedOffset, LocalResult, Months, Utc};
 use std::i64;
 
 #[test]
@@ -85,6 +86,26 @@ fn test_datetime_sub() {
     );
 }
 
+#[test]
+fn test_datetime_add_sub_months() {
+    let ymdhms = |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s);
+
+    let dt = ymdhms(2024, 1, 31, 12, 0, 0).unwrap();
+    assert_eq!(dt.checked_add_months(Months::new(0)), Some(dt));
+    assert_eq!(dt.checked_add_months(Months::new(1)), ymdhms(2024, 2, 29, 12, 0, 0));
+    assert_eq!(dt.checked_add_months(Months::new(2)), ymdhms(2024, 3, 31, 12, 0, 0));
+    assert_eq!(dt.checked_add_months(Months::new(13)), ymdhms(2025, 2, 28, 12, 0, 0));
+    assert_eq!(dt.checked_sub_months(Months::new(2)), ymdhms(2023, 11, 30, 12, 0, 0));
+    assert_eq!(dt.checked_sub_months(Months::new(11)), ymdhms(2023, 2, 28, 12, 0, 0));
+
+    let leap = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500);
+    let leap = leap.unwrap();
+    assert_eq!(leap.checked_add_months(Months::new(1)).unwrap().time(), leap.time());
+
+    assert_eq!(NaiveDateTime::MAX.checked_add_months(Months::new(1)), None);
+    assert_eq!(NaiveDateTime::MIN.checked_sub_months(Months::new(1)), None);
+}
+
 #[test]
 fn test_datetime_addassignment() {
     let ymdhms =
@@ -226,6 +247,38 @@ fn test_datetime_format() {
     assert_eq!(dt.format("%s").to_string(), "1341100799"); // not 1341100800, it's intentional.
 }
 
+#[test]
+fn test_datetime_to_string_opts() {
+    use crate::SecondsFormat::*;
+    let dt = NaiveDate::from_ymd_opt(2018, 1, 11)
+        .unwrap()
+        .and_hms_nano_opt(10, 5, 13, 84_660_000)
+        .unwrap();
+    assert_eq!(dt.to_string_opts(Secs), "2018-01-11T10:05:13");
+    assert_eq!(dt.to_string_opts(Millis), "2018-01-11T10:05:13.084");
+    assert_eq!(dt.to_string_opts(Micros), "2018-01-11T10:05:13.084660");
+    assert_eq!(dt.to_string_opts(Nanos), "2018-01-11T10:05:13.084660000");
+    assert_eq!(dt.to_string_opts(AutoSi), "2018-01-11T10:05:13.084660");
+
+    let dt = NaiveDate::from_ymd_opt(2018, 1, 11).unwrap().and_hms_opt(2, 5, 13).unwrap();
+    assert_eq!(dt.to_string_opts(Millis), "2018-01-11T02:05:13.000");
+    assert_eq!(dt.to_string_opts(AutoSi), "2018-01-11T02:05:13");
+
+    // leap second
+    let dt = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_milli_opt(23, 59, 59, 1_500)
+        .unwrap();
+    assert_eq!(dt.to_string_opts(Millis), "2016-12-31T23:59:60.500");
+}
+
+#[test]
+#[should_panic]
+fn test_datetime_to_string_opts_nonexhaustive() {
+    let dt = NaiveDate::from_ymd_opt(1999, 10, 9).unwrap().and_hms_opt(1, 2, 3).unwrap();
+    dt.to_string_opts(crate::SecondsFormat::__NonExhaustive);
+}
+
 #[test]
 fn test_datetime_add_sub_invariant() {
     // issue #37
@@ -266,4 +319,64 @@ fn test_and_timezone() {
     let dt_offset = ndt.and_local_timezone(offset_tz).unwrap();
     assert_eq!(dt_offset.naive_local(), ndt);
     assert_eq!(dt_offset.timezone(), offset_tz);
+
+    // a local time skipped by a DST transition
+    let gap = NaiveDate::from_ymd_opt(2022, 3, 27).unwrap().and_hms_opt(2, 30, 0).unwrap();
+    assert_eq!(gap.and_local_timezone(Cet), LocalResult::None);
+    assert_eq!(gap.and_local_timezone(Utc), LocalResult::Single(gap.and_utc()));
+
+    // `and_timezone` is the same conversion
+    assert_eq!(ndt.and_timezone(Utc), LocalResult::Single(ndt.and_utc()));
+    assert_eq!(ndt.and_timezone(offset_tz).unwrap(), dt_offset);
+    assert_eq!(gap.and_timezone(Cet), LocalResult::None);
+}
+
+#[test]
+fn test_and_utc() {
+    let ndt =
+        NaiveDate::from_ymd_opt(2022, 6, 15).unwrap().and_hms_nano_opt(18, 59, 36, 7).unwrap();
+    let dt_utc = ndt.and_utc();
+    assert_eq!(dt_utc.naive_local(), ndt);
+    assert_eq!(dt_utc.naive_utc(), ndt);
+    assert_eq!(dt_utc.timezone(), Utc);
+    assert_eq!(dt_utc, ndt.and_local_timezone(Utc).unwrap());
+    assert_eq!(NaiveDateTime::MAX.and_utc(), DateTime::<Utc>::MAX_UTC);
+}
+
+#[test]
+fn test_datetime_parse_from_str_ignoring_offset() {
+    let parse = NaiveDateTime::parse_from_str_ignoring_offset;
+    let fmt = "%Y-%m-%dT%H:%M:%S";
+    let expected = NaiveDate::from_ymd_opt(2015, 2, 18).unwrap().and_hms_opt(23, 16, 9).unwrap();
+
+    assert_eq!(parse("2015-02-18T23:16:09", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18T23:16:09Z", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18T23:16:09z", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18T23:16:09+05:00", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18T23:16:09-0800", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18T23:16:09 UTC", fmt), Ok(expected));
+    assert_eq!(parse("2015-02-18 23:16:09 +05:00", "%Y-%m-%d %H:%M:%S"), Ok(expected));
+
+    // an offset in the format is still honoured (and ignored)
+    assert_eq!(parse("2015-02-18T23:16:09+05:00", "%Y-%m-%dT%H:%M:%S%:z"), Ok(expected));
+
+    // errors of the format itself are kept
+    assert_eq!(
+        parse("2015-02-18T23:16", fmt),
+        NaiveDateTime::parse_from_str("2015-02-18T23:16", fmt)
+    );
+    assert!(parse("2015-02-18T23:16:09 CET", fmt).is_err());
+    assert!(parse("2015-02-18T23:16:09Z junk", fmt).is_err());
+}
+
+#[test]
+fn test_datetime_parse_from_str_with_year_pivot() {
+    let ymdhms =
+        |y, m, d, h, n, s| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, n, s).unwrap();
+    let parse = |s, pivot| NaiveDateTime::parse_from_str_with_year_pivot(s, "%y%m%d%H%M%S", pivot);
+
+    assert_eq!(parse("491231235959", 1950), Ok(ymdhms(2049, 12, 31, 23, 59, 59)));
+    assert_eq!(parse("500101000000", 1950), Ok(ymdhms(1950, 1, 1, 0, 0, 0)));
+    assert_eq!(parse("500101000000", 1970), Ok(ymdhms(2050, 1, 1, 0, 0, 0)));
+    assert!(parse("500101246000", 1950).is_err());
 }
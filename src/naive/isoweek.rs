@@ -3,9 +3,11 @@
 
 //! ISO 8601 week.
 
-use core::fmt;
+use core::{fmt, str};
 
 use super::internals::{DateImpl, Of, YearFlags};
+use crate::format::{parse, Item, Numeric, Pad, ParseError, ParseResult, Parsed};
+use crate::{Datelike, Weekday};
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -144,8 +146,118 @@ impl fmt::Debug for IsoWeek {
     }
 }
 
+/// The `Display` output of the ISO week is the same as its [`Debug`](#impl-Debug) output,
+/// such as `2015-W36`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Datelike};
+///
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().iso_week().to_string(), "2024-W09");
+/// ```
+impl fmt::Display for IsoWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Parsing a `str` into an `IsoWeek` uses the format [`%G-W%V`](../format/strftime/index.html).
+///
+/// The week must exist in the given ISO year, so `2024-W53` and `2024-W54` are rejected.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Datelike, IsoWeek};
+///
+/// let week = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().iso_week();
+/// assert_eq!("2024-W09".parse::<IsoWeek>(), Ok(week));
+/// assert_eq!("2024-W9".parse::<IsoWeek>(), Ok(week));
+///
+/// assert!("2024-W54".parse::<IsoWeek>().is_err());
+/// assert!("2024-09".parse::<IsoWeek>().is_err());
+/// ```
+impl str::FromStr for IsoWeek {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<IsoWeek> {
+        const ITEMS: &[Item<'static>] = &[
+            Item::Numeric(Numeric::IsoYear, Pad::Zero),
+            Item::Literal("-W"),
+            Item::Numeric(Numeric::IsoWeek, Pad::Zero),
+        ];
+
+        let mut parsed = Parsed::new();
+        parse(&mut parsed, s, ITEMS.iter())?;
+        parsed.set_weekday(Weekday::Mon)?;
+        parsed.to_naive_date().map(|date| date.iso_week())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+mod serde {
+    use super::IsoWeek;
+    use core::fmt;
+    use serde::{de, ser};
+
+    impl ser::Serialize for IsoWeek {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ser::Serializer,
+        {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct IsoWeekVisitor;
+
+    impl<'de> de::Visitor<'de> for IsoWeekVisitor {
+        type Value = IsoWeek;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a formatted ISO week string")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            value.parse().map_err(E::custom)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for IsoWeek {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IsoWeekVisitor)
+        }
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        use crate::naive::NaiveDate;
+        use crate::Datelike;
+
+        let week = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().iso_week();
+        assert_eq!(serde_json::to_string(&week).unwrap(), r#""2024-W09""#);
+        assert_eq!(serde_json::from_str::<IsoWeek>(r#""2024-W09""#).unwrap(), week);
+
+        let encoded = bincode::serialize(&week).unwrap();
+        assert_eq!(bincode::deserialize::<IsoWeek>(&encoded).unwrap(), week);
+
+        assert!(serde_json::from_str::<IsoWeek>(r#""2024-W54""#).is_err());
+        assert!(serde_json::from_str::<IsoWeek>(r#""2024-W00""#).is_err());
+        assert!(serde_json::from_str::<IsoWeek>(r#"202409"#).is_err());
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::IsoWeek;
     use crate::naive::{internals, NaiveDate};
     use crate::Datelike;
 
@@ -164,4 +276,25 @@ mod tests {
         assert_eq!(maxweek.week0(), 0);
         assert_eq!(format!("{:?}", maxweek), NaiveDate::MAX.format("%G-W%V").to_string());
     }
+
+    #[test]
+    fn test_iso_week_display_from_str() {
+        let week = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().iso_week();
+        assert_eq!(week.to_string(), "2024-W09");
+        assert_eq!("2024-W09".parse::<IsoWeek>(), Ok(week));
+
+        let week = NaiveDate::from_ymd_opt(2020, 12, 31).unwrap().iso_week();
+        assert_eq!(week.to_string(), "2020-W53");
+        assert_eq!("2020-W53".parse::<IsoWeek>(), Ok(week));
+
+        let week = NaiveDate::from_ymd_opt(-1, 6, 1).unwrap().iso_week();
+        assert_eq!(week.to_string(), "-0001-W22");
+        assert_eq!("-0001-W22".parse::<IsoWeek>(), Ok(week));
+
+        assert!("2024-W53".parse::<IsoWeek>().is_err());
+        assert!("2024-W54".parse::<IsoWeek>().is_err());
+        assert!("2024-W00".parse::<IsoWeek>().is_err());
+        assert!("2024-w09".parse::<IsoWeek>().is_err());
+        assert!("2024-W09-1".parse::<IsoWeek>().is_err());
+    }
 }
@@ -9,13 +9,15 @@ pub(crate) mod datetime;
 mod internals;
 mod isoweek;
 mod time;
+mod value;
 
 #[allow(deprecated)]
-pub use self::date::{Days, NaiveDate, NaiveWeek, MAX_DATE, MIN_DATE};
+pub use self::date::{Days, NaiveDate, NaiveWeek, PartialDefaults, MAX_DATE, MIN_DATE};
 #[allow(deprecated)]
 pub use self::datetime::{NaiveDateTime, MAX_DATETIME, MIN_DATETIME};
 pub use self::isoweek::IsoWeek;
 pub use self::time::NaiveTime;
+pub use self::value::NaiveValue;
 
 #[cfg(feature = "__internal_bench")]
 #[doc(hidden)]
@@ -324,6 +324,9 @@ impl NaiveTime {
     ///
     /// The nanosecond part can exceed 1,000,000,000
     /// in order to represent the [leap second](#leap-second-handling).
+    /// A `nano` in `1_000_000_000..2_000_000_000` makes the time a leap second following `sec`.
+    /// A UTC leap second follows second 59, but other seconds are accepted as well,
+    /// for offsets with fractional minutes; such times are displayed as the next second.
     ///
     /// Returns `None` on invalid hour, minute, second and/or nanosecond.
     ///
@@ -565,10 +568,38 @@ impl NaiveTime {
         (time, -rhs) // safe to negate, rhs is within +/- (2^63 / 1000)
     }
 
+    /// Adds given `TimeDelta` to the current time, wrapping around midnight.
+    ///
+    /// **The number of whole days in the result is silently discarded**, so for example
+    /// 23:00 plus 2 hours is 01:00. This is the same as the `+` operator, but makes the
+    /// wrapping explicit at the call site. Use
+    /// [`overflowing_add_signed`](#method.overflowing_add_signed) to also get the discarded
+    /// number of seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeDelta, NaiveTime};
+    ///
+    /// let from_hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    ///
+    /// assert_eq!(from_hms(23, 0, 0).wrapping_add(TimeDelta::hours(2)), from_hms(1, 0, 0));
+    /// assert_eq!(from_hms(3, 4, 5).wrapping_add(TimeDelta::hours(-7)), from_hms(20, 4, 5));
+    /// assert_eq!(from_hms(3, 4, 5).wrapping_add(TimeDelta::days(3)), from_hms(3, 4, 5));
+    /// ```
+    #[inline]
+    pub fn wrapping_add(&self, rhs: TimeDelta) -> NaiveTime {
+        self.overflowing_add_signed(rhs).0
+    }
+
     /// Subtracts another `NaiveTime` from the current time.
     /// Returns a `TimeDelta` within +/- 1 day.
     /// This does not overflow or underflow at all.
     ///
+    /// Both times are treated as times within the same day and any day wrap is ignored,
+    /// so `08:30 - 10:00` is `-1h30m` rather than `+22h30m`.
+    /// Use [`NaiveDateTime`](./struct.NaiveDateTime.html) if the day boundary matters.
+    ///
     /// As a part of Chrono's [leap second handling](#leap-second-handling),
     /// the subtraction assumes that **there is no leap second ever**,
     /// except when any of the `NaiveTime`s themselves represents a leap second
@@ -736,6 +767,83 @@ impl NaiveTime {
         self.format_with_items(StrftimeItems::new(fmt))
     }
 
+    /// Encodes the time as 8 bytes: the number of seconds since midnight followed by the
+    /// nanoseconds, each as a little-endian `u32`.
+    ///
+    /// A leap second is stored as 1,000,000,000 or more nanoseconds, so it is preserved.
+    /// This is a stable binary format for compact storage; see [`from_bytes`](#method.from_bytes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_nano_opt(23, 56, 4, 12_345_678).unwrap();
+    /// assert_eq!(t.to_bytes(), [0x94, 0x50, 0x01, 0x00, 0x4e, 0x61, 0xbc, 0x00]);
+    /// assert_eq!(NaiveTime::from_bytes(t.to_bytes()), Some(t));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let secs = self.secs.to_le_bytes();
+        let frac = self.frac.to_le_bytes();
+        [secs[0], secs[1], secs[2], secs[3], frac[0], frac[1], frac[2], frac[3]]
+    }
+
+    /// Decodes a time encoded with [`to_bytes`](#method.to_bytes).
+    ///
+    /// Returns `None` if the seconds or nanoseconds are out of range, as in
+    /// [`from_num_seconds_from_midnight_opt`](#method.from_num_seconds_from_midnight_opt).
+    pub fn from_bytes(bytes: [u8; 8]) -> Option<NaiveTime> {
+        let secs = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let frac = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, frac)
+    }
+
+    /// Returns the number of nanoseconds since midnight, including the fractional second.
+    ///
+    /// A [leap second](#leap-second-handling) counts as part of the following second,
+    /// so `12:34:60.5` gives the same number as `12:35:00.5`. A leap second at the end of the day
+    /// gives a number from 86,400,000,000,000, which
+    /// [`from_nanos_from_midnight`](#method.from_nanos_from_midnight) decodes back to it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let t = NaiveTime::from_hms_milli_opt(1, 1, 1, 500).unwrap();
+    /// assert_eq!(t.as_nanos_from_midnight(), 3_661_500_000_000);
+    /// assert_eq!(NaiveTime::from_nanos_from_midnight(3_661_500_000_000), Some(t));
+    /// ```
+    pub fn as_nanos_from_midnight(&self) -> u64 {
+        u64::from(self.secs) * 1_000_000_000 + u64::from(self.frac)
+    }
+
+    /// Makes a new `NaiveTime` from the number of nanoseconds since midnight.
+    ///
+    /// Numbers from 86,400,000,000,000 up to one second later represent a leap second after
+    /// `23:59:59`. Returns `None` for larger numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveTime;
+    ///
+    /// let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_250).unwrap();
+    /// assert_eq!(NaiveTime::from_nanos_from_midnight(86_400_250_000_000), Some(leap));
+    /// assert_eq!(NaiveTime::from_nanos_from_midnight(86_401_000_000_000), None);
+    /// ```
+    pub fn from_nanos_from_midnight(nanos: u64) -> Option<NaiveTime> {
+        const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+        let (secs, frac) = if nanos < NANOS_PER_DAY {
+            (nanos / 1_000_000_000, nanos % 1_000_000_000)
+        } else if nanos < NANOS_PER_DAY + 1_000_000_000 {
+            (86_399, nanos - NANOS_PER_DAY + 1_000_000_000)
+        } else {
+            return None;
+        };
+        NaiveTime::from_num_seconds_from_midnight_opt(secs as u32, frac as u32)
+    }
+
     /// Returns a triple of the hour, minute and second numbers.
     fn hms(&self) -> (u32, u32, u32) {
         let (mins, sec) = div_mod_floor(self.secs, 60);
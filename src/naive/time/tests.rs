@@ -1,4 +1,4 @@
-use std::u32;
+use std::{u32, u64};
 
 use super::NaiveTime;
 use crate::{TimeDelta, Timelike};
@@ -45,6 +45,23 @@ fn test_time_from_hms_micro() {
     assert_eq!(NaiveTime::from_hms_micro_opt(3, 5, 7, u32::MAX), None);
 }
 
+#[test]
+fn test_time_from_hms_nano() {
+    let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+    assert_eq!(leap.second(), 59);
+    assert_eq!(leap.nanosecond(), 1_500_000_000);
+    assert_eq!(leap.to_string(), "23:59:60.500");
+
+    // a leap second after a second other than 59 is accepted, but reads as the next second
+    let odd = NaiveTime::from_hms_nano_opt(12, 0, 30, 1_000_000_000).unwrap();
+    assert_eq!(odd.second(), 30);
+    assert_eq!(odd.to_string(), "12:00:31");
+
+    assert_eq!(NaiveTime::from_hms_nano_opt(23, 59, 59, 2_000_000_000), None);
+    assert_eq!(NaiveTime::from_hms_nano_opt(23, 59, 60, 0), None);
+    assert_eq!(NaiveTime::from_hms_nano_opt(12, 0, 30, u32::MAX), None);
+}
+
 #[test]
 fn test_time_hms() {
     assert_eq!(NaiveTime::from_hms_opt(3, 5, 7).unwrap().hour(), 3);
@@ -89,6 +106,7 @@ fn test_time_add() {
     macro_rules! check {
         ($lhs:expr, $rhs:expr, $sum:expr) => {{
             assert_eq!($lhs + $rhs, $sum);
+            assert_eq!($lhs.wrapping_add($rhs), $sum);
             //assert_eq!($rhs + $lhs, $sum);
         }};
     }
@@ -108,6 +126,7 @@ fn test_time_add() {
     check!(hmsm(3, 5, 7, 900), TimeDelta::days(12345), hmsm(3, 5, 7, 900));
     check!(hmsm(3, 5, 7, 1_300), TimeDelta::days(1), hmsm(3, 5, 7, 300));
     check!(hmsm(3, 5, 7, 1_300), TimeDelta::days(-1), hmsm(3, 5, 8, 300));
+    check!(hmsm(23, 0, 0, 0), TimeDelta::hours(2), hmsm(1, 0, 0, 0));
 
     // regression tests for #37
     check!(hmsm(0, 0, 0, 0), TimeDelta::milliseconds(-990), hmsm(23, 59, 59, 10));
@@ -176,6 +195,8 @@ fn test_time_sub() {
 
     check!(hmsm(3, 5, 7, 900), hmsm(3, 5, 7, 900), TimeDelta::zero());
     check!(hmsm(3, 5, 7, 900), hmsm(3, 5, 7, 600), TimeDelta::milliseconds(300));
+    check!(hmsm(10, 0, 0, 0), hmsm(8, 30, 0, 0), TimeDelta::minutes(90));
+    check!(hmsm(23, 0, 0, 0), hmsm(1, 0, 0, 0), TimeDelta::hours(22)); // no day wrap
     check!(hmsm(3, 5, 7, 200), hmsm(2, 4, 6, 200), TimeDelta::seconds(3600 + 60 + 1));
     check!(
         hmsm(3, 5, 7, 200),
@@ -284,6 +305,35 @@ fn test_time_parse_from_str() {
     assert!(NaiveTime::parse_from_str("12:3456", "%H:%M:%S").is_err());
 }
 
+#[test]
+fn test_time_parse_from_str_12_hour() {
+    let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+    // padded and unpadded hours are both accepted, whatever the padding modifier
+    assert_eq!(NaiveTime::parse_from_str("9:05 am", "%-I:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("9:05 am", "%I:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("09:05 AM", "%I:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("09:05 AM", "%-I:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("9:05 am", "%l:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str(" 9:05 am", "%l:%M %p"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("09:05 am", "%l:%M %p"), Ok(hm(9, 5)));
+
+    // am/pm is case-insensitive for both `%p` and `%P`
+    assert_eq!(NaiveTime::parse_from_str("9:05 am", "%-I:%M %P"), Ok(hm(9, 5)));
+    assert_eq!(NaiveTime::parse_from_str("9:05 PM", "%-I:%M %P"), Ok(hm(21, 5)));
+    assert_eq!(NaiveTime::parse_from_str("9:05 pm", "%-I:%M %p"), Ok(hm(21, 5)));
+
+    // noon and midnight
+    assert_eq!(NaiveTime::parse_from_str("12:00 pm", "%I:%M %p"), Ok(hm(12, 0)));
+    assert_eq!(NaiveTime::parse_from_str("12:00 am", "%I:%M %p"), Ok(hm(0, 0)));
+    assert_eq!(NaiveTime::parse_from_str("12:00 PM", "%l:%M %P"), Ok(hm(12, 0)));
+    assert_eq!(NaiveTime::parse_from_str("12:00 AM", "%l:%M %P"), Ok(hm(0, 0)));
+
+    // hours outside of 1--12 are rejected
+    assert!(NaiveTime::parse_from_str("0:05 am", "%-I:%M %p").is_err());
+    assert!(NaiveTime::parse_from_str("13:05 pm", "%-I:%M %p").is_err());
+}
+
 #[test]
 fn test_time_format() {
     let t = NaiveTime::from_hms_nano_opt(3, 5, 7, 98765432).unwrap();
@@ -315,3 +365,53 @@ fn test_time_format() {
         "23:59:60"
     );
 }
+
+#[test]
+fn test_time_nanos_from_midnight() {
+    let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+    for &(time, nanos) in &[
+        (NaiveTime::MIN, 0),
+        (hmsn(1, 1, 1, 500_000_000), 3_661_500_000_000),
+        (hmsn(0, 0, 0, 1), 1),
+        (NaiveTime::MAX, 86_399_999_999_999),
+        (hmsn(23, 59, 59, 1_000_000_000), 86_400_000_000_000), // leap second
+        (hmsn(23, 59, 59, 1_999_999_999), 86_400_999_999_999),
+    ] {
+        assert_eq!(time.as_nanos_from_midnight(), nanos);
+        assert_eq!(NaiveTime::from_nanos_from_midnight(nanos), Some(time));
+    }
+
+    // other leap seconds share their encoding with the following second
+    let leap = hmsn(12, 34, 59, 1_500_000_000);
+    assert_eq!(
+        leap.as_nanos_from_midnight(),
+        hmsn(12, 35, 0, 500_000_000).as_nanos_from_midnight()
+    );
+    assert_eq!(
+        NaiveTime::from_nanos_from_midnight(leap.as_nanos_from_midnight()),
+        Some(hmsn(12, 35, 0, 500_000_000))
+    );
+
+    assert_eq!(NaiveTime::from_nanos_from_midnight(86_401_000_000_000), None);
+    assert_eq!(NaiveTime::from_nanos_from_midnight(u64::MAX), None);
+}
+
+#[test]
+fn test_time_bytes() {
+    let hmsn = |h, m, s, n| NaiveTime::from_hms_nano_opt(h, m, s, n).unwrap();
+    for &time in &[
+        NaiveTime::MIN,
+        hmsn(0, 0, 0, 1),
+        hmsn(12, 34, 56, 789_012_345),
+        hmsn(23, 59, 59, 999_999_999),
+        hmsn(23, 59, 59, 1_999_999_999), // leap second
+        NaiveTime::MAX,
+    ] {
+        assert_eq!(NaiveTime::from_bytes(time.to_bytes()), Some(time));
+    }
+    assert_eq!(hmsn(0, 0, 1, 2).to_bytes(), [1, 0, 0, 0, 2, 0, 0, 0]);
+
+    assert_eq!(NaiveTime::from_bytes([0x80, 0x51, 0x01, 0, 0, 0, 0, 0]), None); // 86,400 seconds
+    assert_eq!(NaiveTime::from_bytes([0, 0, 0, 0, 0, 0x94, 0x35, 0x77]), None); // 2,000,000,000 ns
+    assert_eq!(NaiveTime::from_bytes([0xff; 8]), None);
+}
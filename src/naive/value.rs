@@ -0,0 +1,134 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A date, a time or a date and time, whichever a string holds.
+
+use core::{fmt, str};
+
+use super::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::format::{ParseError, ParseErrorKind, ParseResult};
+
+/// Either a [`NaiveDate`], a [`NaiveTime`] or a [`NaiveDateTime`].
+///
+/// This is useful to store a value whose kind is only known at run time,
+/// such as a setting that may be given as `2024-02-29`, `23:16:09` or `2024-02-29T23:16:09`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveValue};
+///
+/// let value: NaiveValue = "2024-02-29".parse().unwrap();
+/// assert_eq!(value, NaiveValue::Date(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()));
+/// assert_eq!(value.to_string(), "2024-02-29");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NaiveValue {
+    /// A calendar date.
+    Date(NaiveDate),
+    /// A time of day.
+    Time(NaiveTime),
+    /// A date and a time of day.
+    DateTime(NaiveDateTime),
+}
+
+/// Parsing a `str` into a `NaiveValue` tries the `FromStr` implementations of
+/// [`NaiveDate`], [`NaiveTime`] and [`NaiveDateTime`], in that order,
+/// and returns the first success.
+///
+/// If every parser fails, the error of the first parser that recognized the shape of the input
+/// but rejected its value (such as `2023-02-29`) is returned; otherwise the error of the
+/// `NaiveDateTime` parser.
+///
+/// # Example
+///
+/// ```
+/// use chrono::format::ParseErrorKind;
+/// use chrono::{NaiveDate, NaiveTime, NaiveValue};
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+/// let time = NaiveTime::from_hms_opt(23, 16, 9).unwrap();
+///
+/// assert_eq!("23:16:09".parse::<NaiveValue>(), Ok(NaiveValue::Time(time)));
+/// assert_eq!("2024-02-29T23:16:09".parse::<NaiveValue>(), Ok(NaiveValue::DateTime(date.and_time(time))));
+/// assert_eq!("2023-02-29".parse::<NaiveValue>().unwrap_err().kind(), ParseErrorKind::OutOfRange);
+/// ```
+impl str::FromStr for NaiveValue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<NaiveValue> {
+        let date_err = match s.parse() {
+            Ok(date) => return Ok(NaiveValue::Date(date)),
+            Err(e) => e,
+        };
+        let time_err = match s.parse() {
+            Ok(time) => return Ok(NaiveValue::Time(time)),
+            Err(e) => e,
+        };
+        let datetime_err = match s.parse() {
+            Ok(datetime) => return Ok(NaiveValue::DateTime(datetime)),
+            Err(e) => e,
+        };
+
+        let rejects_value = |e: &ParseError| match e.kind() {
+            ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => true,
+            _ => false,
+        };
+        Err([date_err, time_err].iter().cloned().find(rejects_value).unwrap_or(datetime_err))
+    }
+}
+
+/// The `Display` output is that of the held value.
+///
+/// Note that a `NaiveDateTime` displays with a space between the date and the time,
+/// which `FromStr` does not accept; the `Debug` output of a `NaiveDateTime` can be parsed back.
+impl fmt::Display for NaiveValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NaiveValue::Date(ref date) => date.fmt(f),
+            NaiveValue::Time(ref time) => time.fmt(f),
+            NaiveValue::DateTime(ref datetime) => datetime.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NaiveValue;
+    use crate::format::ParseErrorKind;
+    use crate::naive::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn test_naive_value_from_str() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time = NaiveTime::from_hms_opt(23, 16, 9).unwrap();
+
+        assert_eq!("2024-02-29".parse::<NaiveValue>(), Ok(NaiveValue::Date(date)));
+        assert_eq!("23:16:09".parse::<NaiveValue>(), Ok(NaiveValue::Time(time)));
+        assert_eq!(
+            "2024-02-29T23:16:09".parse::<NaiveValue>(),
+            Ok(NaiveValue::DateTime(date.and_time(time)))
+        );
+
+        let err = |s: &str| s.parse::<NaiveValue>().unwrap_err().kind();
+        assert_eq!(err("2023-02-29"), ParseErrorKind::OutOfRange);
+        assert_eq!(err("24:61:00"), ParseErrorKind::OutOfRange);
+        assert!("".parse::<NaiveValue>().is_err());
+        assert!("yesterday".parse::<NaiveValue>().is_err());
+    }
+
+    #[test]
+    fn test_naive_value_display() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let time = NaiveTime::from_hms_opt(23, 16, 9).unwrap();
+
+        assert_eq!(NaiveValue::Date(date).to_string(), "2024-02-29");
+        assert_eq!(NaiveValue::Time(time).to_string(), "23:16:09");
+        assert_eq!(NaiveValue::DateTime(date.and_time(time)).to_string(), "2024-02-29 23:16:09");
+
+        // like `NaiveDateTime`, the `Display` output of a date and time is not parsed back
+        for value in &[NaiveValue::Date(date), NaiveValue::Time(time)] {
+            assert_eq!(value.to_string().parse::<NaiveValue>(), Ok(*value));
+        }
+    }
+}
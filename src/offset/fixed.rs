@@ -3,6 +3,13 @@
 
 //! The time zone which has a fixed offset from UTC.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
 use core::fmt;
 use core::ops::{Add, Sub};
 
@@ -10,6 +17,8 @@ use num_integer::div_mod_floor;
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
 
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use super::Utc;
 use super::{LocalResult, Offset, TimeZone};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::time_delta::TimeDelta;
@@ -32,10 +41,27 @@ impl FixedOffset {
     /// Makes a new `FixedOffset` for the Eastern Hemisphere with given timezone difference.
     /// The negative `secs` means the Western Hemisphere.
     ///
-    /// Panics on the out-of-bound `secs`.
-    #[deprecated(since = "0.4.23", note = "use `east_opt()` instead")]
-    pub fn east(secs: i32) -> FixedOffset {
-        FixedOffset::east_opt(secs).expect("FixedOffset::east out of bounds")
+    /// This is a `const fn` meant for defining offset constants;
+    /// use [`east_opt`](#method.east_opt) for offsets only known at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics on the out-of-bound `secs`, which is a compile error in a const context.
+    /// As `panic!` is not available in a `const fn` on the minimum supported Rust version,
+    /// the panic is reported as an index out of bounds, with the message
+    /// `index out of bounds: the len is 1 but the index is 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// const KST: FixedOffset = FixedOffset::east(9 * 3600);
+    /// assert_eq!(KST, FixedOffset::east_opt(9 * 3600).unwrap());
+    /// ```
+    pub const fn east(secs: i32) -> FixedOffset {
+        FixedOffset::assert_in_bounds(secs);
+        FixedOffset { local_minus_utc: secs }
     }
 
     /// Makes a new `FixedOffset` for the Eastern Hemisphere with given timezone difference.
@@ -63,10 +89,34 @@ impl FixedOffset {
     /// Makes a new `FixedOffset` for the Western Hemisphere with given timezone difference.
     /// The negative `secs` means the Eastern Hemisphere.
     ///
-    /// Panics on the out-of-bound `secs`.
-    #[deprecated(since = "0.4.23", note = "use `west_opt()` instead")]
-    pub fn west(secs: i32) -> FixedOffset {
-        FixedOffset::west_opt(secs).expect("FixedOffset::west out of bounds")
+    /// Like [`east`](#method.east) this is a `const fn` meant for defining offset constants;
+    /// use [`west_opt`](#method.west_opt) for offsets only known at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics on the out-of-bound `secs` in the same way as [`east`](#method.east), with the
+    /// message `index out of bounds: the len is 1 but the index is 1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// const NST: FixedOffset = FixedOffset::west(3 * 3600 + 30 * 60);
+    /// assert_eq!(NST, FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap());
+    /// ```
+    pub const fn west(secs: i32) -> FixedOffset {
+        // check before negating, as `-i32::MIN` overflows
+        FixedOffset::assert_in_bounds(secs);
+        FixedOffset { local_minus_utc: -secs }
+    }
+
+    /// Panics unless `-86_400 < secs < 86_400`.
+    const fn assert_in_bounds(secs: i32) {
+        // `if` and `panic!` are not available in a `const fn` on our MSRV,
+        // so an out-of-bounds index is used to panic instead.
+        #[allow(clippy::no_effect)]
+        [()][((secs <= -86_400) | (secs >= 86_400)) as usize];
     }
 
     /// Makes a new `FixedOffset` for the Western Hemisphere with given timezone difference.
@@ -102,6 +152,92 @@ impl FixedOffset {
     pub fn utc_minus_local(&self) -> i32 {
         -self.local_minus_utc
     }
+
+    /// Formats the offset in the given [`OffsetStyle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    /// use chrono::offset::OffsetStyle;
+    ///
+    /// let offset = FixedOffset::east_opt(9 * 3600).unwrap();
+    /// assert_eq!(offset.to_string_named(OffsetStyle::Iso), "+09:00");
+    /// assert_eq!(offset.to_string_named(OffsetStyle::Gmt), "GMT+09:00");
+    /// assert_eq!(offset.to_string_named(OffsetStyle::Short), "UTC+9");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn to_string_named(&self, style: OffsetStyle) -> String {
+        StyledOffset { offset: *self, style }.to_string()
+    }
+
+    /// Writes the offset as `+HH:MM` into `buf` and returns it as a string slice.
+    ///
+    /// This is the format of `%:z`, written without allocating. Like `%:z`, it leaves out any
+    /// seconds of the offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::FixedOffset;
+    ///
+    /// let mut buf = [0; 6];
+    /// let offset = FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap();
+    /// assert_eq!(offset.write_iso(&mut buf), "-05:30");
+    /// ```
+    pub fn write_iso<'a>(&self, buf: &'a mut [u8; 6]) -> &'a str {
+        let (sign, offset) = if self.local_minus_utc < 0 {
+            (b'-', -self.local_minus_utc)
+        } else {
+            (b'+', self.local_minus_utc)
+        };
+        let (hour, min) = ((offset / 3600) as u8, (offset / 60 % 60) as u8);
+        *buf = [sign, b'0' + hour / 10, b'0' + hour % 10, b':', b'0' + min / 10, b'0' + min % 10];
+        core::str::from_utf8(buf).expect("the offset is written in ASCII")
+    }
+}
+
+/// The style used by [`FixedOffset::to_string_named`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum OffsetStyle {
+    /// ISO 8601 offset, the same as the `Display` implementation: `+09:00`, `-05:30`.
+    Iso,
+    /// ISO 8601 offset prefixed with `GMT`: `GMT+09:00`, `GMT-05:30`.
+    Gmt,
+    /// `UTC` followed by the hours without padding, and the minutes only if not zero:
+    /// `UTC+9`, `UTC-5:30`.
+    Short,
+}
+
+/// Formats a `FixedOffset` in a given `OffsetStyle`.
+#[cfg(any(feature = "alloc", feature = "std", test))]
+struct StyledOffset {
+    offset: FixedOffset,
+    style: OffsetStyle,
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl fmt::Display for StyledOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.style {
+            OffsetStyle::Iso => fmt::Display::fmt(&self.offset, f),
+            OffsetStyle::Gmt => write!(f, "GMT{}", self.offset),
+            OffsetStyle::Short => {
+                let offset = self.offset.local_minus_utc;
+                let (sign, offset) = if offset < 0 { ('-', -offset) } else { ('+', offset) };
+                let (mins, sec) = div_mod_floor(offset, 60);
+                let (hour, min) = div_mod_floor(mins, 60);
+                if sec != 0 {
+                    write!(f, "UTC{}{}:{:02}:{:02}", sign, hour, min, sec)
+                } else if min != 0 {
+                    write!(f, "UTC{}{}:{:02}", sign, hour, min)
+                } else {
+                    write!(f, "UTC{}{}", sign, hour)
+                }
+            }
+        }
+    }
 }
 
 impl TimeZone for FixedOffset {
@@ -124,6 +260,15 @@ impl TimeZone for FixedOffset {
     fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> FixedOffset {
         *self
     }
+
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn transitions_between(
+        &self,
+        _start: DateTime<Utc>,
+        _end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, FixedOffset, FixedOffset)> {
+        Vec::new()
+    }
 }
 
 impl Offset for FixedOffset {
@@ -223,9 +368,70 @@ impl<Tz: TimeZone> Sub<FixedOffset> for DateTime<Tz> {
 
 #[cfg(test)]
 mod tests {
-    use super::FixedOffset;
+    use super::{FixedOffset, OffsetStyle};
     use crate::offset::TimeZone;
 
+    const KST: FixedOffset = FixedOffset::east(9 * 3600);
+    const NST: FixedOffset = FixedOffset::west(3 * 3600 + 30 * 60);
+
+    #[test]
+    fn test_write_iso() {
+        let mut buf = [0; 6];
+        assert_eq!(KST.write_iso(&mut buf), "+09:00");
+        assert_eq!(NST.write_iso(&mut buf), "-03:30");
+        assert_eq!(FixedOffset::east_opt(0).unwrap().write_iso(&mut buf), "+00:00");
+        assert_eq!(FixedOffset::east_opt(86_399).unwrap().write_iso(&mut buf), "+23:59");
+        assert_eq!(FixedOffset::west_opt(86_399).unwrap().write_iso(&mut buf), "-23:59");
+        assert_eq!(FixedOffset::west_opt(59).unwrap().write_iso(&mut buf), "-00:00");
+
+        // the same as `%:z`
+        let dt = NST.ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(NST.write_iso(&mut buf), dt.format("%:z").to_string());
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        assert_eq!(KST, FixedOffset::east_opt(9 * 3600).unwrap());
+        assert_eq!(NST, FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap());
+        assert_eq!(FixedOffset::east(86_399).local_minus_utc(), 86_399);
+        assert_eq!(FixedOffset::west(86_399).local_minus_utc(), -86_399);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn test_const_constructor_out_of_bounds() {
+        FixedOffset::east(86_400);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn test_const_constructor_west_min() {
+        // must not overflow on negation before the bounds check
+        FixedOffset::west(i32::min_value());
+    }
+
+    #[test]
+    fn test_to_string_named() {
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(kst.to_string_named(OffsetStyle::Iso), "+09:00");
+        assert_eq!(kst.to_string_named(OffsetStyle::Gmt), "GMT+09:00");
+        assert_eq!(kst.to_string_named(OffsetStyle::Short), "UTC+9");
+        assert_eq!(kst.to_string_named(OffsetStyle::Iso), kst.to_string());
+
+        let nst = FixedOffset::west_opt(3 * 3600 + 30 * 60).unwrap();
+        assert_eq!(nst.to_string_named(OffsetStyle::Iso), "-03:30");
+        assert_eq!(nst.to_string_named(OffsetStyle::Gmt), "GMT-03:30");
+        assert_eq!(nst.to_string_named(OffsetStyle::Short), "UTC-3:30");
+
+        let utc = FixedOffset::east_opt(0).unwrap();
+        assert_eq!(utc.to_string_named(OffsetStyle::Gmt), "GMT+00:00");
+        assert_eq!(utc.to_string_named(OffsetStyle::Short), "UTC+0");
+
+        let odd = FixedOffset::west_opt(12 * 3600 + 5).unwrap();
+        assert_eq!(odd.to_string_named(OffsetStyle::Iso), "-12:00:05");
+        assert_eq!(odd.to_string_named(OffsetStyle::Short), "UTC-12:00:05");
+    }
+
     #[test]
     fn test_date_extreme_offset() {
         // starting from 0.3 we don't have an offset exceeding one day.
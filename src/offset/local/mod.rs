@@ -5,9 +5,11 @@
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize, Serialize};
+#[cfg(unix)]
+use std::borrow::Cow;
 
 use super::fixed::FixedOffset;
-use super::{LocalResult, TimeZone};
+use super::{LocalResult, TimeZone, Utc};
 use crate::naive::{NaiveDate, NaiveDateTime};
 use crate::{Date, DateTime};
 
@@ -61,30 +63,71 @@ impl Local {
     }
 
     /// Returns a `DateTime` which corresponds to the current date and time.
+    ///
+    /// Panics if the offset of the local time zone can not be determined,
+    /// see [`Local::now_opt`] for a non-panicking alternative.
+    pub fn now() -> DateTime<Local> {
+        Local::now_opt().expect("unable to determine the local time zone offset")
+    }
+
+    /// Returns a `DateTime` which corresponds to the current date and time.
+    ///
+    /// Returns `None` if the offset of the local time zone can not be determined,
+    /// for example because the time zone data on the system is unusable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Local;
+    ///
+    /// match Local::now_opt() {
+    ///     Some(now) => println!("{}", now),
+    ///     None => eprintln!("no usable local time zone"),
+    /// }
+    /// ```
     #[cfg(not(all(
         target_arch = "wasm32",
         feature = "wasmbind",
         not(any(target_os = "emscripten", target_os = "wasi"))
     )))]
-    pub fn now() -> DateTime<Local> {
-        inner::now()
+    pub fn now_opt() -> Option<DateTime<Local>> {
+        inner::now_opt()
     }
 
     /// Returns a `DateTime` which corresponds to the current date and time.
+    ///
+    /// Returns `None` if the offset of the local time zone can not be determined.
     #[cfg(all(
         target_arch = "wasm32",
         feature = "wasmbind",
         not(any(target_os = "emscripten", target_os = "wasi"))
     ))]
-    pub fn now() -> DateTime<Local> {
+    pub fn now_opt() -> Option<DateTime<Local>> {
         use super::Utc;
         let now: DateTime<Utc> = super::Utc::now();
 
         // Workaround missing timezone logic in `time` crate
         let offset =
-            FixedOffset::west_opt((js_sys::Date::new_0().get_timezone_offset() as i32) * 60)
-                .unwrap();
-        DateTime::from_utc(now.naive_utc(), offset)
+            FixedOffset::west_opt((js_sys::Date::new_0().get_timezone_offset() as i32) * 60)?;
+        Some(DateTime::from_utc(now.naive_utc(), offset))
+    }
+
+    /// Returns the offset of the local time zone at the given UTC instant.
+    ///
+    /// This is the same offset as `utc.with_timezone(&Local).offset()`,
+    /// without constructing the intermediate `DateTime<Local>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Local, Utc};
+    ///
+    /// let now = Utc::now();
+    /// let offset = Local::offset_at_utc(&now);
+    /// assert_eq!(offset, *now.with_timezone(&Local).offset());
+    /// ```
+    pub fn offset_at_utc(utc: &DateTime<Utc>) -> FixedOffset {
+        Local.offset_from_utc_datetime(&utc.naive_utc())
     }
 }
 
@@ -112,6 +155,30 @@ impl TimeZone for Local {
         *self.from_utc_datetime(utc).offset()
     }
 
+    /// Lists the transitions of the system time zone data, without sampling.
+    #[cfg(unix)]
+    fn transitions_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, FixedOffset, FixedOffset)> {
+        inner::transitions_between(&start.naive_utc(), &end.naive_utc())
+            .into_iter()
+            .map(|(utc, before, after)| (DateTime::from_utc(utc, Utc), before, after))
+            .collect()
+    }
+
+    /// Returns the abbreviation of the system time zone data, such as `JST`.
+    ///
+    /// Falls back to the offset, such as `+09:00`, if the data does not name it.
+    #[cfg(unix)]
+    fn offset_name(&self, utc: &NaiveDateTime) -> Cow<'static, str> {
+        match inner::offset_name(utc) {
+            Some(name) => Cow::Owned(name),
+            None => Cow::Owned(self.offset_from_utc_datetime(utc).to_string()),
+        }
+    }
+
     // override them for avoiding redundant works
     fn from_local_date(&self, local: &NaiveDate) -> LocalResult<Date<Local>> {
         // this sounds very strange, but required for keeping `TimeZone::ymd` sane.
@@ -178,7 +245,7 @@ impl TimeZone for Local {
 #[cfg(test)]
 mod tests {
     use super::Local;
-    use crate::offset::TimeZone;
+    use crate::offset::{Offset, TimeZone, Utc};
     use crate::{Datelike, TimeDelta};
 
     #[test]
@@ -224,6 +291,60 @@ mod tests {
         assert_eq!(distant_future, from_utc);
     }
 
+    #[test]
+    fn test_now_opt() {
+        let now = Local::now_opt().expect("local time zone should be available");
+        let from_utc = Local.from_utc_datetime(&now.naive_utc());
+        assert_eq!(now.offset().local_minus_utc(), from_utc.offset().local_minus_utc());
+    }
+
+    #[test]
+    fn test_offset_at_utc() {
+        let now = Utc::now();
+        for &days in &[0, 90, 180, 270, -250 * 31, 250 * 31] {
+            let utc = now + TimeDelta::days(days);
+            assert_eq!(Local::offset_at_utc(&utc), utc.with_timezone(&Local).offset().fix());
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_offset_name() {
+        // the name comes from the time zone data, e.g. `UTC` rather than `+00:00`
+        for &(y, m) in &[(2022, 1), (2022, 7)] {
+            let dt = Local.ymd_opt(y, m, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+            let name = Local.offset_name(&dt.naive_utc());
+            assert!((3..=7).contains(&name.len()), "unexpected name {:?}", name);
+            assert!(!name.contains(':'), "unexpected name {:?}", name);
+            assert_eq!(dt.format("%Z").to_string(), name);
+        }
+    }
+
+    #[test]
+    fn test_transitions_between() {
+        let start = Utc.ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let end = Utc.ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let transitions = Local.transitions_between(start, end);
+
+        let mut offset = Local::offset_at_utc(&start);
+        let mut remaining = transitions.iter().peekable();
+        let mut day = start;
+        while day < end {
+            while let Some(&&(at, before, after)) = remaining.peek() {
+                if at > day {
+                    break;
+                }
+                assert_eq!(before, offset);
+                assert_eq!(Local::offset_at_utc(&(at - TimeDelta::seconds(1))), before);
+                assert_eq!(Local::offset_at_utc(&at), after);
+                offset = after;
+                remaining.next();
+            }
+            assert_eq!(Local::offset_at_utc(&day), offset);
+            day += TimeDelta::days(1);
+        }
+    }
+
     #[test]
     fn test_local_date_sanity_check() {
         // issue #27
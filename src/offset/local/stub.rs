@@ -13,8 +13,8 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use super::{FixedOffset, Local};
 use crate::{DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
-pub(super) fn now() -> DateTime<Local> {
-    tm_to_datetime(Timespec::now().local())
+pub(super) fn now_opt() -> Option<DateTime<Local>> {
+    Some(tm_to_datetime(Timespec::now().local()))
 }
 
 /// Converts a local `NaiveDateTime` to the `time::Timespec`.
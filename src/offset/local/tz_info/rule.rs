@@ -94,6 +94,16 @@ impl TransitionRule {
             }
         }
     }
+
+    /// Unix times in seconds at which the rule starts and ends Daylight Saving Time in the specified year, if it alternates
+    pub(super) fn transition_times(&self, year: i32) -> Option<(i64, i64)> {
+        match self {
+            TransitionRule::Fixed(_) => None,
+            TransitionRule::Alternate(alternate_time) => {
+                Some(alternate_time.transition_times(year))
+            }
+        }
+    }
 }
 
 impl From<LocalTimeType> for TransitionRule {
@@ -145,6 +155,18 @@ impl AlternateTime {
         Ok(Self { std, dst, dst_start, dst_start_time, dst_end, dst_end_time })
     }
 
+    /// Unix times in seconds at which Daylight Saving Time starts and ends in the specified year
+    fn transition_times(&self, year: i32) -> (i64, i64) {
+        // Overflow is not possible
+        let dst_start_time_in_utc = self.dst_start_time as i64 - self.std.ut_offset as i64;
+        let dst_end_time_in_utc = self.dst_end_time as i64 - self.dst.ut_offset as i64;
+
+        (
+            self.dst_start.unix_time(year, dst_start_time_in_utc),
+            self.dst_end.unix_time(year, dst_end_time_in_utc),
+        )
+    }
+
     /// Find the local time type associated to the alternate transition rule at the specified Unix time in seconds
     fn find_local_time_type(&self, unix_time: i64) -> Result<&LocalTimeType, Error> {
         // Overflow is not possible
@@ -5,7 +5,7 @@ use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 use std::{cmp::Ordering, fmt, str};
 
-use super::rule::{AlternateTime, TransitionRule};
+use super::rule::{AlternateTime, TransitionRule, UtcDateTime};
 use super::{parser, Error, DAYS_PER_WEEK, SECONDS_PER_DAY};
 
 /// Time zone
@@ -118,6 +118,15 @@ impl TimeZone {
         self.as_ref().find_local_time_type(unix_time)
     }
 
+    /// Find the changes of UTC offset from `start` (inclusive) to `end` (exclusive), in Unix time in seconds
+    pub(crate) fn find_offset_changes(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<(i64, i32, i32)>, Error> {
+        self.as_ref().find_offset_changes(start, end)
+    }
+
     // should we pass NaiveDateTime all the way through to this fn?
     pub(crate) fn find_local_time_type_from_local(
         &self,
@@ -286,6 +295,65 @@ impl<'a> TimeZoneRef<'a> {
         }
     }
 
+    /// Find the changes of UTC offset from `start` (inclusive) to `end` (exclusive), in Unix time in seconds.
+    ///
+    /// Each change is listed with the offsets before and after it.
+    /// Transitions that keep the same offset (e.g. only changing the name) are skipped.
+    pub(crate) fn find_offset_changes(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<(i64, i32, i32)>, Error> {
+        let mut changes = Vec::new();
+
+        let mut before = self.local_time_types[0].ut_offset;
+        let mut last_transition = None;
+        for transition in self.transitions {
+            let unix_time = self.unix_leap_time_to_unix_time(transition.unix_leap_time)?;
+            let after = self.local_time_types[transition.local_time_type_index].ut_offset;
+            if start <= unix_time && unix_time < end && before != after {
+                changes.push((unix_time, before, after));
+            }
+            before = after;
+            last_transition = Some(unix_time);
+        }
+
+        // the extra rule applies from the last transition onwards
+        let extra_rule = match self.extra_rule {
+            Some(extra_rule) => extra_rule,
+            None => return Ok(changes),
+        };
+        let first = match last_transition {
+            Some(unix_time) => start.max(unix_time.saturating_add(1)),
+            None => start.max(i64::min_value() + 1),
+        };
+        if first >= end {
+            return Ok(changes);
+        }
+
+        // the transitions of a year can fall in the previous or next year in UTC
+        let first_year = UtcDateTime::from_timespec(first)?.year.saturating_sub(1);
+        let last_year = UtcDateTime::from_timespec(end)?.year.saturating_add(1);
+        let mut unix_times = Vec::new();
+        for year in first_year..=last_year {
+            if let Some((dst_start, dst_end)) = extra_rule.transition_times(year) {
+                unix_times.extend([dst_start, dst_end].iter().filter(|&&t| first <= t && t < end));
+            }
+        }
+        unix_times.sort_unstable();
+        unix_times.dedup();
+
+        // rules can describe transitions that keep the offset, such as all-year DST
+        for unix_time in unix_times {
+            let before = self.find_local_time_type(unix_time - 1)?.ut_offset;
+            let after = self.find_local_time_type(unix_time)?.ut_offset;
+            if before != after {
+                changes.push((unix_time, before, after));
+            }
+        }
+        Ok(changes)
+    }
+
     /// Check time zone inputs
     fn validate(&self) -> Result<(), Error> {
         // Check local time types
@@ -582,6 +650,11 @@ impl LocalTimeType {
         self.is_dst
     }
 
+    /// Returns the time zone name, such as `CEST`
+    pub(crate) fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
     pub(super) const UTC: LocalTimeType = Self { ut_offset: 0, is_dst: false, name: None };
 }
 
@@ -804,6 +877,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_find_offset_changes() -> Result<(), Error> {
+        let utc = LocalTimeType::UTC;
+        let cet = LocalTimeType::with_offset(3600)?;
+
+        // a change undone within ten minutes, followed by a fixed rule
+        let time_zone = TimeZone::new(
+            vec![Transition::new(0, 1), Transition::new(600, 0)],
+            vec![utc, cet],
+            vec![],
+            Some(TransitionRule::from(utc)),
+        )?;
+        assert_eq!(time_zone.find_offset_changes(-1, 601)?, vec![(0, 0, 3600), (600, 3600, 0)]);
+        assert_eq!(time_zone.find_offset_changes(1, 600)?, vec![]);
+        assert_eq!(time_zone.find_offset_changes(601, 32_503_680_000)?, vec![]);
+
+        // 2022-03-27T01:00:00Z and 2022-10-30T01:00:00Z
+        let rule = TransitionRule::from_tz_string(b"CET-1CEST,M3.5.0,M10.5.0/3", false)?;
+        let time_zone = TimeZone::new(vec![], vec![cet], vec![], Some(rule))?;
+        let (start_2022, end_2022) = (1_640_995_200, 1_672_531_200);
+        assert_eq!(
+            time_zone.find_offset_changes(start_2022, end_2022)?,
+            vec![(1_648_342_800, 3600, 7200), (1_667_091_600, 7200, 3600)]
+        );
+        assert_eq!(
+            time_zone.find_offset_changes(1_648_342_800, 1_667_091_600)?,
+            vec![(1_648_342_800, 3600, 7200)]
+        );
+        // two changes per year, also across a century
+        let end_2122 = end_2022 + 100 * 31_556_952;
+        assert_eq!(time_zone.find_offset_changes(end_2022, end_2122)?.len(), 200);
+
+        // the rule only applies after the last transition
+        let time_zone = TimeZone::new(
+            vec![Transition::new(1_650_000_000, 1)],
+            vec![cet, LocalTimeType::new(7200, true, Some(b"CEST"))?],
+            vec![],
+            Some(rule),
+        )?;
+        assert_eq!(
+            time_zone.find_offset_changes(start_2022, end_2022)?,
+            vec![(1_650_000_000, 3600, 7200), (1_667_091_600, 7200, 3600)]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_time_zone_from_posix_tz() -> Result<(), Error> {
         #[cfg(unix)]
@@ -828,6 +948,17 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_local_time_type_name() -> Result<(), Error> {
+        let time_zone = TimeZone::from_posix_tz("CET-1CEST,M3.5.0,M10.5.0/3")?;
+        // 2022-01-01 and 2022-07-01 00:00:00 UTC
+        assert_eq!(time_zone.find_local_time_type(1_640_995_200)?.name(), Some("CET"));
+        assert_eq!(time_zone.find_local_time_type(1_656_633_600)?.name(), Some("CEST"));
+        assert_eq!(LocalTimeType::with_offset(3600)?.name(), None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_leap_seconds() -> Result<(), Error> {
         let time_zone = TimeZone::new(
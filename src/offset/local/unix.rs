@@ -14,9 +14,15 @@ use super::tz_info::TimeZone;
 use super::{DateTime, FixedOffset, Local, NaiveDateTime};
 use crate::{Datelike, LocalResult, Utc};
 
-pub(super) fn now() -> DateTime<Local> {
+pub(super) fn now_opt() -> Option<DateTime<Local>> {
     let now = Utc::now().naive_utc();
-    naive_to_local(&now, false).unwrap()
+    TZ_INFO
+        .try_with(|maybe_cache| {
+            maybe_cache.borrow_mut().get_or_insert_with(Cache::default).try_offset(now, false)
+        })
+        .ok()
+        .and_then(|offset| offset)
+        .and_then(|offset| offset.single())
 }
 
 pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> LocalResult<DateTime<Local>> {
@@ -25,6 +31,21 @@ pub(super) fn naive_to_local(d: &NaiveDateTime, local: bool) -> LocalResult<Date
     })
 }
 
+pub(super) fn transitions_between(
+    start: &NaiveDateTime,
+    end: &NaiveDateTime,
+) -> Vec<(NaiveDateTime, FixedOffset, FixedOffset)> {
+    TZ_INFO.with(|maybe_cache| {
+        maybe_cache.borrow_mut().get_or_insert_with(Cache::default).transitions_between(start, end)
+    })
+}
+
+pub(super) fn offset_name(utc: &NaiveDateTime) -> Option<String> {
+    TZ_INFO.with(|maybe_cache| {
+        maybe_cache.borrow_mut().get_or_insert_with(Cache::default).offset_name(utc)
+    })
+}
+
 // we have to store the `Cache` in an option as it can't
 // be initalized in a static context.
 thread_local! {
@@ -124,30 +145,68 @@ impl Default for Cache {
 
 impl Cache {
     fn offset(&mut self, d: NaiveDateTime, local: bool) -> LocalResult<DateTime<Local>> {
+        self.try_offset(d, local).expect("unable to select local time type")
+    }
+
+    fn transitions_between(
+        &mut self,
+        start: &NaiveDateTime,
+        end: &NaiveDateTime,
+    ) -> Vec<(NaiveDateTime, FixedOffset, FixedOffset)> {
+        if self.source.out_of_date() {
+            *self = Cache::default();
+        }
+
+        // transitions happen on whole seconds, so round both bounds up
+        let round_up =
+            |d: &NaiveDateTime| d.timestamp() + i64::from(d.timestamp_subsec_nanos() > 0);
+        let changes = self
+            .zone
+            .find_offset_changes(round_up(start), round_up(end))
+            .expect("unable to select local time type");
+        changes
+            .into_iter()
+            .filter_map(|(unix_time, before, after)| {
+                Some((
+                    NaiveDateTime::from_timestamp_opt(unix_time, 0)?,
+                    FixedOffset::east_opt(before)?,
+                    FixedOffset::east_opt(after)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn offset_name(&mut self, utc: &NaiveDateTime) -> Option<String> {
+        if self.source.out_of_date() {
+            *self = Cache::default();
+        }
+
+        self.zone.find_local_time_type(utc.timestamp()).ok()?.name().map(String::from)
+    }
+
+    /// Same as `offset`, but returns `None` instead of panicking when no local time type
+    /// can be selected from the time zone data.
+    fn try_offset(
+        &mut self,
+        d: NaiveDateTime,
+        local: bool,
+    ) -> Option<LocalResult<DateTime<Local>>> {
         if self.source.out_of_date() {
             *self = Cache::default();
         }
 
         if !local {
-            let offset = self
-                .zone
-                .find_local_time_type(d.timestamp())
-                .expect("unable to select local time type")
-                .offset();
+            let offset = self.zone.find_local_time_type(d.timestamp()).ok()?.offset();
 
-            return match FixedOffset::east_opt(offset) {
+            return Some(match FixedOffset::east_opt(offset) {
                 Some(offset) => LocalResult::Single(DateTime::from_utc(d, offset)),
                 None => LocalResult::None,
-            };
+            });
         }
 
         // we pass through the year as the year of a local point in time must either be valid in that locale, or
         // the entire time was skipped in which case we will return LocalResult::None anywa.
-        match self
-            .zone
-            .find_local_time_type_from_local(d.timestamp(), d.year())
-            .expect("unable to select local time type")
-        {
+        Some(match self.zone.find_local_time_type_from_local(d.timestamp(), d.year()).ok()? {
             LocalResult::None => LocalResult::None,
             LocalResult::Ambiguous(early, late) => {
                 let early_offset = FixedOffset::east_opt(early.offset()).unwrap();
@@ -162,6 +221,6 @@ impl Cache {
                 let offset = FixedOffset::east_opt(tt.offset()).unwrap();
                 LocalResult::Single(DateTime::from_utc(d - offset, offset))
             }
-        }
+        })
     }
 }
@@ -18,21 +18,33 @@
 //! and provides implementations for 1 and 3.
 //! An `TimeZone` instance can be reconstructed from the corresponding `Offset` instance.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 use core::fmt;
+#[cfg(any(feature = "std", test))]
+use std::borrow::Cow;
 
 use crate::format::{parse, ParseResult, Parsed, StrftimeItems};
 use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 use crate::Weekday;
 use crate::{Date, DateTime};
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::{TimeDelta, Timelike};
 
 mod fixed;
-pub use self::fixed::FixedOffset;
+pub use self::fixed::{FixedOffset, OffsetStyle};
 
 #[cfg(feature = "clock")]
 mod local;
 #[cfg(feature = "clock")]
 pub use self::local::Local;
 
+mod named;
+pub use self::named::NamedOffset;
+
 mod utc;
 pub use self::utc::Utc;
 
@@ -190,6 +202,27 @@ impl<T: fmt::Debug> LocalResult<T> {
     }
 }
 
+/// Renders `None` as `(none)`, `Single(t)` as `t` and `Ambiguous(t1, t2)` as `ambiguous: t1 / t2`.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+/// assert_eq!(tz.ymd_opt(2014, 5, 6).and_hms_opt(7, 8, 9).to_string(), "2014-05-06 07:08:09 +09:00");
+/// assert_eq!(tz.ymd_opt(2014, 2, 30).and_hms_opt(7, 8, 9).to_string(), "(none)");
+/// ```
+impl<T: fmt::Display> fmt::Display for LocalResult<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LocalResult::None => f.write_str("(none)"),
+            LocalResult::Single(ref t) => t.fmt(f),
+            LocalResult::Ambiguous(ref t1, ref t2) => write!(f, "ambiguous: {} / {}", t1, t2),
+        }
+    }
+}
+
 /// The offset from the local time to UTC.
 pub trait Offset: Sized + Clone + fmt::Debug {
     /// Returns the fixed offset from UTC to the local time stored.
@@ -313,6 +346,37 @@ pub trait TimeZone: Sized + Clone {
         }
     }
 
+    /// Makes a new `DateTime` from the local year, month, day, hour, minute and second,
+    /// resolving it in this time zone in a single step.
+    ///
+    /// Returns `LocalResult::None` on an invalid date or time, or when the local time falls
+    /// into a gap (e.g. a DST transition that skips forward). Returns `LocalResult::Ambiguous`
+    /// when the local time occurs twice (e.g. a DST transition that falls back).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{LocalResult, Utc, TimeZone};
+    ///
+    /// assert_eq!(Utc.at(2015, 5, 15, 10, 20, 30).unwrap().to_string(), "2015-05-15 10:20:30 UTC");
+    /// assert_eq!(Utc.at(2015, 2, 29, 0, 0, 0), LocalResult::None);
+    /// ```
+    fn at(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        min: u32,
+        sec: u32,
+    ) -> LocalResult<DateTime<Self>> {
+        match NaiveDate::from_ymd_opt(year, month, day).and_then(|d| d.and_hms_opt(hour, min, sec))
+        {
+            Some(dt) => self.from_local_datetime(&dt),
+            None => LocalResult::None,
+        }
+    }
+
     /// Makes a new `DateTime` from the number of non-leap seconds
     /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp")
     /// and the number of nanoseconds since the last whole non-leap second.
@@ -331,6 +395,10 @@ pub trait TimeZone: Sized + Clone {
     /// Returns `LocalResult::None` on out-of-range number of seconds and/or
     /// invalid nanosecond, otherwise always returns `LocalResult::Single`.
     ///
+    /// A timestamp names a single instant, so the result is never `Ambiguous`, even in a time
+    /// zone where the local time it maps to occurs twice. The `LocalResult` return type only
+    /// matches the other constructors of this trait.
+    ///
     /// # Example
     ///
     /// ```
@@ -358,10 +426,9 @@ pub trait TimeZone: Sized + Clone {
     /// Makes a new `DateTime` from the number of non-leap milliseconds
     /// since January 1, 1970 0:00:00 UTC (aka "UNIX timestamp").
     ///
-    ///
     /// Returns `LocalResult::None` on out-of-range number of milliseconds
     /// and/or invalid nanosecond, otherwise always returns
-    /// `LocalResult::Single`.
+    /// `LocalResult::Single`; see [`timestamp_opt`](#method.timestamp_opt).
     ///
     /// # Example
     ///
@@ -451,6 +518,100 @@ pub trait TimeZone: Sized + Clone {
     /// Creates the offset for given UTC `NaiveDateTime`. This cannot fail.
     fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset;
 
+    /// Returns the name of the offset in effect at given UTC `NaiveDateTime`.
+    ///
+    /// This is what `%Z` writes when formatting a `DateTime` in this time zone.
+    /// The default implementation returns the `Display` output of the offset, such as `+09:00`
+    /// for a `FixedOffset`, which has to be allocated.
+    /// Time zones with a fixed name can override this to return a borrowed string instead,
+    /// as [`Utc`] does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+    ///
+    /// let utc = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert_eq!(Utc.offset_name(&utc), "UTC");
+    /// assert_eq!(FixedOffset::east_opt(9 * 3600).unwrap().offset_name(&utc), "+09:00");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    fn offset_name(&self, utc: &NaiveDateTime) -> Cow<'static, str>
+    where
+        Self::Offset: fmt::Display,
+    {
+        Cow::Owned(self.offset_from_utc_datetime(utc).to_string())
+    }
+
+    /// Returns the changes of the offset from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// Each transition is listed as the instant the new offset takes effect, followed by the
+    /// offsets before and after it.
+    ///
+    /// The default implementation samples the offset every hour and narrows each change down
+    /// to the second. It therefore misses changes that are undone within the same hour, and it
+    /// calls [`offset_from_utc_datetime`](TimeZone::offset_from_utc_datetime) once per hour of
+    /// the range: close to 9,000 times per year, or close to a million times per century.
+    /// Time zones that know their transitions should override it. `Utc`, `FixedOffset` and,
+    /// on Unix, `Local` do so and return their transitions without sampling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    ///
+    /// let start = Utc.ymd_opt(2023, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let end = Utc.ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// assert!(Utc.transitions_between(start, end).is_empty());
+    /// assert!(FixedOffset::east_opt(3600).unwrap().transitions_between(start, end).is_empty());
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    fn transitions_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, FixedOffset, FixedOffset)> {
+        let offset_at = |utc: &NaiveDateTime| self.offset_from_utc_datetime(utc).fix();
+        let second = TimeDelta::seconds(1);
+        let mut transitions = Vec::new();
+
+        // sample whole seconds, starting just before `start` and ending just before `end`
+        let start = start.naive_utc();
+        let last = match end.naive_utc().checked_sub_signed(TimeDelta::nanoseconds(1)) {
+            Some(last) => last.with_nanosecond(0).unwrap(),
+            None => return transitions,
+        };
+        let first = start.with_nanosecond(0).unwrap();
+        let mut t = first.checked_sub_signed(second).unwrap_or(first);
+        let mut offset = offset_at(&t);
+        while t < last {
+            let next = match t.checked_add_signed(TimeDelta::hours(1)) {
+                Some(next) if next < last => next,
+                _ => last,
+            };
+            let next_offset = offset_at(&next);
+            if next_offset != offset {
+                let (mut lo, mut hi) = (t, next);
+                while hi - lo > second {
+                    let mid = lo + TimeDelta::seconds((hi - lo).num_seconds() / 2);
+                    if offset_at(&mid) == offset {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                if hi >= start {
+                    transitions.push((DateTime::from_utc(hi, Utc), offset, offset_at(&hi)));
+                }
+            }
+            t = next;
+            offset = next_offset;
+        }
+        transitions
+    }
+
     /// Converts the UTC `NaiveDate` to the local time.
     /// The UTC is continuous and thus this cannot fail (but can give the duplicate local time).
     #[allow(clippy::wrong_self_convention)]
@@ -467,7 +628,7 @@ pub trait TimeZone: Sized + Clone {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     #[test]
@@ -517,6 +678,184 @@ mod tests {
         assert_eq!(dt.to_string(), "1969-12-31 23:00:00 UTC");
     }
 
+    /// A test zone at UTC+1 that moves to UTC+2 from 2022-03-27 01:00 UTC until
+    /// 2022-10-30 01:00 UTC, mimicking central European summer time.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub(crate) struct Cet;
+
+    impl Cet {
+        fn is_dst(utc: &NaiveDateTime) -> bool {
+            let start = NaiveDate::from_ymd_opt(2022, 3, 27).unwrap().and_hms_opt(1, 0, 0).unwrap();
+            let end = NaiveDate::from_ymd_opt(2022, 10, 30).unwrap().and_hms_opt(1, 0, 0).unwrap();
+            *utc >= start && *utc < end
+        }
+    }
+
+    impl TimeZone for Cet {
+        type Offset = FixedOffset;
+
+        fn from_offset(_: &FixedOffset) -> Cet {
+            Cet
+        }
+
+        fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+            self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+            let winter = FixedOffset::east_opt(3600).unwrap();
+            let summer = FixedOffset::east_opt(7200).unwrap();
+            let winter_ok = !Cet::is_dst(&(*local - winter));
+            let summer_ok = Cet::is_dst(&(*local - summer));
+            match (winter_ok, summer_ok) {
+                (false, false) => LocalResult::None,
+                (true, false) => LocalResult::Single(winter),
+                (false, true) => LocalResult::Single(summer),
+                (true, true) => LocalResult::Ambiguous(summer, winter),
+            }
+        }
+
+        fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+            self.offset_from_utc_datetime(&utc.and_hms_opt(12, 0, 0).unwrap())
+        }
+
+        fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+            FixedOffset::east_opt(if Cet::is_dst(utc) { 7200 } else { 3600 }).unwrap()
+        }
+
+        fn offset_name(&self, utc: &NaiveDateTime) -> Cow<'static, str> {
+            Cow::Borrowed(if Cet::is_dst(utc) { "CEST" } else { "CET" })
+        }
+    }
+
+    #[test]
+    fn test_at() {
+        let dt = Utc.at(2022, 6, 1, 12, 30, 45).unwrap();
+        assert_eq!(dt, Utc.ymd_opt(2022, 6, 1).unwrap().and_hms_opt(12, 30, 45).unwrap());
+        assert_eq!(Utc.at(2022, 2, 29, 0, 0, 0), LocalResult::None);
+        assert_eq!(Utc.at(2022, 6, 1, 24, 0, 0), LocalResult::None);
+
+        // a valid time in a DST zone
+        let dt = Cet.at(2022, 6, 1, 12, 0, 0).unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 7200);
+        assert_eq!(dt.naive_utc().to_string(), "2022-06-01 10:00:00");
+
+        // a local time skipped by the spring-forward transition
+        assert_eq!(Cet.at(2022, 3, 27, 2, 30, 0), LocalResult::None);
+
+        // a local time repeated by the fall-back transition
+        match Cet.at(2022, 10, 30, 2, 30, 0) {
+            LocalResult::Ambiguous(a, b) => {
+                assert_eq!(a.offset().local_minus_utc(), 7200);
+                assert_eq!(b.offset().local_minus_utc(), 3600);
+                assert_eq!(b - a, crate::TimeDelta::hours(1));
+            }
+            e => panic!("expected an ambiguous result, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_timestamp_opt_is_single() {
+        assert_eq!(
+            Utc.timestamp_opt(0, 0),
+            LocalResult::Single(Utc.at(1970, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(Utc.timestamp_opt(0, 2_000_000_000), LocalResult::None);
+        assert_eq!(Utc.timestamp_opt(i64::max_value(), 0), LocalResult::None);
+        assert!(matches!(Utc.timestamp_millis_opt(-1), LocalResult::Single(_)));
+        assert_eq!(Utc.timestamp_millis_opt(i64::max_value()), LocalResult::None);
+
+        // both instants render as the ambiguous local time 2022-10-30 02:30:00
+        let (first, second) = match Cet.at(2022, 10, 30, 2, 30, 0) {
+            LocalResult::Ambiguous(a, b) => (a, b),
+            e => panic!("expected an ambiguous result, got {:?}", e),
+        };
+        assert_eq!(Cet.timestamp_opt(1_667_089_800, 0), LocalResult::Single(first));
+        assert_eq!(Cet.timestamp_opt(1_667_093_400, 0), LocalResult::Single(second));
+        assert_eq!(Cet.timestamp_millis_opt(1_667_089_800_000), LocalResult::Single(first));
+        assert_eq!(Cet.timestamp_millis_opt(1_667_093_400_000), LocalResult::Single(second));
+    }
+
+    #[test]
+    fn test_transitions_between() {
+        let at = |y, m, d, h, n, s| Utc.at(y, m, d, h, n, s).unwrap();
+        let winter = FixedOffset::east_opt(3600).unwrap();
+        let summer = FixedOffset::east_opt(7200).unwrap();
+        let spring = at(2022, 3, 27, 1, 0, 0);
+        let autumn = at(2022, 10, 30, 1, 0, 0);
+
+        assert_eq!(
+            Cet.transitions_between(at(2022, 1, 1, 0, 0, 0), at(2023, 1, 1, 0, 0, 0)),
+            vec![(spring, winter, summer), (autumn, summer, winter)]
+        );
+        assert_eq!(
+            Cet.transitions_between(at(2022, 6, 1, 0, 0, 0), at(2023, 1, 1, 0, 0, 0)),
+            vec![(autumn, summer, winter)]
+        );
+        assert!(Cet
+            .transitions_between(at(2022, 4, 1, 0, 0, 0), at(2022, 10, 1, 0, 0, 0))
+            .is_empty());
+
+        // the range includes `start` but not `end`
+        assert_eq!(Cet.transitions_between(spring, autumn), vec![(spring, winter, summer)]);
+        let just_after = spring + crate::TimeDelta::nanoseconds(1);
+        assert!(Cet.transitions_between(just_after, autumn).is_empty());
+        assert!(Cet.transitions_between(autumn, spring).is_empty());
+        assert!(Cet.transitions_between(spring, spring).is_empty());
+
+        let (start, end) = (DateTime::<Utc>::MIN_UTC, DateTime::<Utc>::MAX_UTC);
+        assert!(Utc.transitions_between(start, end).is_empty());
+        assert!(summer.transitions_between(start, end).is_empty());
+    }
+
+    #[test]
+    fn test_offset_name() {
+        let winter = NaiveDate::from_ymd_opt(2022, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        let summer = NaiveDate::from_ymd_opt(2022, 7, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        assert!(matches!(Utc.offset_name(&winter), Cow::Borrowed("UTC")));
+        let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+        assert!(matches!(kst.offset_name(&winter), Cow::Owned(ref name) if name == "+09:00"));
+        assert_eq!(Cet.offset_name(&winter), "CET");
+        assert_eq!(Cet.offset_name(&summer), "CEST");
+    }
+
+    #[test]
+    fn test_offset_name_format() {
+        // `%Z` writes the name given by the time zone, not the `Display` output of the offset
+        let winter = Cet.at(2022, 1, 1, 12, 0, 0).unwrap();
+        let summer = Cet.at(2022, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(winter.format("%H:%M %Z (%:z)").to_string(), "12:00 CET (+01:00)");
+        assert_eq!(summer.format("%H:%M %Z (%:z)").to_string(), "12:00 CEST (+02:00)");
+        assert_eq!(summer.to_string(), "2022-07-01 12:00:00 +02:00");
+
+        let utc = Utc.at(2022, 7, 1, 12, 0, 0).unwrap();
+        assert_eq!(utc.format("%Z").to_string(), "UTC");
+        let kst = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+        assert_eq!(utc.with_timezone(&kst).format("%Z").to_string(), "+09:00");
+    }
+
+    #[test]
+    fn test_local_result_display() {
+        let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+        let dt = tz.ymd_opt(2022, 10, 30).unwrap().and_hms_opt(2, 30, 0).unwrap();
+        let later = tz.ymd_opt(2022, 10, 30).unwrap().and_hms_opt(3, 30, 0).unwrap();
+
+        assert_eq!(LocalResult::<DateTime<FixedOffset>>::None.to_string(), "(none)");
+        assert_eq!(LocalResult::Single(dt).to_string(), "2022-10-30 02:30:00 +05:00");
+        assert_eq!(
+            LocalResult::Ambiguous(dt, later).to_string(),
+            "ambiguous: 2022-10-30 02:30:00 +05:00 / 2022-10-30 03:30:00 +05:00"
+        );
+        assert_eq!(tz.at(2022, 2, 30, 0, 0, 0).to_string(), "(none)");
+
+        // an actual DST fold
+        assert_eq!(
+            Cet.at(2022, 10, 30, 2, 30, 0).to_string(),
+            "ambiguous: 2022-10-30 02:30:00 +02:00 / 2022-10-30 02:30:00 +01:00"
+        );
+    }
+
     #[test]
     fn test_nanos_never_panics() {
         Utc.timestamp_nanos(i64::max_value());
@@ -0,0 +1,147 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A fixed offset carrying the name of its time zone.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+#[cfg(any(feature = "std", test))]
+use std::borrow::Cow;
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use super::Utc;
+use super::{FixedOffset, LocalResult, Offset, TimeZone};
+use crate::naive::{NaiveDate, NaiveDateTime};
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use crate::DateTime;
+
+/// A fixed offset from UTC together with a name for it, such as `CEST` for UTC+2.
+///
+/// This behaves exactly like the wrapped [`FixedOffset`], except that the name is kept with
+/// every `DateTime<NamedOffset>`: it is the `Display` output of the offset, and so is written by
+/// `%Z` and by the `Display` implementation of `DateTime`. `%z` and `Debug` still write the
+/// numeric offset.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, NamedOffset, TimeZone};
+///
+/// let cest = NamedOffset::new("CEST", FixedOffset::east_opt(2 * 3600).unwrap());
+/// let dt = cest.ymd_opt(2023, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+///
+/// assert_eq!(dt.to_string(), "2023-05-01 12:00:00 CEST");
+/// assert_eq!(dt.format("%H:%M %Z (%z)").to_string(), "12:00 CEST (+0200)");
+/// assert_eq!(format!("{:?}", dt), "2023-05-01T12:00:00+02:00");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NamedOffset {
+    name: &'static str,
+    offset: FixedOffset,
+}
+
+impl NamedOffset {
+    /// Makes a new `NamedOffset` from a name and the offset it stands for.
+    pub const fn new(name: &'static str, offset: FixedOffset) -> NamedOffset {
+        NamedOffset { name, offset }
+    }
+
+    /// Returns the name of the offset.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the offset from UTC.
+    pub const fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl TimeZone for NamedOffset {
+    type Offset = NamedOffset;
+
+    fn from_offset(offset: &NamedOffset) -> NamedOffset {
+        *offset
+    }
+
+    fn offset_from_local_date(&self, _local: &NaiveDate) -> LocalResult<NamedOffset> {
+        LocalResult::Single(*self)
+    }
+    fn offset_from_local_datetime(&self, _local: &NaiveDateTime) -> LocalResult<NamedOffset> {
+        LocalResult::Single(*self)
+    }
+
+    fn offset_from_utc_date(&self, _utc: &NaiveDate) -> NamedOffset {
+        *self
+    }
+    fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> NamedOffset {
+        *self
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn offset_name(&self, _utc: &NaiveDateTime) -> Cow<'static, str> {
+        Cow::Borrowed(self.name)
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn transitions_between(
+        &self,
+        _start: DateTime<Utc>,
+        _end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, FixedOffset, FixedOffset)> {
+        Vec::new()
+    }
+}
+
+impl Offset for NamedOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Debug for NamedOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.offset, f)
+    }
+}
+
+impl fmt::Display for NamedOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NamedOffset;
+    use crate::offset::{FixedOffset, TimeZone, Utc};
+    use std::borrow::Cow;
+
+    const EST: NamedOffset = NamedOffset::new("EST", FixedOffset::west(5 * 3600));
+
+    #[test]
+    fn test_named_offset_format() {
+        let dt = EST.ymd_opt(2023, 1, 15).unwrap().and_hms_opt(8, 30, 0).unwrap();
+        assert_eq!(dt.format("%Z").to_string(), "EST");
+        assert_eq!(dt.format("%z").to_string(), "-0500");
+        assert_eq!(dt.format("%H:%M:%S %Z %:z").to_string(), "08:30:00 EST -05:00");
+        assert_eq!(dt.to_string(), "2023-01-15 08:30:00 EST");
+        assert_eq!(dt.to_rfc3339(), "2023-01-15T08:30:00-05:00");
+    }
+
+    #[test]
+    fn test_named_offset_conversions() {
+        let dt = EST.ymd_opt(2023, 1, 15).unwrap().and_hms_opt(8, 30, 0).unwrap();
+        let utc = Utc.ymd_opt(2023, 1, 15).unwrap().and_hms_opt(13, 30, 0).unwrap();
+        assert_eq!(dt, utc);
+        assert_eq!(utc.with_timezone(&EST).offset().name(), "EST");
+        assert_eq!(dt.offset().offset(), FixedOffset::west_opt(5 * 3600).unwrap());
+        assert_eq!(dt.timezone(), EST);
+        assert!(matches!(EST.offset_name(&utc.naive_utc()), Cow::Borrowed("EST")));
+        assert!(EST.transitions_between(utc, utc + crate::TimeDelta::days(365)).is_empty());
+    }
+}
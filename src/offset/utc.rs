@@ -3,7 +3,14 @@
 
 //! The UTC (Coordinated Universal Time) time zone.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, vec::Vec};
 use core::fmt;
+#[cfg(any(feature = "std", test))]
+use std::borrow::Cow;
 #[cfg(all(
     feature = "clock",
     not(all(
@@ -20,7 +27,9 @@ use rkyv::{Archive, Deserialize, Serialize};
 use super::{FixedOffset, LocalResult, Offset, TimeZone};
 use crate::naive::{NaiveDate, NaiveDateTime};
 #[cfg(feature = "clock")]
-use crate::{Date, DateTime};
+use crate::Date;
+#[cfg(any(feature = "alloc", feature = "std", feature = "clock", test))]
+use crate::DateTime;
 
 /// The UTC time zone. This is the most efficient time zone when you don't need the local time.
 /// It is also used as an offset (which is also a dummy type).
@@ -98,6 +107,20 @@ impl TimeZone for Utc {
     fn offset_from_utc_datetime(&self, _utc: &NaiveDateTime) -> Utc {
         Utc
     }
+
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn offset_name(&self, _utc: &NaiveDateTime) -> Cow<'static, str> {
+        Cow::Borrowed("UTC")
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std", test))]
+    fn transitions_between(
+        &self,
+        _start: DateTime<Utc>,
+        _end: DateTime<Utc>,
+    ) -> Vec<(DateTime<Utc>, FixedOffset, FixedOffset)> {
+        Vec::new()
+    }
 }
 
 impl Offset for Utc {
@@ -0,0 +1,63 @@
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! A calendar-aware relative offset combining years, months, days and a time delta.
+
+use core::convert::TryFrom;
+
+use crate::naive::{Days, NaiveDateTime};
+use crate::{Months, TimeDelta};
+
+/// A relative offset made of calendar years, months and days, and an exact [`TimeDelta`].
+///
+/// Unlike a `TimeDelta`, the calendar parts do not have a fixed length:
+/// one month from January 31 is February 28 (or 29), and one day is always the next calendar
+/// day regardless of a DST transition.
+///
+/// The parts are applied to the local datetime in the following order:
+///
+/// 1. `years` and `months` together, as `years * 12 + months` months. If the day would be out of
+///    range for the resulting month, the last day of that month is used.
+/// 2. `days`, as whole calendar days.
+/// 3. `time`, as a `TimeDelta` added to the local wall-clock time.
+///
+/// The order matters: from 2023-01-30, one month and one day gives 2023-03-01, whereas applying
+/// the day before the month would give 2023-02-28.
+///
+/// See [`DateTime::checked_add_relative`](./struct.DateTime.html#method.checked_add_relative).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RelativeDelta {
+    /// The number of calendar years, may be negative.
+    pub years: i32,
+    /// The number of calendar months, may be negative.
+    pub months: i32,
+    /// The number of calendar days, may be negative.
+    pub days: i64,
+    /// The exact duration added after the calendar parts.
+    pub time: TimeDelta,
+}
+
+impl RelativeDelta {
+    /// Makes a new `RelativeDelta` from its parts.
+    pub fn new(years: i32, months: i32, days: i64, time: TimeDelta) -> RelativeDelta {
+        RelativeDelta { years, months, days, time }
+    }
+
+    /// Applies this offset to a local datetime, in the documented order.
+    ///
+    /// Returns `None` if any step goes out of range.
+    pub(crate) fn add_to(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        let datetime = if months < 0 {
+            datetime.checked_sub_months(Months::new(u32::try_from(-months).ok()?))?
+        } else {
+            datetime.checked_add_months(Months::new(u32::try_from(months).ok()?))?
+        };
+        let datetime = if self.days < 0 {
+            datetime.checked_sub_days(Days::new(self.days.checked_neg()? as u64))?
+        } else {
+            datetime.checked_add_days(Days::new(self.days as u64))?
+        };
+        datetime.checked_add_signed(self.time)
+    }
+}
@@ -93,6 +93,10 @@ fn span_for_digits(digits: u16) -> u32 {
 
 /// Extension trait for rounding or truncating a DateTime by a TimeDelta.
 ///
+/// A value within a [leap second](./naive/struct.NaiveTime.html#leap-second-handling) is
+/// rounded by its actual position in time: `23:59:60.4` rounds to `23:59:60` by one second,
+/// but to `00:00:00` of the next day by one minute, and truncates to `23:59:00` by one minute.
+///
 /// # Limitations
 /// Both rounding and truncating are done via [`TimeDelta::num_nanoseconds`] and
 /// [`DateTime::timestamp_nanos`]. This means that they will fail if either the
@@ -188,6 +192,17 @@ where
         if span == 0 {
             return Ok(original);
         }
+        if let Some((naive, clamped, frac)) = leap_second_clamp(naive, &original, span) {
+            // `clamped` is `frac + 1` nanoseconds before `original`, so that much is added to
+            // the distance down and taken from the distance up to the next multiple of `span`.
+            let delta_down = naive.timestamp_nanos().rem_euclid(span);
+            let delta_up = span - delta_down;
+            if delta_up - 1 + (1_000_000_000 - frac) <= delta_down + 1 + frac {
+                return Ok(clamped + TimeDelta::nanoseconds(delta_up));
+            } else {
+                return Ok(clamped - TimeDelta::nanoseconds(delta_down));
+            }
+        }
         let delta_down = stamp % span;
         if delta_down == 0 {
             Ok(original)
@@ -224,6 +239,9 @@ where
         if span > stamp.abs() {
             return Err(RoundingError::DurationExceedsTimestamp);
         }
+        if let Some((naive, clamped, _)) = leap_second_clamp(naive, &original, span) {
+            return Ok(clamped - TimeDelta::nanoseconds(naive.timestamp_nanos().rem_euclid(span)));
+        }
         let delta_down = stamp % span;
         match delta_down.cmp(&0) {
             Ordering::Equal => Ok(original),
@@ -235,6 +253,23 @@ where
     }
 }
 
+/// For a value within a leap second, returns it moved to the last nanosecond of the preceding
+/// second, along with how far into the leap second it was.
+///
+/// Multiples of a `span` that divides one second also fall within the leap second, so such
+/// values are rounded as they are and `None` is returned.
+fn leap_second_clamp<T: Timelike>(
+    naive: NaiveDateTime,
+    original: &T,
+    span: i64,
+) -> Option<(NaiveDateTime, T, i64)> {
+    let frac = i64::from(naive.nanosecond()) - 1_000_000_000;
+    if frac < 0 || span <= 0 || 1_000_000_000 % span == 0 {
+        return None;
+    }
+    Some((naive.with_nanosecond(999_999_999)?, original.with_nanosecond(999_999_999)?, frac))
+}
+
 /// An error from rounding by `TimeDelta`
 ///
 /// See: [`DurationRound`]
@@ -353,6 +388,17 @@ mod tests {
         assert_eq!(dt.round_subsecs(0).second(), 0);
     }
 
+    #[test]
+    fn test_round_subsecs_in_leap_second() {
+        let leap =
+            |nano| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, nano).unwrap();
+        let next_day = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        assert_eq!(leap(1_400_000_000).round_subsecs(0), leap(1_000_000_000));
+        assert_eq!(leap(1_600_000_000).round_subsecs(0), next_day);
+        assert_eq!(leap(1_000_000_000).round_subsecs(0), leap(1_000_000_000));
+    }
+
     #[test]
     fn test_trunc_subsecs() {
         let pst = FixedOffset::east_opt(8 * 60 * 60).unwrap();
@@ -545,6 +591,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_round_leap_second() {
+        let leap =
+            |nano| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, nano).unwrap();
+        let next_day = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        let second = TimeDelta::seconds(1);
+        assert_eq!(leap(1_400_000_000).duration_round(second), Ok(leap(1_000_000_000)));
+        assert_eq!(leap(1_600_000_000).duration_round(second), Ok(next_day));
+        assert_eq!(
+            leap(1_600_000_000).duration_round(TimeDelta::milliseconds(250)),
+            Ok(leap(1_500_000_000))
+        );
+
+        // the leap second is not a multiple of larger spans
+        let minute = TimeDelta::minutes(1);
+        assert_eq!(leap(1_400_000_000).duration_round(minute), Ok(next_day));
+        assert_eq!(leap(1_000_000_000).duration_round(minute), Ok(next_day));
+        assert_eq!(leap(1_400_000_000).duration_round(TimeDelta::days(1)), Ok(next_day));
+        // multiples of seven seconds since the epoch fall on 23:59:56 and 00:00:03
+        let seven = TimeDelta::seconds(7);
+        assert_eq!(leap(1_400_000_000).duration_round(seven), Ok(next_day + TimeDelta::seconds(3)));
+
+        let naive = leap(1_600_000_000).naive_utc();
+        assert_eq!(naive.duration_round(minute), Ok(next_day.naive_utc()));
+        assert_eq!(naive.duration_round(second), Ok(next_day.naive_utc()));
+    }
+
+    #[test]
+    fn test_duration_trunc_leap_second() {
+        let leap =
+            |nano| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, nano).unwrap();
+        let at = |h, m, s| Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_opt(h, m, s).unwrap();
+
+        assert_eq!(
+            leap(1_600_000_000).duration_trunc(TimeDelta::seconds(1)),
+            Ok(leap(1_000_000_000))
+        );
+        assert_eq!(leap(1_600_000_000).duration_trunc(TimeDelta::minutes(1)), Ok(at(23, 59, 0)));
+        assert_eq!(leap(1_600_000_000).duration_trunc(TimeDelta::seconds(7)), Ok(at(23, 59, 56)));
+        assert_eq!(leap(1_600_000_000).duration_trunc(TimeDelta::days(1)), Ok(at(0, 0, 0)));
+        assert_eq!(
+            leap(1_600_000_000).naive_utc().duration_trunc(TimeDelta::hours(1)),
+            Ok(at(23, 0, 0).naive_utc())
+        );
+    }
+
     #[test]
     fn test_duration_trunc() {
         let dt =
@@ -0,0 +1,206 @@
+#![cfg_attr(docsrs, doc(cfg(feature = "time")))]
+// This is a part of Chrono.
+// See README.md and LICENSE.txt for details.
+
+//! Conversions from and to the types of the [`time`](https://docs.rs/time/0.3) crate.
+//!
+//! The supported ranges of the two crates differ: chrono supports years from -262,143 to
+//! 262,142, and `time` from -9999 to 9999 (or -999,999 to 999,999 with its `large-dates`
+//! feature). `time` also allows offsets of up to ±25:59:59, and has no leap seconds.
+//! Conversions are therefore implemented with `TryFrom`, failing with [`OutOfRange`] where a
+//! value cannot be represented, except for `time::Time` to `NaiveTime` which always succeeds.
+
+use core::convert::TryFrom;
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::naive::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::offset::{FixedOffset, Offset, TimeZone, Utc};
+use crate::{DateTime, Datelike, OutOfRange, TimeDelta, Timelike};
+
+/// Converts a `DateTime` in any time zone, keeping its offset from UTC.
+///
+/// A leap second is moved into the following second, as `time` cannot represent it.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use std::convert::TryFrom;
+///
+/// let dt = Utc.ymd_opt(2023, 4, 5).unwrap().and_hms_nano_opt(6, 7, 8, 123_456_789).unwrap();
+/// let odt = time::OffsetDateTime::try_from(dt).unwrap();
+/// assert_eq!(odt.unix_timestamp_nanos(), 1_680_674_828_123_456_789);
+/// assert_eq!(chrono::DateTime::<Utc>::try_from(odt), Ok(dt));
+/// ```
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for OffsetDateTime {
+    type Error = OutOfRange;
+
+    fn try_from(dt: DateTime<Tz>) -> Result<OffsetDateTime, OutOfRange> {
+        let offset = UtcOffset::from_whole_seconds(dt.offset().fix().local_minus_utc())
+            .map_err(|_| OutOfRange::new())?;
+        let mut local = dt.naive_local();
+        let nanos = local.nanosecond();
+        if nanos >= 1_000_000_000 {
+            local = local
+                .with_nanosecond(nanos - 1_000_000_000)
+                .and_then(|dt| dt.checked_add_signed(TimeDelta::seconds(1)))
+                .ok_or_else(OutOfRange::new)?;
+        }
+        let date = Date::try_from(local.date())?;
+        let time = Time::try_from(local.time())?;
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+}
+
+/// Converts an `OffsetDateTime` to a `DateTime` with the same offset.
+impl TryFrom<OffsetDateTime> for DateTime<FixedOffset> {
+    type Error = OutOfRange;
+
+    fn try_from(dt: OffsetDateTime) -> Result<DateTime<FixedOffset>, OutOfRange> {
+        let seconds = dt.offset().whole_seconds();
+        let offset = FixedOffset::east_opt(seconds).ok_or_else(OutOfRange::new)?;
+        let local = NaiveDateTime::new(NaiveDate::try_from(dt.date())?, NaiveTime::from(dt.time()));
+        let utc = local
+            .checked_sub_signed(TimeDelta::seconds(i64::from(seconds)))
+            .ok_or_else(OutOfRange::new)?;
+        Ok(DateTime::from_utc(utc, offset))
+    }
+}
+
+/// Converts an `OffsetDateTime` to the same instant in UTC.
+impl TryFrom<OffsetDateTime> for DateTime<Utc> {
+    type Error = OutOfRange;
+
+    fn try_from(dt: OffsetDateTime) -> Result<DateTime<Utc>, OutOfRange> {
+        DateTime::<FixedOffset>::try_from(dt).map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = OutOfRange;
+
+    fn try_from(date: NaiveDate) -> Result<Date, OutOfRange> {
+        let ordinal = u16::try_from(date.ordinal()).map_err(|_| OutOfRange::new())?;
+        Date::from_ordinal_date(date.year(), ordinal).map_err(|_| OutOfRange::new())
+    }
+}
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = OutOfRange;
+
+    fn try_from(date: Date) -> Result<NaiveDate, OutOfRange> {
+        let (year, ordinal) = date.to_ordinal_date();
+        NaiveDate::from_yo_opt(year, u32::from(ordinal)).ok_or_else(OutOfRange::new)
+    }
+}
+
+/// Fails for a time within a leap second, which `time` cannot represent.
+impl TryFrom<NaiveTime> for Time {
+    type Error = OutOfRange;
+
+    fn try_from(time: NaiveTime) -> Result<Time, OutOfRange> {
+        // all components are in range, unless the nanoseconds represent a leap second
+        Time::from_hms_nano(
+            time.hour() as u8,
+            time.minute() as u8,
+            time.second() as u8,
+            time.nanosecond(),
+        )
+        .map_err(|_| OutOfRange::new())
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> NaiveTime {
+        let (hour, minute, second, nano) = time.as_hms_nano();
+        NaiveTime::from_hms_nano_opt(u32::from(hour), u32::from(minute), u32::from(second), nano)
+            .expect("`time::Time` is always a valid `NaiveTime`")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+
+    use time::{Date, OffsetDateTime, Time, UtcOffset};
+
+    use crate::naive::{NaiveDate, NaiveTime};
+    use crate::offset::{FixedOffset, TimeZone, Utc};
+    use crate::{DateTime, TimeDelta};
+
+    #[test]
+    fn test_datetime_round_trip() {
+        let dt = Utc.ymd_opt(2023, 4, 5).unwrap().and_hms_nano_opt(6, 7, 8, 123_456_789).unwrap();
+        let odt = OffsetDateTime::try_from(dt).unwrap();
+        assert_eq!(odt.unix_timestamp(), dt.timestamp());
+        assert_eq!(odt.nanosecond(), 123_456_789);
+        assert_eq!(odt.offset(), UtcOffset::UTC);
+        assert_eq!(DateTime::<Utc>::try_from(odt), Ok(dt));
+
+        for &(secs, nanos) in &[(0, 0), (-1, 999_999_999), (1_000_000_000, 1), (-2_000_000_000, 5)]
+        {
+            let dt = Utc.timestamp_opt(secs, nanos).unwrap();
+            let odt = OffsetDateTime::try_from(dt).unwrap();
+            assert_eq!(
+                odt.unix_timestamp_nanos(),
+                i128::from(secs) * 1_000_000_000 + i128::from(nanos)
+            );
+            assert_eq!(DateTime::<Utc>::try_from(odt), Ok(dt));
+        }
+    }
+
+    #[test]
+    fn test_datetime_offset() {
+        let tz = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+        let dt = tz.ymd_opt(2023, 4, 5).unwrap().and_hms_nano_opt(6, 7, 8, 9).unwrap();
+        let odt = OffsetDateTime::try_from(dt).unwrap();
+        assert_eq!(odt.offset(), UtcOffset::from_hms(5, 45, 0).unwrap());
+        assert_eq!((odt.hour(), odt.minute(), odt.second(), odt.nanosecond()), (6, 7, 8, 9));
+        assert_eq!(DateTime::<FixedOffset>::try_from(odt), Ok(dt));
+        assert_eq!(DateTime::<Utc>::try_from(odt), Ok(dt.with_timezone(&Utc)));
+
+        // `time` allows offsets that chrono does not
+        let odt = odt.replace_offset(UtcOffset::from_hms(25, 0, 0).unwrap());
+        assert!(DateTime::<FixedOffset>::try_from(odt).is_err());
+    }
+
+    #[test]
+    fn test_datetime_out_of_range() {
+        let leap = Utc.ymd_opt(2016, 12, 31).unwrap().and_hms_nano_opt(23, 59, 59, 1_500_000_000);
+        let odt = OffsetDateTime::try_from(leap.unwrap()).unwrap();
+        let next = Utc.ymd_opt(2017, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500).unwrap();
+        assert_eq!(DateTime::<Utc>::try_from(odt), Ok(next));
+
+        assert!(OffsetDateTime::try_from(DateTime::<Utc>::MAX_UTC).is_err());
+        assert!(OffsetDateTime::try_from(DateTime::<Utc>::MIN_UTC).is_err());
+        let far = Utc.ymd_opt(10_000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert!(OffsetDateTime::try_from(far).is_err());
+        assert!(OffsetDateTime::try_from(far - TimeDelta::nanoseconds(1)).is_ok());
+    }
+
+    #[test]
+    fn test_naive_date() {
+        for &(y, m, d) in &[(2023, 4, 5), (2024, 12, 31), (1, 1, 1), (0, 2, 29), (-9999, 1, 1)] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            let converted = Date::try_from(date).unwrap();
+            assert_eq!(converted.to_calendar_date().0, y);
+            assert_eq!(converted.day(), d as u8);
+            assert_eq!(NaiveDate::try_from(converted), Ok(date));
+        }
+        assert!(Date::try_from(NaiveDate::MAX).is_err());
+        assert!(Date::try_from(NaiveDate::from_ymd_opt(-10_000, 12, 31).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_naive_time() {
+        let time = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+        let converted = Time::try_from(time).unwrap();
+        assert_eq!(converted.as_hms_nano(), (23, 59, 59, 999_999_999));
+        assert_eq!(NaiveTime::from(converted), time);
+        assert_eq!(NaiveTime::from(Time::MIDNIGHT), NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert!(Time::try_from(leap).is_err());
+    }
+}
@@ -246,6 +246,26 @@ impl TimeDelta {
         }
     }
 
+    /// Add two durations, clamping to [`max_value`](#method.max_value) or
+    /// [`min_value`](#method.min_value) if overflow occurred.
+    pub fn saturating_add(&self, rhs: &TimeDelta) -> TimeDelta {
+        match self.checked_add(rhs) {
+            Some(d) => d,
+            None if *rhs > TimeDelta::zero() => MAX,
+            None => MIN,
+        }
+    }
+
+    /// Subtract two durations, clamping to [`max_value`](#method.max_value) or
+    /// [`min_value`](#method.min_value) if overflow occurred.
+    pub fn saturating_sub(&self, rhs: &TimeDelta) -> TimeDelta {
+        match self.checked_sub(rhs) {
+            Some(d) => d,
+            None if *rhs < TimeDelta::zero() => MAX,
+            None => MIN,
+        }
+    }
+
     /// Returns the duration as an absolute (non-negative) value.
     #[inline]
     pub fn abs(&self) -> TimeDelta {
@@ -620,6 +640,31 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn test_duration_saturating_ops() {
+        let near_max = TimeDelta::max_value() - TimeDelta::seconds(1);
+        assert_eq!(near_max.checked_add(&near_max), None);
+        assert_eq!(near_max.saturating_add(&near_max), TimeDelta::max_value());
+        assert_eq!(
+            near_max.saturating_add(&TimeDelta::milliseconds(1)),
+            near_max + TimeDelta::milliseconds(1)
+        );
+
+        let near_min = TimeDelta::min_value() + TimeDelta::seconds(1);
+        assert_eq!(near_min.saturating_add(&near_min), TimeDelta::min_value());
+        assert_eq!(near_min.saturating_sub(&near_max), TimeDelta::min_value());
+        assert_eq!(near_max.saturating_sub(&near_min), TimeDelta::max_value());
+
+        assert_eq!(
+            TimeDelta::hours(1).saturating_add(&TimeDelta::minutes(30)),
+            TimeDelta::minutes(90)
+        );
+        assert_eq!(
+            TimeDelta::hours(1).saturating_sub(&TimeDelta::minutes(90)),
+            TimeDelta::minutes(-30)
+        );
+    }
+
     #[test]
     fn test_duration_abs() {
         assert_eq!(TimeDelta::milliseconds(1300).abs(), TimeDelta::milliseconds(1300));
@@ -1,5 +1,28 @@
+use core::fmt;
+
 use crate::{IsoWeek, Weekday};
 
+/// The era of a year in the proleptic Gregorian calendar.
+///
+/// There is no year 0 in this notation: the year 0 is 1 BCE, the year -1 is 2 BCE, and so on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Era {
+    /// Before the Common Era (BC), i.e. the year 0 and earlier.
+    Bce,
+    /// The Common Era (AD), i.e. the year 1 and later.
+    Ce,
+}
+
+/// Formats the era as `BCE` or `CE`.
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Era::Bce => "BCE",
+            Era::Ce => "CE",
+        })
+    }
+}
+
 /// The common set of methods for date component.
 pub trait Datelike: Sized {
     /// Returns the year number in the [calendar date](./naive/struct.NaiveDate.html#calendar-date).
@@ -17,6 +40,27 @@ pub trait Datelike: Sized {
         }
     }
 
+    /// Returns the era of the year, to be used together with the year number from
+    /// [`year_ce`](#method.year_ce).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{Datelike, Era, NaiveDate};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(0, 6, 1).unwrap();
+    /// assert_eq!(date.era(), Era::Bce);
+    /// assert_eq!(format!("{} {}", date.year_ce().1, date.era()), "1 BCE");
+    /// ```
+    #[inline]
+    fn era(&self) -> Era {
+        if self.year() < 1 {
+            Era::Bce
+        } else {
+            Era::Ce
+        }
+    }
+
     /// Returns the month number starting from 1.
     ///
     /// The return value ranges from 1 to 12.
@@ -179,9 +223,26 @@ pub trait Timelike: Sized {
 
 #[cfg(test)]
 mod tests {
-    use super::Datelike;
+    use super::{Datelike, Era};
     use crate::{NaiveDate, TimeDelta};
 
+    #[test]
+    fn test_era() {
+        let ymd = |y| NaiveDate::from_ymd_opt(y, 1, 1).unwrap();
+
+        assert_eq!(ymd(2024).era(), Era::Ce);
+        assert_eq!(ymd(2024).year_ce(), (true, 2024));
+        assert_eq!(ymd(1).era(), Era::Ce);
+        assert_eq!(ymd(1).year_ce(), (true, 1));
+        assert_eq!(ymd(0).era(), Era::Bce);
+        assert_eq!(ymd(0).year_ce(), (false, 1));
+        assert_eq!(ymd(-1).era(), Era::Bce);
+        assert_eq!(ymd(-1).year_ce(), (false, 2));
+
+        assert_eq!(Era::Ce.to_string(), "CE");
+        assert_eq!(Era::Bce.to_string(), "BCE");
+    }
+
     /// Tests `Datelike::num_days_from_ce` against an alternative implementation.
     ///
     /// The alternative implementation is not as short as the current one but it is simpler to
@@ -1,3 +1,4 @@
+use core::ops::{Add, Sub};
 use core::{convert::TryFrom, fmt};
 
 #[cfg(feature = "rkyv")]
@@ -137,6 +138,62 @@ impl Weekday {
             Weekday::Sun => 0,
         }
     }
+
+    /// Makes a `Weekday` from an ISO 8601 weekday number (Monday = 1 to Sunday = 7).
+    ///
+    /// This is the inverse of [`number_from_monday`](#method.number_from_monday).
+    /// Returns `None` on the out-of-range number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::from_iso(1), Some(Weekday::Mon));
+    /// assert_eq!(Weekday::from_iso(7), Some(Weekday::Sun));
+    /// assert_eq!(Weekday::from_iso(0), None);
+    /// ```
+    #[inline]
+    pub fn from_iso(n: u32) -> Option<Weekday> {
+        match n {
+            1 => Some(Weekday::Mon),
+            2 => Some(Weekday::Tue),
+            3 => Some(Weekday::Wed),
+            4 => Some(Weekday::Thu),
+            5 => Some(Weekday::Fri),
+            6 => Some(Weekday::Sat),
+            7 => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+
+    /// Makes a `Weekday` from a day-of-week number starting from Sunday = 0.
+    ///
+    /// This is the inverse of [`num_days_from_sunday`](#method.num_days_from_sunday).
+    /// Returns `None` on the out-of-range number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Weekday;
+    ///
+    /// assert_eq!(Weekday::from_sunday_zero(0), Some(Weekday::Sun));
+    /// assert_eq!(Weekday::from_sunday_zero(6), Some(Weekday::Sat));
+    /// assert_eq!(Weekday::from_sunday_zero(7), None);
+    /// ```
+    #[inline]
+    pub fn from_sunday_zero(n: u32) -> Option<Weekday> {
+        match n {
+            0 => Some(Weekday::Sun),
+            1 => Some(Weekday::Mon),
+            2 => Some(Weekday::Tue),
+            3 => Some(Weekday::Wed),
+            4 => Some(Weekday::Thu),
+            5 => Some(Weekday::Fri),
+            6 => Some(Weekday::Sat),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Weekday {
@@ -153,6 +210,45 @@ impl fmt::Display for Weekday {
     }
 }
 
+/// Adding a number of days cycles through the week, going backwards for a negative number.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Weekday;
+///
+/// assert_eq!(Weekday::Fri + 3, Weekday::Mon);
+/// assert_eq!(Weekday::Mon + 7, Weekday::Mon);
+/// assert_eq!(Weekday::Mon + -1, Weekday::Sun);
+/// ```
+impl Add<i64> for Weekday {
+    type Output = Weekday;
+
+    fn add(self, days: i64) -> Weekday {
+        let n = (i64::from(self.num_days_from_monday()) + days.rem_euclid(7)) % 7;
+        Weekday::from_iso(n as u32 + 1).unwrap()
+    }
+}
+
+/// Subtracting a number of days cycles backwards through the week, going forwards for a
+/// negative number.
+///
+/// # Example
+///
+/// ```
+/// use chrono::Weekday;
+///
+/// assert_eq!(Weekday::Mon - 3, Weekday::Fri);
+/// assert_eq!(Weekday::Sun - -1, Weekday::Mon);
+/// ```
+impl Sub<i64> for Weekday {
+    type Output = Weekday;
+
+    fn sub(self, days: i64) -> Weekday {
+        self + (7 - days.rem_euclid(7))
+    }
+}
+
 /// Any weekday can be represented as an integer from 0 to 6, which equals to
 /// [`Weekday::num_days_from_monday`](#method.num_days_from_monday) in this implementation.
 /// Do not heavily depend on this though; use explicit methods whenever possible.
@@ -197,6 +293,86 @@ impl fmt::Debug for ParseWeekdayError {
 
 // the actual `FromStr` implementation is in the `format` module to leverage the existing code
 
+#[cfg(test)]
+mod tests {
+    use super::Weekday;
+
+    #[test]
+    fn test_days_from_sunday() {
+        use Weekday::*;
+
+        assert_eq!(Sun.num_days_from_sunday(), 0);
+        assert_eq!(Sat.num_days_from_sunday(), 6);
+        assert_eq!(Sun.number_from_sunday(), 1);
+        assert_eq!(Sat.number_from_sunday(), 7);
+
+        let mut day = Sun;
+        for n in 0..7 {
+            assert_eq!(day.num_days_from_sunday(), n);
+            assert_eq!(day.number_from_sunday(), n + 1);
+            day = day.succ();
+        }
+    }
+
+    #[test]
+    fn test_add_sub_days() {
+        use std::i64;
+        use Weekday::*;
+
+        assert_eq!(Mon + 7, Mon);
+        assert_eq!(Fri + 3, Mon);
+        assert_eq!(Mon + (-1), Sun);
+        assert_eq!(Mon + 0, Mon);
+        assert_eq!(Wed + 15, Thu);
+        assert_eq!(Wed + (-15), Tue);
+        assert_eq!(Mon - 1, Sun);
+        assert_eq!(Mon - 3, Fri);
+        assert_eq!(Sun - (-1), Mon);
+        assert_eq!(Sun - 14, Sun);
+
+        // i64::MAX is 7 * 1317624576693539401, so it is a multiple of a week
+        assert_eq!(Tue + i64::MAX, Tue);
+        assert_eq!(Tue - i64::MAX, Tue);
+        assert_eq!(Tue + i64::MIN, Mon);
+        assert_eq!(Tue - i64::MIN, Wed);
+
+        let mut day = Mon;
+        for n in 0..21 {
+            assert_eq!(Mon + n, day);
+            assert_eq!(day - n, Mon);
+            assert_eq!(Mon + (-n), Mon - n);
+            day = day.succ();
+        }
+    }
+
+    #[test]
+    fn test_from_numbers() {
+        use core::convert::TryFrom;
+        use Weekday::*;
+
+        assert_eq!(Weekday::from_iso(1), Some(Mon));
+        assert_eq!(Weekday::from_iso(7), Some(Sun));
+        assert_eq!(Weekday::from_iso(0), None);
+        assert_eq!(Weekday::from_iso(8), None);
+
+        assert_eq!(Weekday::from_sunday_zero(0), Some(Sun));
+        assert_eq!(Weekday::from_sunday_zero(1), Some(Mon));
+        assert_eq!(Weekday::from_sunday_zero(7), None);
+        assert_eq!(Weekday::from_sunday_zero(8), None);
+
+        assert_eq!(Weekday::try_from(0), Ok(Mon));
+        assert_eq!(Weekday::try_from(6), Ok(Sun));
+        assert!(Weekday::try_from(8).is_err());
+
+        let mut day = Mon;
+        for _ in 0..7 {
+            assert_eq!(Weekday::from_iso(day.number_from_monday()), Some(day));
+            assert_eq!(Weekday::from_sunday_zero(day.num_days_from_sunday()), Some(day));
+            day = day.succ();
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 mod weekday_serde {